@@ -1,3 +1,5 @@
+use super::graph::*;
+
 use {clap::*, kutil::cli::clap::*, std::path::*};
 
 // https://docs.rs/clap/latest/clap/_derive/index.html
@@ -37,6 +39,27 @@ pub enum SubCommand {
     /// compile a TOSCA service template to a Floria template
     Compile(Compile),
 
+    /// compile many TOSCA service templates in one process
+    CompileAll(CompileAll),
+
+    /// lint a TOSCA service template for non-fatal quality warnings
+    Lint(Lint),
+
+    /// browse the type hierarchies of a TOSCA service template
+    Types(Types),
+
+    /// generate reference documentation for the types of a TOSCA service template
+    Doc(Doc),
+
+    /// show where each field of a completed TOSCA entity came from
+    Explain(Explain),
+
+    /// evaluate an expression on a simulated instance of a TOSCA service template
+    Query(Query),
+
+    /// explore a simulated instance of a TOSCA service template interactively
+    Repl(Repl),
+
     /// show the version of puccini-tosca
     Version(Version),
 
@@ -97,6 +120,47 @@ pub struct Compile {
     #[arg(long = "update", short = 'u', verbatim_doc_comment)]
     pub update: bool,
 
+    /// local artifact deployment area for `$get_artifact`;
+    /// requires `--instantiate`;
+    /// when absent will use $PUCCINI_ARTIFACTS or a temporary directory
+    #[arg(long = "artifacts", verbatim_doc_comment)]
+    pub artifacts_path: Option<PathBuf>,
+
+    /// remove the artifacts staged by `$get_artifact` for "LOCAL_FILE"
+    /// after instantiation;
+    /// requires `--instantiate`
+    #[arg(long = "teardown", verbatim_doc_comment)]
+    pub teardown: bool,
+
+    /// trace function calls during instantiation;
+    /// requires `--instantiate`
+    #[arg(long = "trace-functions", verbatim_doc_comment)]
+    pub trace_functions: bool,
+
+    /// write the function trace as JSON to file path
+    /// instead of depicting it;
+    /// requires `--trace-functions`
+    #[arg(long = "trace-output", verbatim_doc_comment)]
+    pub trace_output_path: Option<PathBuf>,
+
+    /// register an extra Wasm function plugin as <id>=<path.wasm>;
+    /// when the path is absent will use <id>.wasm;
+    /// relative paths are also searched for in $PUCCINI_PLUGIN_PATH;
+    /// can be used multiple times
+    #[arg(long = "plugin", verbatim_doc_comment)]
+    pub plugins: Vec<String>,
+
+    /// report undeclared properties, capabilities, requirements,
+    /// and requirement nodes as errors;
+    /// with suggestions for the closest declared names
+    #[arg(long = "strict", verbatim_doc_comment)]
+    pub strict: bool,
+
+    /// errors format;
+    /// written to stderr instead of depicting the errors
+    #[arg(long = "errors-format", verbatim_doc_comment, value_enum)]
+    pub errors_format: Option<ErrorsFormat>,
+
     /// disable annotations
     #[arg(long = "no-annotations")]
     pub no_annotations: bool,
@@ -130,6 +194,382 @@ pub struct Compile {
     pub help: Option<bool>,
 }
 
+//
+// CompileAll
+//
+
+/// Compile all subcommand.
+#[derive(Args)]
+pub struct CompileAll {
+    /// can be file paths, directories, or glob patterns;
+    /// directories are walked for ".yaml" and ".yml" files
+    #[arg(required = true, verbatim_doc_comment)]
+    pub inputs: Vec<String>,
+
+    /// number of files to compile in parallel;
+    /// each job has its own catalog, so common imports are loaded once per job;
+    /// 0 for the number of CPUs
+    #[arg(long = "jobs", short = 'j', verbatim_doc_comment, default_value_t = 0)]
+    pub jobs: usize,
+
+    /// compile into Floria directory
+    #[arg(long = "directory")]
+    pub directory: Option<String>,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Lint
+//
+
+/// Lint subcommand.
+#[derive(Args)]
+pub struct Lint {
+    /// can be a file path or a URL;
+    /// when absent will read from stdin
+    #[arg(verbatim_doc_comment)]
+    pub input_path_or_url: Option<String>,
+
+    /// lint configuration file path;
+    /// when absent will use "puccini-lint.yaml" in the current directory if it exists
+    #[arg(long = "config", short = 'c', verbatim_doc_comment)]
+    pub configuration_path: Option<PathBuf>,
+
+    /// enable a rule;
+    /// can be used multiple times
+    #[arg(long = "enable", short = 'e', verbatim_doc_comment)]
+    pub enable: Vec<String>,
+
+    /// disable a rule;
+    /// can be used multiple times
+    #[arg(long = "disable", short = 'x', verbatim_doc_comment)]
+    pub disable: Vec<String>,
+
+    /// exit with an error if there are warnings
+    #[arg(long = "deny-warnings", short = 'D')]
+    pub deny_warnings: bool,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Types
+//
+
+/// Types subcommand.
+#[derive(Args)]
+pub struct Types {
+    /// can be a file path or a URL;
+    /// when absent will read from stdin
+    #[arg(verbatim_doc_comment)]
+    pub input_path_or_url: Option<String>,
+
+    /// show only this type kind, e.g. "NodeType" or "nodetype";
+    /// can be used multiple times
+    #[arg(long = "kind", short = 'k', verbatim_doc_comment)]
+    pub kinds: Vec<String>,
+
+    /// show only types with full names matching this glob pattern, e.g. "tosca:*",
+    /// and their ancestors
+    #[arg(long = "name", short = 'n', verbatim_doc_comment)]
+    pub name_pattern: Option<String>,
+
+    /// show only this type and the types derived from it;
+    /// can be a full name or a name without the namespace prefix
+    #[arg(long = "descendants-of", short = 'd', verbatim_doc_comment)]
+    pub descendants_of: Option<String>,
+
+    /// output format;
+    /// when absent will depict the hierarchy
+    #[arg(long = "format", short = 'f', verbatim_doc_comment, value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// plain output;
+    /// avoid whitespace and colors
+    #[arg(long = "plain", short = 'p')]
+    pub output_plain: bool,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Doc
+//
+
+/// Doc subcommand.
+#[derive(Args)]
+pub struct Doc {
+    /// can be a file path or a URL;
+    /// when absent will read from stdin
+    #[arg(verbatim_doc_comment)]
+    pub input_path_or_url: Option<String>,
+
+    /// output directory path;
+    /// will be created if it does not exist
+    #[arg(long = "output", short = 'o', verbatim_doc_comment)]
+    pub output_path: PathBuf,
+
+    /// documentation format
+    #[arg(long = "format", short = 'f', default_value_t = DocFormat::Markdown, value_enum)]
+    pub doc_format: DocFormat,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Explain
+//
+
+/// Explain subcommand.
+#[derive(Args)]
+pub struct Explain {
+    /// entity kind, e.g. "NodeTemplate" or "nodetype"
+    pub kind: String,
+
+    /// entity name;
+    /// can be a full name with a namespace prefix
+    #[arg(verbatim_doc_comment)]
+    pub name: String,
+
+    /// can be a file path or a URL;
+    /// when absent will read from stdin
+    #[arg(verbatim_doc_comment)]
+    pub input_path_or_url: Option<String>,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Query
+//
+
+/// Query subcommand.
+#[derive(Args)]
+pub struct Query {
+    /// can be a file path or a URL
+    pub input_path_or_url: String,
+
+    /// TOSCA expression in YAML;
+    /// e.g. "{ $get_attribute: [ db, RELATIONSHIP, host, TARGET, ip ] }"
+    #[arg(verbatim_doc_comment)]
+    pub expression: String,
+
+    /// name of the node template to use as the call site;
+    /// will use its first node;
+    /// when absent will use the service
+    #[arg(long = "node", short = 'n', verbatim_doc_comment)]
+    pub node: Option<String>,
+
+    /// output format;
+    /// when absent will depict the result
+    #[arg(long = "format", short = 'f', verbatim_doc_comment, value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// plain output;
+    /// avoid whitespace and colors
+    #[arg(long = "plain", short = 'p')]
+    pub output_plain: bool,
+
+    /// compile into Floria directory
+    #[arg(long = "directory")]
+    pub directory: Option<String>,
+
+    /// local artifact deployment area for `$get_artifact`;
+    /// when absent will use $PUCCINI_ARTIFACTS or a temporary directory
+    #[arg(long = "artifacts", verbatim_doc_comment)]
+    pub artifacts_path: Option<PathBuf>,
+
+    /// remove the artifacts staged by `$get_artifact` for "LOCAL_FILE"
+    /// after evaluating
+    #[arg(long = "teardown", verbatim_doc_comment)]
+    pub teardown: bool,
+
+    /// register an extra Wasm function plugin as <id>=<path.wasm>;
+    /// when the path is absent will use <id>.wasm;
+    /// relative paths are also searched for in $PUCCINI_PLUGIN_PATH;
+    /// can be used multiple times
+    #[arg(long = "plugin", verbatim_doc_comment)]
+    pub plugins: Vec<String>,
+
+    /// suppress console output
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
+//
+// Repl
+//
+
+/// REPL subcommand.
+#[derive(Args)]
+pub struct Repl {
+    /// can be a file path or a URL
+    pub input_path_or_url: String,
+
+    /// compile into Floria directory
+    #[arg(long = "directory")]
+    pub directory: Option<String>,
+
+    /// local artifact deployment area for `$get_artifact`;
+    /// when absent will use $PUCCINI_ARTIFACTS or a temporary directory
+    #[arg(long = "artifacts", verbatim_doc_comment)]
+    pub artifacts_path: Option<PathBuf>,
+
+    /// remove the artifacts staged by `$get_artifact` for "LOCAL_FILE"
+    /// when exiting
+    #[arg(long = "teardown", verbatim_doc_comment)]
+    pub teardown: bool,
+
+    /// register an extra Wasm function plugin as <id>=<path.wasm>;
+    /// when the path is absent will use <id>.wasm;
+    /// relative paths are also searched for in $PUCCINI_PLUGIN_PATH;
+    /// can be used multiple times
+    #[arg(long = "plugin", verbatim_doc_comment)]
+    pub plugins: Vec<String>,
+
+    /// history file path;
+    /// when absent will use ".puccini-tosca-history" in the home directory
+    #[arg(long = "history", verbatim_doc_comment)]
+    pub history_path: Option<PathBuf>,
+
+    /// colorize output
+    #[arg(long = "colorize", short = 'z', default_value_t = Colorize::True, value_enum)]
+    pub output_colorize: Colorize,
+
+    /// add a log verbosity level;
+    /// can be used 3 times
+    #[arg(long, short, verbatim_doc_comment, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// log to file path;
+    /// defaults to stderr
+    #[arg(long, long = "log", short = 'l', verbatim_doc_comment)]
+    pub log_path: Option<PathBuf>,
+
+    /// show this help
+    #[arg(long, short = 'h', action = ArgAction::Help)]
+    pub help: Option<bool>,
+}
+
 //
 // OutputFormat
 //
@@ -144,6 +584,8 @@ pub enum OutputFormat {
     #[value(name = "messagepack")]
     MessagePack,
     Depict,
+    DOT,
+    Mermaid,
 }
 
 impl OutputFormat {
@@ -156,7 +598,16 @@ impl OutputFormat {
             //OutputFormat::XML => Some(compris::Format::XML),
             OutputFormat::CBOR => Some(compris::Format::CBOR),
             OutputFormat::MessagePack => Some(compris::Format::MessagePack),
-            OutputFormat::Depict => None,
+            OutputFormat::Depict | OutputFormat::DOT | OutputFormat::Mermaid => None,
+        }
+    }
+
+    /// To graph format.
+    pub fn to_graph(&self) -> Option<GraphFormat> {
+        match self {
+            OutputFormat::DOT => Some(GraphFormat::DOT),
+            OutputFormat::Mermaid => Some(GraphFormat::Mermaid),
+            _ => None,
         }
     }
 }
@@ -167,6 +618,50 @@ impl ToString for OutputFormat {
     }
 }
 
+//
+// DocFormat
+//
+
+/// Doc format.
+#[derive(Clone, ValueEnum)]
+pub enum DocFormat {
+    /// Markdown pages
+    Markdown,
+
+    /// HTML pages
+    HTML,
+}
+
+impl DocFormat {
+    /// File extension.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::HTML => "html",
+        }
+    }
+}
+
+impl ToString for DocFormat {
+    fn to_string(&self) -> String {
+        self.to_possible_value().expect("to_possible_value").get_name().into()
+    }
+}
+
+//
+// ErrorsFormat
+//
+
+/// Errors format.
+#[derive(Clone, ValueEnum)]
+pub enum ErrorsFormat {
+    /// JSON array of diagnostics
+    JSON,
+
+    /// SARIF 2.1.0 log
+    SARIF,
+}
+
 //
 // Debug
 //
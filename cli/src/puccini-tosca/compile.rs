@@ -1,4 +1,4 @@
-use super::{cli::*, errors::*, utils::*};
+use super::{cli::*, diagnostics::*, errors::*, graph::*, utils::*};
 
 use {
     anstream::println,
@@ -13,6 +13,9 @@ use {
     std::fmt,
 };
 
+#[cfg(feature = "plugins")]
+use super::trace::*;
+
 impl Compile {
     /// Run compile subcommand.
     pub fn run(&self) -> Result<(), MainError> {
@@ -30,6 +33,27 @@ impl Compile {
             return Err(ExitError::from("cannot use `--update` without `--instantiate`").into());
         }
 
+        if self.artifacts_path.is_some() && !self.instantiate {
+            return Err(ExitError::from("cannot use `--artifacts` without `--instantiate`").into());
+        }
+
+        if self.teardown && !self.instantiate {
+            return Err(ExitError::from("cannot use `--teardown` without `--instantiate`").into());
+        }
+
+        if self.trace_functions && !self.instantiate {
+            return Err(ExitError::from("cannot use `--trace-functions` without `--instantiate`").into());
+        }
+
+        if self.trace_output_path.is_some() && !self.trace_functions {
+            return Err(ExitError::from("cannot use `--trace-output` without `--trace-functions`").into());
+        }
+
+        #[cfg(not(feature = "plugins"))]
+        if !self.plugins.is_empty() {
+            return Err(ExitError::from("to use `--plugin` you must enable \"plugins\" feature in build").into());
+        }
+
         if self.no_annotations {
             self.run_annotated::<WithoutAnnotations>()
         } else {
@@ -45,6 +69,19 @@ impl Compile {
         let url_context = self.url_context()?;
         let source_id = self.source_id();
         let mut catalog = Self::catalog::<AnnotatedT>();
+        catalog.report_undeclared = self.strict;
+
+        #[cfg(feature = "plugins")]
+        let plugin_files = self.plugin_files()?;
+
+        // Calls to unregistered plugins will be reported as errors
+        #[cfg(feature = "plugins")]
+        {
+            catalog.add_plugin_id(puccini_tosca::dialect::tosca_2_0::DIALECT_ID);
+            for plugin_file in &plugin_files {
+                catalog.add_plugin_id(plugin_file.id.clone());
+            }
+        }
 
         let mut tosca_errors = Errors::<ToscaError<AnnotatedT>>::default();
 
@@ -78,12 +115,40 @@ impl Compile {
         #[cfg(feature = "plugins")]
         let mut floria_instance = None;
 
+        #[cfg(feature = "plugins")]
+        let mut function_traces = FunctionTraces::default();
+
         // Instantiate
         #[cfg(feature = "plugins")]
         if self.should_instantiate()
             && let Some(floria_service_template_id) = &floria_service_template_id
         {
-            floria_instance = self.instantiate(floria_service_template_id, &directory, &store, &mut floria_errors)?;
+            floria_instance = self.instantiate(
+                floria_service_template_id,
+                &directory,
+                &store,
+                &plugin_files,
+                &mut function_traces,
+                &mut floria_errors,
+            )?;
+        }
+
+        // Write function trace
+        #[cfg(feature = "plugins")]
+        if let Some(trace_output_path) = &self.trace_output_path {
+            function_traces.write_json(trace_output_path)?;
+        }
+
+        // Write diagnostics
+
+        if let Some(errors_format) = &self.errors_format {
+            let mut diagnostics = Diagnostics::default();
+            diagnostics.add_tosca_errors(&tosca_errors);
+
+            #[cfg(feature = "plugins")]
+            diagnostics.add_floria_errors(&floria_errors);
+
+            diagnostics.write_stderr(errors_format)?;
         }
 
         // Output
@@ -96,9 +161,11 @@ impl Compile {
 
             if let Err(tosca_errors) = tosca_errors.check() {
                 print_floria = false;
-                first = false;
 
-                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+                if self.errors_format.is_none() {
+                    first = false;
+                    tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+                }
             }
 
             // Depict Floria errors
@@ -107,12 +174,26 @@ impl Compile {
             if let Err(floria_errors) = floria_errors.check() {
                 print_floria = false;
 
+                if self.errors_format.is_none() {
+                    if !first {
+                        println!();
+                    }
+                    first = false;
+
+                    floria_errors.to_depict("Floria Errors").print_default_depiction();
+                }
+            }
+
+            // Depict function trace
+
+            #[cfg(feature = "plugins")]
+            if self.trace_functions && self.trace_output_path.is_none() {
                 if !first {
                     println!();
                 }
                 first = false;
 
-                floria_errors.to_depict("Floria Errors").print_default_depiction();
+                function_traces.print_default_depiction();
             }
 
             // Depict debug
@@ -192,17 +273,14 @@ impl Compile {
         self.output_format.as_ref().and_then(|format| format.to_compris())
     }
 
+    /// Graph format.
+    pub fn get_graph_format(&self) -> Option<GraphFormat> {
+        self.output_format.as_ref().and_then(|format| format.to_graph())
+    }
+
     /// URL context.
     pub fn url_context(&self) -> Result<UrlContextRef, MainError> {
-        let url_context = UrlContext::new();
-
-        #[cfg(feature = "filesystem")]
-        let base_urls = url_context.working_dir_url_vec()?;
-
-        #[cfg(not(feature = "filesystem"))]
-        let base_urls = Vec::default();
-
-        Ok(url_context.with_base_urls(base_urls))
+        url_context()
     }
 
     /// Source ID.
@@ -212,9 +290,6 @@ impl Compile {
 
     /// Floria directory.
     pub fn floria_directory(&self) -> floria::Directory {
-        self.directory
-            .as_ref()
-            .map(|directory| floria::ID::parse_directory(directory))
-            .unwrap_or_else(|| Default::default())
+        floria_directory(self.directory.as_ref())
     }
 }
@@ -0,0 +1,520 @@
+use super::{cli::*, errors::*, utils::*};
+
+use {
+    anstream::println,
+    compris::annotate::*,
+    floria::*,
+    kutil::{
+        cli::{depict::*, log::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::grammar::*,
+    read_url::*,
+    std::{collections::*, fs, io, mem::*, path::*, thread, time::*},
+};
+
+const EXTENSIONS: &[&str] = &["yaml", "yml"];
+
+impl CompileAll {
+    /// Run compile-all subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        let paths = self.paths()?;
+        if paths.is_empty() {
+            return Err(ExitError::from("no files to compile").into());
+        }
+
+        let results = self.compile(paths)?;
+
+        if !self.quiet {
+            // Depict TOSCA errors per file
+
+            for result in &results.results {
+                if let Err(tosca_errors) = result.tosca_errors.check() {
+                    tosca_errors
+                        .annotated_depictions(Some(result.path.display().to_string().into()))
+                        .print_default_depiction();
+                    println!();
+                }
+            }
+
+            // Errors in imports are depicted once, not for every file that imports them
+            if let Err(import_errors) = results.import_errors.check() {
+                import_errors.annotated_depictions(Some("Imports".into())).print_default_depiction();
+                println!();
+            }
+
+            // Depict summary
+
+            results.print_default_depiction();
+        }
+
+        return if results.is_ok() { Ok(()) } else { Err(ExitError::new(1, None).into()) };
+    }
+
+    /// Paths of the files to compile.
+    ///
+    /// Directories are walked and glob patterns are expanded. The paths are sorted and unique.
+    pub fn paths(&self) -> Result<Vec<PathBuf>, MainError> {
+        let mut paths = BTreeSet::default();
+
+        for input in &self.inputs {
+            let path = PathBuf::from(input);
+            if path.is_dir() {
+                walk(&path, &mut paths)?;
+            } else if path.exists() {
+                paths.insert(path);
+            } else {
+                let entries = glob::glob(input)
+                    .map_err(|error| ExitError::new(1, Some(format!("invalid glob pattern: {}: {}", input, error))))?;
+
+                let mut found = false;
+                for entry in entries {
+                    let entry = entry.map_err(|error| error.into_error())?;
+                    found = true;
+
+                    if entry.is_dir() {
+                        walk(&entry, &mut paths)?;
+                    } else {
+                        paths.insert(entry);
+                    }
+                }
+
+                if !found {
+                    return Err(ExitError::new(1, Some(format!("no files found: {}", input))).into());
+                }
+            }
+        }
+
+        Ok(paths.into_iter().collect())
+    }
+
+    /// Number of jobs.
+    pub fn jobs(&self, files: usize) -> usize {
+        let jobs = match self.jobs {
+            0 => thread::available_parallelism().map(|jobs| jobs.get()).unwrap_or(1),
+            jobs => jobs,
+        };
+
+        jobs.min(files).max(1)
+    }
+
+    /// Compile files.
+    ///
+    /// The [Catalog] is not thread-safe, so each job has its own, which is shared by all the files
+    /// in that job. The results are in the same order as the paths.
+    pub fn compile(&self, paths: Vec<PathBuf>) -> Result<CompileResults, MainError> {
+        let jobs = self.jobs(paths.len());
+        tracing::info!(files = paths.len(), jobs, "compiling");
+
+        let url_context = url_context()?;
+        let directory = floria_directory(self.directory.as_ref());
+
+        // Distribute the files among the jobs
+        let mut job_paths = vec![Vec::default(); jobs];
+        for (index, path) in paths.into_iter().enumerate() {
+            job_paths[index % jobs].push((index, path));
+        }
+
+        let mut results: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = job_paths
+                .into_iter()
+                .map(|paths| {
+                    let url_context = url_context.clone();
+                    let directory = &directory;
+                    scope.spawn(move || compile_job(paths, &url_context, directory))
+                })
+                .collect();
+
+            handles.into_iter().flat_map(|handle| handle.join().expect("join")).collect()
+        });
+
+        results.sort_by_key(|(index, _)| *index);
+        Ok(CompileResults::new(results.into_iter().map(|(_, result)| result).collect()))
+    }
+}
+
+//
+// CompileResult
+//
+
+/// Result of compiling a file.
+#[derive(Debug)]
+pub struct CompileResult {
+    /// Path.
+    pub path: PathBuf,
+
+    /// TOSCA errors in the file.
+    pub tosca_errors: Errors<ToscaError<WithAnnotations>>,
+
+    /// TOSCA errors in the imports that were completed for the file.
+    pub import_errors: Errors<ToscaError<WithAnnotations>>,
+
+    /// Whether a service template was compiled.
+    pub compiled: bool,
+
+    /// Duration.
+    pub duration: Duration,
+}
+
+impl CompileResult {
+    /// Compile a file.
+    pub fn compile(
+        path: PathBuf,
+        catalog: &mut Catalog,
+        url_context: &UrlContextRef,
+        directory: &floria::Directory,
+    ) -> Self {
+        tracing::info!(path = path.display().to_string(), "compiling");
+
+        let start = Instant::now();
+        let source_id = SourceID::url_or_default(Some(path.display().to_string().into()));
+        let mut tosca_errors = Errors::default();
+
+        let compiled = match compile_source(&source_id, catalog, url_context, directory, &mut tosca_errors) {
+            Ok(compiled) => compiled,
+            Err(error) => {
+                tosca_errors.errors.push(error);
+                false
+            }
+        };
+
+        // Errors located in other sources are in imports
+        let source = source_id.to_string();
+        let mut import_errors = Errors::default();
+        (tosca_errors.errors, import_errors.errors) = take(&mut tosca_errors.errors).into_iter().partition(|error| {
+            error
+                .annotations()
+                .and_then(|annotations| annotations.source.as_ref())
+                .is_none_or(|error_source| error_source.to_string() == source)
+        });
+
+        Self { path, tosca_errors, import_errors, compiled, duration: start.elapsed() }
+    }
+}
+
+//
+// CompileResults
+//
+
+/// Results of compiling files.
+#[derive(Debug, Default)]
+pub struct CompileResults {
+    /// Results, in the order of the files.
+    pub results: Vec<CompileResult>,
+
+    /// TOSCA errors in imports.
+    ///
+    /// An import that is shared by several files is reported once.
+    pub import_errors: Errors<ToscaError<WithAnnotations>>,
+}
+
+impl CompileResults {
+    /// Constructor.
+    ///
+    /// Collects the import errors of the results.
+    pub fn new(mut results: Vec<CompileResult>) -> Self {
+        // Each job has its own catalog, so the same import may have been completed more than once
+        let mut reported = HashSet::new();
+        let mut import_errors = Errors::default();
+        for result in &mut results {
+            for error in take(&mut result.import_errors.errors) {
+                if reported.insert((location(&error), error.to_string())) {
+                    import_errors.errors.push(error);
+                }
+            }
+        }
+
+        Self { results, import_errors }
+    }
+
+    /// True if there are no errors.
+    pub fn is_ok(&self) -> bool {
+        self.import_errors.is_empty() && self.results.iter().all(|result| result.tosca_errors.is_empty())
+    }
+}
+
+impl Depict for CompileResults {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.theme.write_heading(writer, "Compile Summary")?;
+
+        let paths: Vec<_> = self.results.iter().map(|result| result.path.display().to_string()).collect();
+        let width = paths.iter().map(|path| path.chars().count()).max().unwrap_or_default();
+
+        let mut failed = 0;
+        for (result, path) in self.results.iter().zip(paths) {
+            context.indent_into_branch(writer, false)?;
+            write!(writer, "{}{}  ", context.theme.name(&path), " ".repeat(width - path.chars().count()))?;
+
+            let errors = result.tosca_errors.errors.len();
+            if errors == 0 {
+                write!(writer, "ok")?;
+                if !result.compiled {
+                    write!(writer, " {}", context.theme.meta("(no service template)"))?;
+                }
+            } else {
+                failed += 1;
+                let errors = format!("{} error{}", errors, if errors == 1 { "" } else { "s" });
+                write!(writer, "{}", context.theme.error(errors))?;
+            }
+
+            write!(writer, " {}", context.theme.meta(format!("{:.2?}", result.duration)))?;
+        }
+
+        if !self.import_errors.is_empty() {
+            context.indent_into_branch(writer, false)?;
+            let errors = self.import_errors.errors.len();
+            let errors = format!("{} error{} in imports", errors, if errors == 1 { "" } else { "s" });
+            write!(writer, "{}", context.theme.error(errors))?;
+        }
+
+        context.indent_into_branch(writer, true)?;
+        write!(writer, "{} files, {} ok, ", self.results.len(), self.results.len() - failed)?;
+        if failed == 0 {
+            write!(writer, "0 failed")
+        } else {
+            write!(writer, "{}", context.theme.error(format!("{} failed", failed)))
+        }
+    }
+}
+
+// Compile files with a shared catalog
+fn compile_job(
+    paths: Vec<(usize, PathBuf)>,
+    url_context: &UrlContextRef,
+    directory: &floria::Directory,
+) -> Vec<(usize, CompileResult)> {
+    let mut catalog = Compile::catalog::<WithAnnotations>();
+
+    let mut results = Vec::with_capacity(paths.len());
+    for (index, path) in paths {
+        results.push((index, CompileResult::compile(path, &mut catalog, url_context, directory)));
+
+        // Completed entities are kept for the next file, but not what was recorded while completing them
+        catalog.clear_completion_records();
+    }
+
+    results
+}
+
+// Returns true if a service template was compiled
+fn compile_source(
+    source_id: &SourceID,
+    catalog: &mut Catalog,
+    url_context: &UrlContextRef,
+    directory: &floria::Directory,
+    errors: &mut Errors<ToscaError<WithAnnotations>>,
+) -> Result<bool, ToscaError<WithAnnotations>> {
+    catalog.load_source_with_annotations(source_id, url_context, errors)?;
+
+    // Errors were already reported if the source could not be loaded
+    if !catalog.sources.contains_key(source_id) {
+        return Ok(false);
+    }
+
+    // Imports shared with previous files are already complete
+    catalog.complete_source_entities(source_id, errors)?;
+
+    let store = InMemoryStore::default();
+    Ok(catalog.compile_service_template(directory, store.to_ref(), source_id, errors)?.is_some())
+}
+
+// Source location of an error, e.g. `file.yaml:12:5`
+fn location(error: &ToscaError<WithAnnotations>) -> Option<String> {
+    let annotations = error.annotations()?;
+    let source = annotations.source.as_ref()?;
+    Some(match &annotations.span {
+        Some(span) => format!("{}:{}:{}", source, span.start.row, span.start.column),
+        None => source.to_string(),
+    })
+}
+
+// Walk a directory for TOSCA files
+fn walk(directory: &Path, paths: &mut BTreeSet<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk(&path, paths)?;
+        } else if let Some(extension) = path.extension()
+            && EXTENSIONS.iter().any(|supported| extension == *supported)
+        {
+            paths.insert(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, kutil::cli::clap::*, std::env};
+
+    // Fresh directory with the files
+    fn directory(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let directory = env::temp_dir().join(format!("puccini-compile-all-{}-{}", name, std::process::id()));
+        _ = fs::remove_dir_all(&directory);
+
+        for (file_name, content) in files {
+            let path = directory.join(file_name);
+            fs::create_dir_all(path.parent().expect("parent")).expect("create directory");
+            fs::write(path, content).expect("write file");
+        }
+
+        directory
+    }
+
+    fn compile_all(inputs: Vec<String>, jobs: usize) -> CompileAll {
+        CompileAll {
+            inputs,
+            jobs,
+            directory: None,
+            output_colorize: Colorize::True,
+            quiet: true,
+            verbose: 0,
+            log_path: None,
+            help: None,
+        }
+    }
+
+    fn file_names(paths: &[PathBuf], directory: &Path) -> Vec<String> {
+        paths.iter().map(|path| path.strip_prefix(directory).expect("in directory").display().to_string()).collect()
+    }
+
+    const SERVER: &str = r#"
+tosca_definitions_version: tosca_2_0
+imports:
+- types.yaml
+service_template:
+  node_templates:
+    server:
+      type: Server
+"#;
+
+    const TYPES: &str = r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Server: {}
+"#;
+
+    const BROKEN_TYPES: &str = r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Server:
+    derived_from: Missing
+"#;
+
+    #[test]
+    fn paths_walk_directories() {
+        let directory = directory(
+            "walk",
+            &[("b.yaml", TYPES), ("a.yml", TYPES), ("nested/c.yaml", TYPES), ("README.md", ""), ("nested/d.json", "")],
+        );
+
+        let paths = compile_all(vec![directory.display().to_string()], 0).paths().expect("paths");
+        assert_eq!(file_names(&paths, &directory), ["a.yml", "b.yaml", "nested/c.yaml"]);
+    }
+
+    #[test]
+    fn paths_expand_globs() {
+        let directory = directory("glob", &[("a.yaml", TYPES), ("b.yaml", TYPES), ("c.yml", TYPES)]);
+
+        // The same file given twice is compiled once
+        let paths = compile_all(
+            vec![directory.join("*.yaml").display().to_string(), directory.join("a.yaml").display().to_string()],
+            0,
+        )
+        .paths()
+        .expect("paths");
+        assert_eq!(file_names(&paths, &directory), ["a.yaml", "b.yaml"]);
+
+        let missing = directory.join("*.json").display().to_string();
+        assert!(compile_all(vec![missing], 0).paths().is_err());
+    }
+
+    #[test]
+    fn jobs() {
+        assert_eq!(compile_all(Vec::default(), 4).jobs(10), 4);
+        assert_eq!(compile_all(Vec::default(), 4).jobs(2), 2);
+        assert_eq!(compile_all(Vec::default(), 4).jobs(0), 1);
+        assert!(compile_all(Vec::default(), 0).jobs(1000) >= 1);
+    }
+
+    #[test]
+    fn compile() {
+        let directory = directory(
+            "compile",
+            &[
+                ("a.yaml", SERVER),
+                ("b.yaml", SERVER),
+                ("types.yaml", TYPES),
+                (
+                    "unknown-type.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+service_template:
+  node_templates:
+    server:
+      type: Missing
+"#,
+                ),
+            ],
+        );
+
+        for jobs in [1, 2] {
+            let compile_all = compile_all(vec![directory.display().to_string()], jobs);
+            let results = compile_all.compile(compile_all.paths().expect("paths")).expect("compile");
+
+            // In the order of the paths, whichever job compiled them
+            let paths: Vec<_> = results.results.iter().map(|result| result.path.clone()).collect();
+            assert_eq!(file_names(&paths, &directory), ["a.yaml", "b.yaml", "types.yaml", "unknown-type.yaml"]);
+
+            let compiled: Vec<_> = results.results.iter().map(|result| result.compiled).collect();
+            assert_eq!(compiled[..3], [true, true, false], "jobs: {}", jobs);
+
+            let ok: Vec<_> = results.results.iter().map(|result| result.tosca_errors.is_empty()).collect();
+            assert_eq!(ok, [true, true, true, false], "jobs: {}", jobs);
+
+            assert!(results.import_errors.is_empty());
+            assert!(!results.is_ok());
+        }
+    }
+
+    #[test]
+    fn import_errors_reported_once() {
+        let directory = directory("imports", &[("a.yaml", SERVER), ("b.yaml", SERVER), ("types.yaml", BROKEN_TYPES)]);
+
+        // The import is not compiled itself, so its errors are only reported as import errors
+        let inputs =
+            vec![directory.join("a.yaml").display().to_string(), directory.join("b.yaml").display().to_string()];
+
+        let import_errors: Vec<_> = [1, 2]
+            .into_iter()
+            .map(|jobs| {
+                let compile_all = compile_all(inputs.clone(), jobs);
+                let results = compile_all.compile(compile_all.paths().expect("paths")).expect("compile");
+
+                assert!(results.results.iter().all(|result| result.tosca_errors.is_empty()), "jobs: {}", jobs);
+                assert!(results.results.iter().all(|result| result.import_errors.is_empty()), "jobs: {}", jobs);
+                assert!(!results.is_ok());
+
+                let mut import_errors: Vec<_> =
+                    results.import_errors.errors.iter().map(|error| location(error).expect("location")).collect();
+                assert!(!import_errors.is_empty(), "jobs: {}", jobs);
+                assert!(import_errors.iter().all(|location| location.contains("types.yaml")), "jobs: {}", jobs);
+
+                import_errors.sort();
+                import_errors
+            })
+            .collect();
+
+        // The same errors whether the import was completed by one job or by both
+        assert_eq!(import_errors[0], import_errors[1]);
+    }
+}
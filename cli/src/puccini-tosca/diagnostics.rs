@@ -0,0 +1,374 @@
+use super::cli::*;
+
+use {
+    compris::{annotate::*, normal::*},
+    kutil::std::error::*,
+    puccini_tosca::grammar::*,
+    puccini_tosca_2_0_core::LocatedError,
+    std::{collections::*, io},
+};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const INFORMATION_URI: &str = "https://github.com/tliron/puccini";
+
+type Map = BTreeMap<Variant<WithoutAnnotations>, Variant<WithoutAnnotations>>;
+
+//
+// Diagnostic
+//
+
+/// Machine-readable diagnostic for an error.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostic {
+    /// Origin, e.g. `tosca` or `floria`.
+    pub origin: &'static str,
+
+    /// Kind of error.
+    pub kind: String,
+
+    /// Message.
+    pub message: String,
+
+    /// Source URL.
+    pub source: Option<String>,
+
+    /// Span as start row and column, and optionally end row and column.
+    ///
+    /// Rows and columns are zero-based, as in annotation spans.
+    pub span: Option<(usize, usize, Option<(usize, usize)>)>,
+
+    /// Entity path.
+    pub path: Option<String>,
+}
+
+impl Diagnostic {
+    /// From [ToscaError].
+    pub fn from_tosca_error<AnnotatedT>(error: &ToscaError<AnnotatedT>) -> Self
+    where
+        AnnotatedT: Annotated,
+    {
+        let mut diagnostic =
+            Self { origin: "tosca", kind: error.kind().into(), message: error.to_string(), ..Default::default() };
+
+        if let Some(annotations) = error.annotations() {
+            diagnostic.source = annotations.source.as_ref().map(|source| source.to_string());
+            diagnostic.span = annotations
+                .span
+                .as_ref()
+                .map(|span| (span.start.row, span.start.column, span.end.as_ref().map(|end| (end.row, end.column))));
+            diagnostic.path = annotations.path.as_ref().map(|path| path.to_string());
+        }
+
+        diagnostic
+    }
+
+    /// From [FloriaError](floria::FloriaError).
+    ///
+    /// [LocatedError]s, such as schema coercion errors, are mapped back to the TOSCA source and keep
+    /// their kind. Otherwise the kind is the error variant.
+    pub fn from_floria_error(error: &floria::FloriaError) -> Self {
+        Self::from_floria_message(error.to_string(), floria_error_kind(error))
+    }
+
+    /// From [FloriaError](floria::FloriaError) message and kind.
+    pub fn from_floria_message(message: String, kind: String) -> Self {
+        match LocatedError::find(&message) {
+            Some((prefix, located_error)) => Self {
+                origin: "floria",
+                kind: located_error.kind,
+                message: format!("{}{}", prefix, located_error.message),
+                source: Some(located_error.location.source),
+                span: located_error.location.position.map(|(row, column)| (row, column, None)),
+                ..Default::default()
+            },
+
+            None => Self { origin: "floria", kind, message, ..Default::default() },
+        }
+    }
+
+    /// To JSON variant.
+    pub fn to_json_variant(&self) -> Variant<WithoutAnnotations> {
+        let mut map = Map::default();
+        map.insert("origin".into(), self.origin.into());
+        map.insert("kind".into(), self.kind.clone().into());
+        map.insert("message".into(), self.message.clone().into());
+
+        if let Some(source) = &self.source {
+            map.insert("source".into(), source.clone().into());
+        }
+
+        if let Some((row, column, end)) = &self.span {
+            let mut span = Map::default();
+            span.insert("row".into(), (*row as u64).into());
+            span.insert("column".into(), (*column as u64).into());
+            if let Some((end_row, end_column)) = end {
+                span.insert("end_row".into(), (*end_row as u64).into());
+                span.insert("end_column".into(), (*end_column as u64).into());
+            }
+            map.insert("span".into(), span.into());
+        }
+
+        if let Some(path) = &self.path {
+            map.insert("path".into(), path.clone().into());
+        }
+
+        map.into()
+    }
+
+    /// To SARIF result variant.
+    pub fn to_sarif_variant(&self) -> Variant<WithoutAnnotations> {
+        let mut message = Map::default();
+        message.insert("text".into(), self.message.clone().into());
+
+        let mut result = Map::default();
+        result.insert("ruleId".into(), format!("{}/{}", self.origin, self.kind).into());
+        result.insert("level".into(), "error".into());
+        result.insert("message".into(), message.into());
+
+        let mut location = Map::default();
+
+        if let Some(source) = &self.source {
+            let mut artifact_location = Map::default();
+            artifact_location.insert("uri".into(), source.clone().into());
+
+            let mut physical_location = Map::default();
+            physical_location.insert("artifactLocation".into(), artifact_location.into());
+
+            if let Some((row, column, end)) = &self.span {
+                // SARIF lines and columns are one-based
+                let mut region = Map::default();
+                region.insert("startLine".into(), one_based(*row));
+                region.insert("startColumn".into(), one_based(*column));
+                if let Some((end_row, end_column)) = end {
+                    region.insert("endLine".into(), one_based(*end_row));
+                    region.insert("endColumn".into(), one_based(*end_column));
+                }
+                physical_location.insert("region".into(), region.into());
+            }
+
+            location.insert("physicalLocation".into(), physical_location.into());
+        }
+
+        if let Some(path) = &self.path {
+            let mut logical_location = Map::default();
+            logical_location.insert("fullyQualifiedName".into(), path.clone().into());
+            location.insert("logicalLocations".into(), vec![Variant::from(logical_location)].into());
+        }
+
+        if !location.is_empty() {
+            result.insert("locations".into(), vec![Variant::from(location)].into());
+        }
+
+        result.into()
+    }
+}
+
+//
+// Diagnostics
+//
+
+/// Machine-readable diagnostics.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// Add [ToscaError]s.
+    pub fn add_tosca_errors<AnnotatedT>(&mut self, errors: &Errors<ToscaError<AnnotatedT>>)
+    where
+        AnnotatedT: Annotated,
+    {
+        self.0.extend(errors.errors.iter().map(Diagnostic::from_tosca_error));
+    }
+
+    /// Add [FloriaError](floria::FloriaError)s.
+    pub fn add_floria_errors(&mut self, errors: &Errors<floria::FloriaError>) {
+        self.0.extend(errors.errors.iter().map(Diagnostic::from_floria_error));
+    }
+
+    /// To variant in format.
+    pub fn to_variant(&self, errors_format: &ErrorsFormat) -> Variant<WithoutAnnotations> {
+        match errors_format {
+            ErrorsFormat::JSON => {
+                let diagnostics: Vec<_> = self.0.iter().map(|diagnostic| diagnostic.to_json_variant()).collect();
+                diagnostics.into()
+            }
+
+            ErrorsFormat::SARIF => {
+                let mut driver = Map::default();
+                driver.insert("name".into(), "puccini-tosca".into());
+                driver.insert("version".into(), env!("CARGO_PKG_VERSION").into());
+                driver.insert("informationUri".into(), INFORMATION_URI.into());
+
+                let mut tool = Map::default();
+                tool.insert("driver".into(), driver.into());
+
+                let results: Vec<_> = self.0.iter().map(|diagnostic| diagnostic.to_sarif_variant()).collect();
+
+                let mut run = Map::default();
+                run.insert("tool".into(), tool.into());
+                run.insert("results".into(), results.into());
+
+                let mut sarif = Map::default();
+                sarif.insert("$schema".into(), SARIF_SCHEMA.into());
+                sarif.insert("version".into(), SARIF_VERSION.into());
+                sarif.insert("runs".into(), vec![Variant::from(run)].into());
+                sarif.into()
+            }
+        }
+    }
+
+    /// Write to stderr.
+    ///
+    /// Stdout is reserved for the compiled output.
+    pub fn write_stderr(&self, errors_format: &ErrorsFormat) -> io::Result<()> {
+        compris::ser::Serializer::new(compris::Format::JSON)
+            .with_pretty(true)
+            .write(&self.to_variant(errors_format), &mut io::stderr())
+            .map_err(io::Error::other)
+    }
+}
+
+fn one_based(index: usize) -> Variant<WithoutAnnotations> {
+    (index as u64 + 1).into()
+}
+
+// The variant name, e.g. "Plugin(...)" -> "plugin"
+fn floria_error_kind(error: &floria::FloriaError) -> String {
+    let debug = format!("{:?}", error);
+    let variant = debug.split(|c: char| !c.is_ascii_alphanumeric()).next().unwrap_or_default();
+    if variant.is_empty() { "floria".into() } else { snake_case(variant) }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index != 0 {
+                snake_case.push('_');
+            }
+            snake_case.push(c.to_ascii_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get<'own>(variant: &'own Variant<WithoutAnnotations>, key: &str) -> &'own Variant<WithoutAnnotations> {
+        match variant {
+            Variant::Map(map) => map.inner.get(&key.into()).expect(key),
+            _ => panic!("not a map: {}", key),
+        }
+    }
+
+    fn first(variant: &Variant<WithoutAnnotations>) -> &Variant<WithoutAnnotations> {
+        match variant {
+            Variant::List(list) => list.inner.first().expect("empty list"),
+            _ => panic!("not a list"),
+        }
+    }
+
+    fn located_diagnostic() -> Diagnostic {
+        Diagnostic {
+            origin: "tosca",
+            kind: "undeclared".into(),
+            message: "undeclared: hello".into(),
+            source: Some("file.yaml".into()),
+            span: Some((0, 0, Some((2, 4)))),
+            path: Some("node_templates.server".into()),
+        }
+    }
+
+    #[test]
+    fn sarif_region_is_one_based() {
+        let result = located_diagnostic().to_sarif_variant();
+
+        assert_eq!(get(&result, "ruleId"), &"tosca/undeclared".into());
+        assert_eq!(get(get(&result, "message"), "text"), &"undeclared: hello".into());
+
+        let location = first(get(&result, "locations"));
+        let physical_location = get(location, "physicalLocation");
+        assert_eq!(get(get(physical_location, "artifactLocation"), "uri"), &"file.yaml".into());
+
+        let region = get(physical_location, "region");
+        assert_eq!(get(region, "startLine"), &1u64.into());
+        assert_eq!(get(region, "startColumn"), &1u64.into());
+        assert_eq!(get(region, "endLine"), &3u64.into());
+        assert_eq!(get(region, "endColumn"), &5u64.into());
+
+        let logical_location = first(get(location, "logicalLocations"));
+        assert_eq!(get(logical_location, "fullyQualifiedName"), &"node_templates.server".into());
+    }
+
+    #[test]
+    fn sarif_log() {
+        let sarif = Diagnostics(vec![located_diagnostic()]).to_variant(&ErrorsFormat::SARIF);
+
+        assert_eq!(get(&sarif, "version"), &SARIF_VERSION.into());
+        assert_eq!(get(&sarif, "$schema"), &SARIF_SCHEMA.into());
+
+        let run = first(get(&sarif, "runs"));
+        assert_eq!(get(get(get(run, "tool"), "driver"), "name"), &"puccini-tosca".into());
+        assert_eq!(get(first(get(run, "results")), "ruleId"), &"tosca/undeclared".into());
+    }
+
+    #[test]
+    fn sarif_without_location() {
+        let diagnostic =
+            Diagnostic { origin: "floria", kind: "plugin".into(), message: "failed".into(), ..Default::default() };
+
+        match diagnostic.to_sarif_variant() {
+            Variant::Map(map) => assert!(!map.inner.contains_key(&"locations".into())),
+            _ => panic!("not a map"),
+        }
+    }
+
+    #[test]
+    fn json_span_is_zero_based() {
+        let json = Diagnostics(vec![located_diagnostic()]).to_variant(&ErrorsFormat::JSON);
+        let diagnostic = first(&json);
+
+        assert_eq!(get(diagnostic, "origin"), &"tosca".into());
+        assert_eq!(get(diagnostic, "kind"), &"undeclared".into());
+
+        let span = get(diagnostic, "span");
+        assert_eq!(get(span, "row"), &0u64.into());
+        assert_eq!(get(span, "column"), &0u64.into());
+        assert_eq!(get(span, "end_row"), &2u64.into());
+        assert_eq!(get(span, "end_column"), &4u64.into());
+    }
+
+    #[test]
+    fn floria_located_error() {
+        let diagnostic = Diagnostic::from_floria_message(
+            "plugin error: file:///tmp/file.yaml:12:5: coerce: properties.ports: not an integer".into(),
+            "plugin".into(),
+        );
+
+        assert_eq!(diagnostic.kind, "coerce");
+        assert_eq!(diagnostic.message, "plugin error: properties.ports: not an integer");
+        assert_eq!(diagnostic.source.as_deref(), Some("file:///tmp/file.yaml"));
+        assert_eq!(diagnostic.span, Some((12, 5, None)));
+    }
+
+    #[test]
+    fn floria_unlocated_error() {
+        let diagnostic = Diagnostic::from_floria_message("plugin error: failed: 1:2".into(), "plugin".into());
+
+        assert_eq!(diagnostic.kind, "plugin");
+        assert_eq!(diagnostic.message, "plugin error: failed: 1:2");
+        assert_eq!(diagnostic.source, None);
+        assert_eq!(diagnostic.span, None);
+    }
+
+    #[test]
+    fn variant_kind() {
+        assert_eq!(snake_case("Plugin"), "plugin");
+        assert_eq!(snake_case("WasmRuntime"), "wasm_runtime");
+    }
+}
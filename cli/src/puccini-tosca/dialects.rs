@@ -7,7 +7,11 @@ use {
 };
 
 #[cfg(feature = "plugins")]
-use floria::{plugins::*, *};
+use {
+    super::{errors::*, plugins::*},
+    floria::{plugins::*, *},
+    std::fs,
+};
 
 impl Compile {
     /// TOSCA [Catalog] with supported dialects.
@@ -21,12 +25,13 @@ impl Compile {
         catalog
     }
 
-    /// Floria [Library] with the plugins for supported dialects.
+    /// Floria [Library] with the plugins for supported dialects and the extra plugin files.
     #[cfg(feature = "plugins")]
     pub fn library<'environment, StoreT>(
         environment: Environment,
         store: StoreT,
-    ) -> Result<Library<StoreT>, FloriaError>
+        plugin_files: &[PluginFile],
+    ) -> Result<Library<StoreT>, MainError>
     where
         StoreT: Clone + Send + Store,
     {
@@ -37,6 +42,12 @@ impl Compile {
             include_bytes!("../../../assets/wasm/puccini_plugin_tosca_2_0_functions.wasm"),
         )?;
 
+        for plugin_file in plugin_files {
+            tracing::info!(id = plugin_file.id.to_string(), path = plugin_file.path.display().to_string(), "plugin");
+            let bytes = fs::read(&plugin_file.path)?;
+            library.add_dispatch_plugin(plugin_file.id.clone(), &bytes)?;
+        }
+
         Ok(library)
     }
 }
@@ -0,0 +1,193 @@
+use super::{super::cli::*, site::*};
+
+use {puccini_tosca::grammar::*, std::fmt::Write};
+
+const FORMAT: DocFormat = DocFormat::HTML;
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; } \
+table { border-collapse: collapse; } \
+th, td { border: 1px solid #ccc; padding: 0.25em 0.5em; text-align: left; vertical-align: top; } \
+.inherited { color: #777; }";
+
+impl ReferenceSite {
+    /// To HTML index page.
+    pub fn to_html_index(&self) -> String {
+        let mut html = String::default();
+
+        write_html_head(&mut html, "Types");
+        writeln!(html, "<h1>Types</h1>").expect("write");
+
+        for (entity_kind_name, types) in self.by_entity_kind() {
+            writeln!(html, "<h2>{}</h2>\n<ul>", escape(entity_kind_name)).expect("write");
+
+            for type_documentation in types {
+                let info = &type_documentation.info;
+                write!(html, "<li>{}", self.html_link(&info.type_id)).expect("write");
+                if let Some(description) = &info.description {
+                    write!(html, ": {}", escape(description.lines().next().unwrap_or_default())).expect("write");
+                }
+                writeln!(html, "</li>").expect("write");
+            }
+
+            writeln!(html, "</ul>").expect("write");
+        }
+
+        writeln!(html, "</body>\n</html>").expect("write");
+        html
+    }
+
+    /// To HTML type page.
+    pub fn to_html_page(&self, type_documentation: &TypeDocumentation) -> String {
+        let info = &type_documentation.info;
+        let full_name = info.full_name.to_string();
+        let mut html = String::default();
+
+        write_html_head(&mut html, &format!("{}: {}", info.entity_kind_name, full_name));
+        writeln!(html, "<h1>{}: {}</h1>", escape(&info.entity_kind_name), escape(&full_name)).expect("write");
+        writeln!(html, "<p><a href=\"{}.{}\">Index</a></p>", INDEX_NAME, FORMAT.extension()).expect("write");
+
+        writeln!(html, "<ul>\n<li>Source: <code>{}</code></li>", escape(&info.type_id.source_id.to_string()))
+            .expect("write");
+        if let Some(version) = &info.version {
+            writeln!(html, "<li>Version: <code>{}</code></li>", escape(version)).expect("write");
+        }
+        writeln!(html, "</ul>").expect("write");
+
+        if let Some(description) = &info.description {
+            writeln!(html, "<p>{}</p>", paragraphs(description)).expect("write");
+        }
+
+        // Derivation chain, from the root
+        if !type_documentation.ancestors.is_empty() {
+            writeln!(html, "<h2>Derived From</h2>").expect("write");
+            let mut chain: Vec<_> =
+                type_documentation.ancestors.iter().rev().map(|type_id| self.html_link(type_id)).collect();
+            chain.push(format!("<strong>{}</strong>", escape(&full_name)));
+            writeln!(html, "<p>{}</p>", chain.join(" &rarr; ")).expect("write");
+        }
+
+        if let Some(children) = self.children.get(&info.type_id) {
+            writeln!(html, "<h2>Derived Types</h2>\n<ul>").expect("write");
+            for child in children {
+                writeln!(html, "<li>{}</li>", self.html_link(child)).expect("write");
+            }
+            writeln!(html, "</ul>").expect("write");
+        }
+
+        if !type_documentation.metadata.is_empty() {
+            writeln!(html, "<h2>Metadata</h2>\n<table>\n<tr><th>Key</th><th>Value</th></tr>").expect("write");
+            for (key, value) in &type_documentation.metadata {
+                writeln!(html, "<tr><td>{}</td><td>{}</td></tr>", escape(key), escape(value)).expect("write");
+            }
+            writeln!(html, "</table>").expect("write");
+        }
+
+        for section in &type_documentation.sections {
+            self.write_html_section(&mut html, &info.type_id, section);
+        }
+
+        writeln!(html, "</body>\n</html>").expect("write");
+        html
+    }
+
+    fn write_html_section(&self, html: &mut String, type_id: &TypeID, section: &DocumentationSection) {
+        let has_required = section.members.iter().any(|member| member.required.is_some());
+        let has_default = section.members.iter().any(|member| member.default.is_some());
+
+        writeln!(html, "<h2>{}</h2>\n<table>", section.title).expect("write");
+
+        write!(html, "<tr><th>Name</th><th>Type</th>").expect("write");
+        if has_required {
+            write!(html, "<th>Required</th>").expect("write");
+        }
+        if has_default {
+            write!(html, "<th>Default</th>").expect("write");
+        }
+        writeln!(html, "<th>Declared By</th><th>Description</th></tr>").expect("write");
+
+        for member in &section.members {
+            let inherited = member.declared_by != *type_id;
+            let types: Vec<_> = member.types.iter().map(|reference| self.html_reference(reference)).collect();
+
+            write!(
+                html,
+                "<tr{}><td><code>{}</code></td><td>{}</td>",
+                if inherited { " class=\"inherited\"" } else { "" },
+                escape(&member.name),
+                types.join(", ")
+            )
+            .expect("write");
+
+            if has_required {
+                let required = member.required.map(|required| if required { "yes" } else { "no" });
+                write!(html, "<td>{}</td>", required.unwrap_or_default()).expect("write");
+            }
+
+            if has_default {
+                match &member.default {
+                    Some(default) => write!(html, "<td><code>{}</code></td>", escape(default)),
+                    None => write!(html, "<td></td>"),
+                }
+                .expect("write");
+            }
+
+            let declared_by = if inherited {
+                format!("inherited from {}", self.html_link(&member.declared_by))
+            } else {
+                "this type".into()
+            };
+            match &member.overrides {
+                Some(overrides) => write!(html, "<td>{} (overrides {})</td>", declared_by, self.html_link(overrides)),
+                None => write!(html, "<td>{}</td>", declared_by),
+            }
+            .expect("write");
+
+            let description = member.description.as_ref().map(|description| escape(description));
+            writeln!(html, "<td>{}</td></tr>", description.unwrap_or_default()).expect("write");
+        }
+
+        writeln!(html, "</table>").expect("write");
+    }
+
+    fn html_link(&self, type_id: &TypeID) -> String {
+        let name = escape(&self.name(type_id));
+        match self.link(type_id, &FORMAT) {
+            Some(link) => format!("<a href=\"{}\">{}</a>", escape(&link), name),
+            None => name,
+        }
+    }
+
+    fn html_reference(&self, reference: &TypeReference) -> String {
+        match &reference.type_id {
+            Some(type_id) if self.page_names.contains_key(type_id) => self.html_link(type_id),
+            _ => escape(&reference.full_name.to_string()),
+        }
+    }
+}
+
+fn write_html_head(html: &mut String, title: &str) {
+    writeln!(html, "<!DOCTYPE html>\n<html>\n<head>").expect("write");
+    writeln!(html, "<meta charset=\"utf-8\">").expect("write");
+    writeln!(html, "<title>{}</title>", escape(title)).expect("write");
+    writeln!(html, "<style>{}</style>", STYLE).expect("write");
+    writeln!(html, "</head>\n<body>").expect("write");
+}
+
+// Blank lines separate paragraphs
+fn paragraphs(text: &str) -> String {
+    escape(text.trim()).replace("\n\n", "</p>\n<p>")
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
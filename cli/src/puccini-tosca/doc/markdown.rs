@@ -0,0 +1,161 @@
+use super::{super::cli::*, site::*};
+
+use {puccini_tosca::grammar::*, std::fmt::Write};
+
+const FORMAT: DocFormat = DocFormat::Markdown;
+
+impl ReferenceSite {
+    /// To Markdown index page.
+    pub fn to_markdown_index(&self) -> String {
+        let mut markdown = String::default();
+
+        writeln!(markdown, "# Types").expect("write");
+
+        for (entity_kind_name, types) in self.by_entity_kind() {
+            writeln!(markdown, "\n## {}\n", entity_kind_name).expect("write");
+
+            for type_documentation in types {
+                let info = &type_documentation.info;
+                write!(markdown, "* {}", self.markdown_link(&info.type_id)).expect("write");
+                if let Some(description) = &info.description {
+                    write!(markdown, ": {}", first_line(description)).expect("write");
+                }
+                writeln!(markdown).expect("write");
+            }
+        }
+
+        markdown
+    }
+
+    /// To Markdown type page.
+    pub fn to_markdown_page(&self, type_documentation: &TypeDocumentation) -> String {
+        let info = &type_documentation.info;
+        let mut markdown = String::default();
+
+        writeln!(markdown, "# {}: {}\n", info.entity_kind_name, escape(&info.full_name.to_string())).expect("write");
+        writeln!(markdown, "[Index]({}.{})\n", INDEX_NAME, FORMAT.extension()).expect("write");
+        writeln!(markdown, "* Source: `{}`", info.type_id.source_id).expect("write");
+        if let Some(version) = &info.version {
+            writeln!(markdown, "* Version: `{}`", version).expect("write");
+        }
+
+        if let Some(description) = &info.description {
+            writeln!(markdown, "\n{}", description).expect("write");
+        }
+
+        // Derivation chain, from the root
+        if !type_documentation.ancestors.is_empty() {
+            writeln!(markdown, "\n## Derived From\n").expect("write");
+            let mut chain: Vec<_> =
+                type_documentation.ancestors.iter().rev().map(|type_id| self.markdown_link(type_id)).collect();
+            chain.push(format!("**{}**", escape(&info.full_name.to_string())));
+            writeln!(markdown, "{}", chain.join(" → ")).expect("write");
+        }
+
+        if let Some(children) = self.children.get(&info.type_id) {
+            writeln!(markdown, "\n## Derived Types\n").expect("write");
+            for child in children {
+                writeln!(markdown, "* {}", self.markdown_link(child)).expect("write");
+            }
+        }
+
+        if !type_documentation.metadata.is_empty() {
+            writeln!(markdown, "\n## Metadata\n").expect("write");
+            writeln!(markdown, "| Key | Value |\n| --- | --- |").expect("write");
+            for (key, value) in &type_documentation.metadata {
+                writeln!(markdown, "| {} | {} |", escape_cell(key), escape_cell(value)).expect("write");
+            }
+        }
+
+        for section in &type_documentation.sections {
+            self.write_markdown_section(&mut markdown, &info.type_id, section);
+        }
+
+        markdown
+    }
+
+    fn write_markdown_section(&self, markdown: &mut String, type_id: &TypeID, section: &DocumentationSection) {
+        let has_required = section.members.iter().any(|member| member.required.is_some());
+        let has_default = section.members.iter().any(|member| member.default.is_some());
+
+        writeln!(markdown, "\n## {}\n", section.title).expect("write");
+
+        let mut headers = vec!["Name", "Type"];
+        if has_required {
+            headers.push("Required");
+        }
+        if has_default {
+            headers.push("Default");
+        }
+        headers.extend(["Declared By", "Description"]);
+
+        writeln!(markdown, "| {} |", headers.join(" | ")).expect("write");
+        writeln!(markdown, "|{}", " --- |".repeat(headers.len())).expect("write");
+
+        for member in &section.members {
+            let types: Vec<_> = member.types.iter().map(|reference| self.markdown_reference(reference)).collect();
+
+            let mut cells = vec![format!("`{}`", member.name), types.join(", ")];
+            if has_required {
+                cells.push(member.required.map(|required| yes_or_no(required).into()).unwrap_or_default());
+            }
+            if has_default {
+                cells.push(
+                    member.default.as_ref().map(|default| format!("`{}`", escape_cell(default))).unwrap_or_default(),
+                );
+            }
+            let declared_by = if member.declared_by == *type_id {
+                "this type".into()
+            } else {
+                format!("inherited from {}", self.markdown_link(&member.declared_by))
+            };
+            cells.push(match &member.overrides {
+                Some(overrides) => format!("{} (overrides {})", declared_by, self.markdown_link(overrides)),
+                None => declared_by,
+            });
+            cells.push(member.description.as_ref().map(|description| escape_cell(description)).unwrap_or_default());
+
+            writeln!(markdown, "| {} |", cells.join(" | ")).expect("write");
+        }
+    }
+
+    fn markdown_link(&self, type_id: &TypeID) -> String {
+        let name = escape(&self.name(type_id));
+        match self.link(type_id, &FORMAT) {
+            Some(link) => format!("[{}]({})", name, link),
+            None => name,
+        }
+    }
+
+    fn markdown_reference(&self, reference: &TypeReference) -> String {
+        match &reference.type_id {
+            Some(type_id) if self.page_names.contains_key(type_id) => self.markdown_link(type_id),
+            _ => escape(&reference.full_name.to_string()),
+        }
+    }
+}
+
+fn yes_or_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+// Escape Markdown inline syntax
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '<' | '>' | '`' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Table cells must be on one line
+fn escape_cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace('\n', "<br>")
+}
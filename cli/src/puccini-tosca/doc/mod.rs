@@ -0,0 +1,49 @@
+mod html;
+mod markdown;
+mod site;
+
+use {
+    self::site::*,
+    super::{cli::*, errors::*, utils::*},
+};
+
+use {
+    compris::annotate::*,
+    kutil::{
+        cli::{depict::*, log::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::grammar::*,
+};
+
+impl Doc {
+    /// Run doc subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        let url_context = url_context()?;
+        let source_id =
+            SourceID::url_or_default(self.input_path_or_url.clone().map(|input_path_or_url| input_path_or_url.into()));
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+        let documentation = catalog.type_documentation(&source_id, &mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            if !self.quiet {
+                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        ReferenceSite::new(documentation).write(&self.output_path, &self.doc_format)?;
+
+        Ok(())
+    }
+}
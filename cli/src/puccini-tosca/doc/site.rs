@@ -0,0 +1,125 @@
+use super::super::cli::*;
+
+use {
+    kutil::std::{collections::*, immutable::*},
+    puccini_tosca::grammar::*,
+    std::{fs, io, path::*},
+};
+
+/// Index file name without extension.
+pub const INDEX_NAME: &str = "index";
+
+//
+// ReferenceSite
+//
+
+/// Static reference documentation site with a page per type.
+#[derive(Clone, Debug, Default)]
+pub struct ReferenceSite {
+    /// Documentation, sorted by entity kind and full name.
+    pub documentation: Vec<TypeDocumentation>,
+
+    /// Page names (without extension).
+    pub page_names: FastHashMap<TypeID, String>,
+
+    /// Derived type IDs.
+    pub children: FastHashMap<TypeID, Vec<TypeID>>,
+}
+
+impl ReferenceSite {
+    /// Constructor.
+    pub fn new(documentation: Vec<TypeDocumentation>) -> Self {
+        let mut page_names = FastHashMap::<TypeID, String>::default();
+        let mut children = FastHashMap::<TypeID, Vec<TypeID>>::default();
+
+        for type_documentation in &documentation {
+            let info = &type_documentation.info;
+
+            let mut page_name = format!("{}.{}", info.entity_kind_name, safe_name(&info.full_name.to_string()));
+            if page_names.values().any(|other| *other == page_name) {
+                page_name = format!("{}.{}", page_name, page_names.len());
+            }
+            page_names.insert(info.type_id.clone(), page_name);
+
+            if let Some(parent_type_id) = &info.parent_type_id {
+                children.entry(parent_type_id.clone()).or_default().push(info.type_id.clone());
+            }
+        }
+
+        Self { documentation, page_names, children }
+    }
+
+    /// Write all pages to a directory.
+    pub fn write(&self, directory: &Path, format: &DocFormat) -> io::Result<()> {
+        fs::create_dir_all(directory)?;
+
+        let index = match format {
+            DocFormat::Markdown => self.to_markdown_index(),
+            DocFormat::HTML => self.to_html_index(),
+        };
+        write_page(directory, INDEX_NAME, format, &index)?;
+
+        for type_documentation in &self.documentation {
+            if let Some(page_name) = self.page_names.get(&type_documentation.info.type_id) {
+                let page = match format {
+                    DocFormat::Markdown => self.to_markdown_page(type_documentation),
+                    DocFormat::HTML => self.to_html_page(type_documentation),
+                };
+                write_page(directory, page_name, format, &page)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Link to a type's page, if it has one.
+    pub fn link(&self, type_id: &TypeID, format: &DocFormat) -> Option<String> {
+        self.page_names.get(type_id).map(|page_name| format!("{}.{}", page_name, format.extension()))
+    }
+
+    /// Display name of a type.
+    ///
+    /// The full name if it is documented, otherwise the name.
+    pub fn name(&self, type_id: &TypeID) -> String {
+        self.documentation
+            .iter()
+            .find(|type_documentation| type_documentation.info.type_id == *type_id)
+            .map(|type_documentation| type_documentation.info.full_name.to_string())
+            .unwrap_or_else(|| type_id.name.to_string())
+    }
+
+    /// Documentation grouped by entity kind name.
+    pub fn by_entity_kind(&self) -> Vec<(&ByteString, Vec<&TypeDocumentation>)> {
+        let mut kinds = Vec::<(&ByteString, Vec<&TypeDocumentation>)>::default();
+
+        for type_documentation in &self.documentation {
+            let entity_kind_name = &type_documentation.info.entity_kind_name;
+            match kinds.last_mut() {
+                Some((last_entity_kind_name, types)) if *last_entity_kind_name == entity_kind_name => {
+                    types.push(type_documentation)
+                }
+
+                _ => kinds.push((entity_kind_name, vec![type_documentation])),
+            }
+        }
+
+        kinds
+    }
+}
+
+fn write_page(directory: &Path, page_name: &str, format: &DocFormat, content: &str) -> io::Result<()> {
+    let path = directory.join(format!("{}.{}", page_name, format.extension()));
+    tracing::info!(path = path.display().to_string(), "writing");
+    fs::write(path, content)
+}
+
+// Namespace delimiters become dots, anything else unsafe in a file name becomes an underscore
+fn safe_name(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            ':' => '.',
+            c if c.is_ascii_alphanumeric() || (c == '-') || (c == '_') || (c == '.') => c,
+            _ => '_',
+        })
+        .collect()
+}
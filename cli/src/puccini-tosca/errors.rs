@@ -25,6 +25,19 @@ pub enum MainError {
     #[error("plugin: {0}")]
     Plugin(#[from] floria::plugins::PluginError),
 
+    #[error("lint configuration: {0}")]
+    LintConfiguration(String),
+
+    #[error("query: {0}")]
+    Query(String),
+
+    #[error("REPL: {0}")]
+    Repl(String),
+
+    #[cfg(feature = "repl")]
+    #[error("readline: {0}")]
+    Readline(#[from] rustyline::error::ReadlineError),
+
     #[error("URL: {0}")]
     URL(#[from] UrlError),
 
@@ -0,0 +1,55 @@
+use super::{cli::*, errors::*, utils::*};
+
+use {
+    compris::annotate::*,
+    kutil::{
+        cli::{depict::*, log::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::grammar::*,
+};
+
+impl Explain {
+    /// Run explain subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        let full_name: FullName = self
+            .name
+            .parse()
+            .map_err(|error| ExitError::new(1, Some(format!("invalid name: {}: {}", self.name, error))))?;
+
+        let url_context = url_context()?;
+        let source_id =
+            SourceID::url_or_default(self.input_path_or_url.clone().map(|input_path_or_url| input_path_or_url.into()));
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            if !self.quiet {
+                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        let entity_kind = catalog
+            .source_entity_kinds::<WithAnnotations>(&source_id)?
+            .find(&self.kind)
+            .ok_or_else(|| ExitError::new(1, Some(format!("unknown entity kind: {}", self.kind))))?;
+
+        let explanation = catalog.explanation::<WithAnnotations>(entity_kind, &full_name, &source_id)?;
+
+        if !self.quiet {
+            explanation.print_default_depiction();
+        }
+
+        Ok(())
+    }
+}
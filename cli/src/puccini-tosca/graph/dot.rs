@@ -0,0 +1,160 @@
+use super::graph::*;
+
+use std::fmt::Write;
+
+const INDENTATION: &str = "    ";
+
+impl Graph {
+    /// To Graphviz DOT.
+    ///
+    /// Nodes are records with a field per capability, so that edges can point at capabilities.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::default();
+
+        writeln!(dot, "digraph topology {{").expect("write");
+        writeln!(dot, "{}rankdir=LR;", INDENTATION).expect("write");
+        writeln!(dot, "{}node [shape=record];", INDENTATION).expect("write");
+
+        for node in &self.nodes {
+            match node.kind {
+                GraphNodeKind::Node => {
+                    let mut label = escape_record(&node.label);
+                    if !node.ports.is_empty() {
+                        let ports: Vec<_> = node
+                            .ports
+                            .iter()
+                            .enumerate()
+                            .map(|(index, port)| format!("<p{}> {}", index, escape_record(port)))
+                            .collect();
+                        label = format!("{{{}|{{{}}}}}", label, ports.join("|"));
+                    }
+
+                    writeln!(dot, "{}{} [label={}];", INDENTATION, quote(&node.id), quote(&label)).expect("write");
+                }
+
+                GraphNodeKind::Placeholder => {
+                    writeln!(
+                        dot,
+                        "{}{} [label={}, shape=ellipse, style=dashed];",
+                        INDENTATION,
+                        quote(&node.id),
+                        quote(&node.label)
+                    )
+                    .expect("write");
+                }
+            }
+        }
+
+        for cluster in self.top_clusters() {
+            self.write_dot_cluster(&mut dot, cluster, 1);
+        }
+
+        for edge in &self.edges {
+            let mut target = quote(&edge.target);
+            if let Some(target_port) = &edge.target_port
+                && let Some(node) = self.get_node(&edge.target)
+                && let Some(index) = node.ports.iter().position(|port| port == target_port)
+            {
+                target = format!("{}:p{}", target, index);
+            }
+
+            writeln!(dot, "{}{} -> {} [label={}];", INDENTATION, quote(&edge.source), target, quote(&edge.label))
+                .expect("write");
+        }
+
+        writeln!(dot, "}}").expect("write");
+
+        dot
+    }
+
+    fn write_dot_cluster(&self, dot: &mut String, cluster: &GraphCluster, depth: usize) {
+        let indentation = INDENTATION.repeat(depth);
+
+        // Graphviz treats subgraphs as clusters only if their names start with "cluster"
+        writeln!(dot, "{}subgraph {} {{", indentation, quote(&format!("cluster:{}", cluster.id))).expect("write");
+        writeln!(dot, "{}{}label={};", indentation, INDENTATION, quote(&cluster.label())).expect("write");
+
+        let style = match cluster.kind {
+            GraphClusterKind::Group => "dashed",
+            GraphClusterKind::Policy => "dotted",
+        };
+        writeln!(dot, "{}{}style={};", indentation, INDENTATION, style).expect("write");
+
+        for node_id in &cluster.node_ids {
+            writeln!(dot, "{}{}{};", indentation, INDENTATION, quote(node_id)).expect("write");
+        }
+
+        for cluster_id in &cluster.cluster_ids {
+            if let Some(nested) = self.get_cluster(cluster_id) {
+                self.write_dot_cluster(dot, nested, depth + 1);
+            }
+        }
+
+        writeln!(dot, "{}}}", indentation).expect("write");
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Record labels have their own special characters
+fn escape_record(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '{' | '}' | '|' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn to_dot() {
+        assert_eq!(
+            graph().to_dot(),
+            r#"digraph topology {
+    rankdir=LR;
+    node [shape=record];
+    "app" [label="app"];
+    "db" [label="{db|{<p0> endpoint|<p1> admin}}"];
+    "placeholder:Database" [label="Database", shape=ellipse, style=dashed];
+    subgraph "cluster:policy:scale" {
+        label="policy: scale";
+        style=dotted;
+        subgraph "cluster:group:tier" {
+            label="group: tier";
+            style=dashed;
+            "app";
+        }
+    }
+    subgraph "cluster:policy:place" {
+        label="policy: place";
+        style=dotted;
+        "db";
+    }
+    "app" -> "db":p0 [label="database"];
+    "app" -> "placeholder:Database" [label="backup"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(quote(r#"say "hi" \ bye"#), r#""say \"hi\" \\ bye""#);
+        assert_eq!(escape_record("{a|b} <c>"), r"\{a\|b\} \<c\>");
+
+        // Record escapes are quoted, too
+        let mut graph = Graph::default();
+        let mut node = GraphNode::new("a|b".into(), "a|b".into(), GraphNodeKind::Node);
+        node.ports = vec!["c>".into()];
+        graph.nodes.push(node);
+        assert!(graph.to_dot().contains(r#""a|b" [label="{a\\|b|{<p0> c\\>}}"];"#));
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::*;
+
+//
+// GraphFormat
+//
+
+/// Graph format.
+#[derive(Clone, Copy, Debug)]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    DOT,
+
+    /// Mermaid flowchart.
+    Mermaid,
+}
+
+//
+// Graph
+//
+
+/// Topology graph.
+///
+/// Node templates (or nodes) are boxes with their capabilities as ports, requirements are labelled
+/// edges, and groups and policies are clusters.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    /// Nodes.
+    pub nodes: Vec<GraphNode>,
+
+    /// Edges.
+    pub edges: Vec<GraphEdge>,
+
+    /// Clusters.
+    pub clusters: Vec<GraphCluster>,
+}
+
+impl Graph {
+    /// Get node.
+    pub fn get_node(&self, id: &str) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Add a placeholder node if it doesn't exist yet.
+    ///
+    /// Returns its ID.
+    pub fn add_placeholder(&mut self, name: &str) -> String {
+        let id = format!("placeholder:{}", name);
+        if self.get_node(&id).is_none() {
+            self.nodes.push(GraphNode::new(id.clone(), name.into(), GraphNodeKind::Placeholder));
+        }
+        id
+    }
+
+    /// Add the node to the cluster of the first of its groups.
+    ///
+    /// Graphviz and Mermaid do not support overlapping clusters.
+    pub fn add_to_cluster(&mut self, node_id: &str, group_names: &[String]) {
+        for group_name in group_names {
+            if let Some(cluster) = self
+                .clusters
+                .iter_mut()
+                .find(|cluster| (cluster.kind == GraphClusterKind::Group) && (cluster.name == *group_name))
+            {
+                cluster.node_ids.push(node_id.into());
+                return;
+            }
+        }
+    }
+
+    /// Assign the policy targets to the policy clusters.
+    ///
+    /// The targets are mapped by policy name and the node IDs by node template name. Targets can be
+    /// node templates or groups. Only targets that are not already in a cluster are assigned.
+    pub fn assign_policy_targets(
+        &mut self,
+        targets: &BTreeMap<String, Vec<String>>,
+        node_ids: &BTreeMap<String, Vec<String>>,
+    ) {
+        let mut clustered: BTreeSet<String> =
+            self.clusters.iter().flat_map(|cluster| cluster.node_ids.iter().cloned()).collect();
+        let mut nested = BTreeSet::<String>::default();
+
+        for index in 0..self.clusters.len() {
+            if self.clusters[index].kind != GraphClusterKind::Policy {
+                continue;
+            }
+
+            let Some(policy_targets) = targets.get(&self.clusters[index].name) else {
+                continue;
+            };
+
+            for target in policy_targets {
+                let group_id = self
+                    .clusters
+                    .iter()
+                    .find(|cluster| (cluster.kind == GraphClusterKind::Group) && (cluster.name == *target))
+                    .map(|cluster| cluster.id.clone());
+
+                match group_id {
+                    Some(group_id) => {
+                        if nested.insert(group_id.clone()) {
+                            self.clusters[index].cluster_ids.push(group_id);
+                        }
+                    }
+
+                    None => {
+                        for node_id in node_ids.get(target).into_iter().flatten() {
+                            if clustered.insert(node_id.clone()) {
+                                self.clusters[index].node_ids.push(node_id.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Top-level clusters.
+    pub fn top_clusters(&self) -> Vec<&GraphCluster> {
+        let nested: BTreeSet<_> = self.clusters.iter().flat_map(|cluster| cluster.cluster_ids.iter()).collect();
+        self.clusters.iter().filter(|cluster| !nested.contains(&cluster.id)).collect()
+    }
+
+    /// Get cluster.
+    pub fn get_cluster(&self, id: &str) -> Option<&GraphCluster> {
+        self.clusters.iter().find(|cluster| cluster.id == id)
+    }
+
+    /// To format.
+    pub fn to_format(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::DOT => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+        }
+    }
+}
+
+//
+// GraphNode
+//
+
+/// Graph node.
+#[derive(Clone, Debug)]
+pub struct GraphNode {
+    /// ID.
+    pub id: String,
+
+    /// Label.
+    pub label: String,
+
+    /// Kind.
+    pub kind: GraphNodeKind,
+
+    /// Ports (capability names).
+    pub ports: Vec<String>,
+}
+
+impl GraphNode {
+    /// Constructor.
+    pub fn new(id: String, label: String, kind: GraphNodeKind) -> Self {
+        Self { id, label, kind, ports: Default::default() }
+    }
+}
+
+//
+// GraphNodeKind
+//
+
+/// Graph node kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphNodeKind {
+    /// Node template or node.
+    Node,
+
+    /// Requirement target that is not a node template, e.g. a node type.
+    Placeholder,
+}
+
+//
+// GraphEdge
+//
+
+/// Graph edge.
+#[derive(Clone, Debug)]
+pub struct GraphEdge {
+    /// Source node ID.
+    pub source: String,
+
+    /// Target node ID.
+    pub target: String,
+
+    /// Target port (capability name).
+    pub target_port: Option<String>,
+
+    /// Label (requirement name).
+    pub label: String,
+}
+
+//
+// GraphCluster
+//
+
+/// Graph cluster.
+#[derive(Clone, Debug)]
+pub struct GraphCluster {
+    /// ID.
+    pub id: String,
+
+    /// Name of the group or policy.
+    pub name: String,
+
+    /// Kind.
+    pub kind: GraphClusterKind,
+
+    /// Node IDs.
+    pub node_ids: Vec<String>,
+
+    /// Nested cluster IDs.
+    pub cluster_ids: Vec<String>,
+}
+
+impl GraphCluster {
+    /// Constructor.
+    pub fn new(name: String, kind: GraphClusterKind) -> Self {
+        let id = match kind {
+            GraphClusterKind::Group => format!("group:{}", name),
+            GraphClusterKind::Policy => format!("policy:{}", name),
+        };
+
+        Self { id, name, kind, node_ids: Default::default(), cluster_ids: Default::default() }
+    }
+
+    /// Label.
+    pub fn label(&self) -> String {
+        match self.kind {
+            GraphClusterKind::Group => format!("group: {}", self.name),
+            GraphClusterKind::Policy => format!("policy: {}", self.name),
+        }
+    }
+}
+
+//
+// GraphClusterKind
+//
+
+/// Graph cluster kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphClusterKind {
+    /// Group.
+    Group,
+
+    /// Policy.
+    Policy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn add_placeholder_once() {
+        let mut graph = Graph::default();
+        assert_eq!(graph.add_placeholder("Database"), "placeholder:Database");
+        assert_eq!(graph.add_placeholder("Database"), "placeholder:Database");
+        assert_eq!(graph.nodes.len(), 1);
+        assert_eq!(graph.nodes[0].kind, GraphNodeKind::Placeholder);
+        assert_eq!(graph.nodes[0].label, "Database");
+    }
+
+    #[test]
+    fn add_to_first_existing_group() {
+        let graph = graph();
+        let tier = graph.get_cluster("group:tier").expect("cluster");
+        assert_eq!(tier.node_ids, ["app"]);
+    }
+
+    #[test]
+    fn assign_policy_targets() {
+        let graph = graph();
+
+        // Groups are nested
+        let scale = graph.get_cluster("policy:scale").expect("cluster");
+        assert_eq!(scale.cluster_ids, ["group:tier"]);
+        assert!(scale.node_ids.is_empty());
+
+        // Nodes that are already in a cluster are not assigned again
+        let place = graph.get_cluster("policy:place").expect("cluster");
+        assert_eq!(place.node_ids, ["db"]);
+        assert!(place.cluster_ids.is_empty());
+
+        let top_clusters: Vec<_> = graph.top_clusters().into_iter().map(|cluster| cluster.id.as_str()).collect();
+        assert_eq!(top_clusters, ["policy:scale", "policy:place"]);
+    }
+
+    #[test]
+    fn cluster_labels() {
+        assert_eq!(GraphCluster::new("tier".into(), GraphClusterKind::Group).label(), "group: tier");
+        assert_eq!(GraphCluster::new("scale".into(), GraphClusterKind::Policy).label(), "policy: scale");
+    }
+}
@@ -0,0 +1,135 @@
+use super::graph::*;
+
+use std::{collections::*, fmt::Write};
+
+const INDENTATION: &str = "    ";
+
+impl Graph {
+    /// To Mermaid flowchart.
+    ///
+    /// Mermaid has no ports, so capabilities are listed in the node label and edges name their
+    /// target capability in the edge label.
+    pub fn to_mermaid(&self) -> String {
+        // Mermaid IDs are restricted, so we number them
+        let ids: BTreeMap<_, _> =
+            self.nodes.iter().enumerate().map(|(index, node)| (node.id.as_str(), format!("n{}", index))).collect();
+        let id = |node_id: &str| ids.get(node_id).cloned().unwrap_or_default();
+
+        let mut mermaid = String::default();
+
+        writeln!(mermaid, "flowchart LR").expect("write");
+
+        for node in &self.nodes {
+            match node.kind {
+                GraphNodeKind::Node => {
+                    let mut label = escape(&node.label);
+                    for port in &node.ports {
+                        write!(label, "<br/>#9673; {}", escape(port)).expect("write");
+                    }
+
+                    writeln!(mermaid, "{}{}[\"{}\"]", INDENTATION, id(&node.id), label).expect("write");
+                }
+
+                GraphNodeKind::Placeholder => {
+                    writeln!(mermaid, "{}{}([\"{}\"])", INDENTATION, id(&node.id), escape(&node.label)).expect("write");
+                    writeln!(mermaid, "{}style {} stroke-dasharray: 5 5", INDENTATION, id(&node.id)).expect("write");
+                }
+            }
+        }
+
+        let cluster_ids: BTreeMap<_, _> = self
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(index, cluster)| (cluster.id.as_str(), format!("c{}", index)))
+            .collect();
+
+        for cluster in self.top_clusters() {
+            self.write_mermaid_cluster(&mut mermaid, cluster, &ids, &cluster_ids, 1);
+        }
+
+        for edge in &self.edges {
+            let label = match &edge.target_port {
+                Some(target_port) => format!("{} #8594; {}", escape(&edge.label), escape(target_port)),
+                None => escape(&edge.label),
+            };
+
+            writeln!(mermaid, "{}{} -- \"{}\" --> {}", INDENTATION, id(&edge.source), label, id(&edge.target))
+                .expect("write");
+        }
+
+        mermaid
+    }
+
+    fn write_mermaid_cluster(
+        &self,
+        mermaid: &mut String,
+        cluster: &GraphCluster,
+        ids: &BTreeMap<&str, String>,
+        cluster_ids: &BTreeMap<&str, String>,
+        depth: usize,
+    ) {
+        let indentation = INDENTATION.repeat(depth);
+        let cluster_id = cluster_ids.get(cluster.id.as_str()).cloned().unwrap_or_default();
+
+        writeln!(mermaid, "{}subgraph {} [\"{}\"]", indentation, cluster_id, escape(&cluster.label())).expect("write");
+
+        for node_id in &cluster.node_ids {
+            if let Some(id) = ids.get(node_id.as_str()) {
+                writeln!(mermaid, "{}{}{}", indentation, INDENTATION, id).expect("write");
+            }
+        }
+
+        for nested_id in &cluster.cluster_ids {
+            if let Some(nested) = self.get_cluster(nested_id) {
+                self.write_mermaid_cluster(mermaid, nested, ids, cluster_ids, depth + 1);
+            }
+        }
+
+        writeln!(mermaid, "{}end", indentation).expect("write");
+
+        if cluster.kind == GraphClusterKind::Policy {
+            writeln!(mermaid, "{}style {} stroke-dasharray: 2 2", indentation, cluster_id).expect("write");
+        }
+    }
+}
+
+// Mermaid has its own entity codes
+fn escape(text: &str) -> String {
+    text.replace('#', "#35;").replace('"', "#quot;").replace('<', "#lt;").replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn to_mermaid() {
+        assert_eq!(
+            graph().to_mermaid(),
+            r#"flowchart LR
+    n0["app"]
+    n1["db<br/>#9673; endpoint<br/>#9673; admin"]
+    n2(["Database"])
+    style n2 stroke-dasharray: 5 5
+    subgraph c1 ["policy: scale"]
+        subgraph c0 ["group: tier"]
+            n0
+        end
+    end
+    style c1 stroke-dasharray: 2 2
+    subgraph c2 ["policy: place"]
+        n1
+    end
+    style c2 stroke-dasharray: 2 2
+    n0 -- "database #8594; endpoint" --> n1
+    n0 -- "backup" --> n2
+"#
+        );
+    }
+
+    #[test]
+    fn escape_entities() {
+        assert_eq!(escape(r#"#1 "a" <b>"#), "#35;1 #quot;a#quot; #lt;b#gt;");
+    }
+}
@@ -0,0 +1,10 @@
+mod dot;
+mod graph;
+mod mermaid;
+mod to_graph;
+
+#[cfg(test)]
+mod test;
+
+#[allow(unused_imports)]
+pub use {graph::*, to_graph::*};
@@ -0,0 +1,47 @@
+use super::graph::*;
+
+use std::collections::*;
+
+/// Graph:
+///
+/// * "app" node in the "tier" group, which is targeted by the "scale" policy
+/// * "db" node with "endpoint" and "admin" ports, targeted by the "place" policy
+/// * "database" edge from "app" to the "endpoint" port of "db"
+/// * "backup" edge from "app" to a "Database" placeholder
+pub fn graph() -> Graph {
+    let mut graph = Graph::default();
+
+    graph.nodes.push(GraphNode::new("app".into(), "app".into(), GraphNodeKind::Node));
+
+    let mut db = GraphNode::new("db".into(), "db".into(), GraphNodeKind::Node);
+    db.ports = vec!["endpoint".into(), "admin".into()];
+    graph.nodes.push(db);
+
+    let placeholder = graph.add_placeholder("Database");
+
+    graph.edges.push(GraphEdge {
+        source: "app".into(),
+        target: "db".into(),
+        target_port: Some("endpoint".into()),
+        label: "database".into(),
+    });
+    graph.edges.push(GraphEdge {
+        source: "app".into(),
+        target: placeholder,
+        target_port: None,
+        label: "backup".into(),
+    });
+
+    graph.clusters.push(GraphCluster::new("tier".into(), GraphClusterKind::Group));
+    graph.clusters.push(GraphCluster::new("scale".into(), GraphClusterKind::Policy));
+    graph.clusters.push(GraphCluster::new("place".into(), GraphClusterKind::Policy));
+
+    graph.add_to_cluster("app", &["missing".into(), "tier".into()]);
+
+    let targets =
+        BTreeMap::from([("scale".into(), vec!["tier".into()]), ("place".into(), vec!["db".into(), "app".into()])]);
+    let node_ids = BTreeMap::from([("app".into(), vec!["app".into()]), ("db".into(), vec!["db".into()])]);
+    graph.assign_policy_targets(&targets, &node_ids);
+
+    graph
+}
@@ -0,0 +1,217 @@
+use super::{
+    super::{errors::*, metadata::*},
+    graph::*,
+};
+
+use {
+    floria::*,
+    puccini_tosca::dialect::tosca_2_0::{
+        CAPABILITY_NAME, GROUP_TEMPLATE_NAME, NODE_TEMPLATE_NAME, POLICY_TEMPLATE_NAME,
+    },
+    std::collections::*,
+};
+
+#[cfg(feature = "plugins")]
+use super::super::instances::*;
+
+//
+// ToGraph
+//
+
+/// To topology [Graph].
+pub trait ToGraph {
+    /// To topology [Graph].
+    fn to_graph<StoreT>(&self, store: &StoreT) -> Result<Graph, MainError>
+    where
+        StoreT: Store;
+}
+
+impl ToGraph for VertexTemplate {
+    // Requirement targets are not resolved in templates, so we use the names as written, and node
+    // types become placeholders
+    fn to_graph<StoreT>(&self, store: &StoreT) -> Result<Graph, MainError>
+    where
+        StoreT: Store,
+    {
+        let mut graph = Graph::default();
+        let mut node_ids = BTreeMap::<String, Vec<String>>::default();
+        let mut node_groups = Vec::default();
+        let mut policy_targets = BTreeMap::default();
+        let mut requirements = Vec::default();
+
+        for contained_vertex_template_id in &self.contained_vertex_template_ids {
+            let Some(vertex_template) = store.get_vertex_template(contained_vertex_template_id)? else {
+                continue;
+            };
+
+            let metadata = &vertex_template.template.metadata;
+            let name = vertex_template.template.id.id.to_string();
+
+            match get_tosca_entity(metadata).as_deref() {
+                Some(NODE_TEMPLATE_NAME) => {
+                    let mut node = GraphNode::new(name.clone(), name.clone(), GraphNodeKind::Node);
+
+                    for id in &vertex_template.contained_vertex_template_ids {
+                        match id.kind {
+                            Kind::VertexTemplate => {
+                                if let Some(contained) = store.get_vertex_template(id)?
+                                    && is_tosca_entity(&contained.template.metadata, CAPABILITY_NAME)
+                                {
+                                    node.ports.push(contained.template.id.id.to_string());
+                                }
+                            }
+
+                            Kind::EdgeTemplate => {
+                                if let Some(edge_template) = store.get_edge_template(id)? {
+                                    requirements.push((name.clone(), edge_template));
+                                }
+                            }
+
+                            _ => {}
+                        }
+                    }
+
+                    node_groups
+                        .push((name.clone(), group_names(&vertex_template.template.class_ids, store, &mut graph)?));
+                    node_ids.insert(name, vec![node.id.clone()]);
+                    graph.nodes.push(node);
+                }
+
+                Some(POLICY_TEMPLATE_NAME) => {
+                    policy_targets.insert(name.clone(), get_metadata_texts(metadata, "tosca:targets"));
+                    graph.clusters.push(GraphCluster::new(name, GraphClusterKind::Policy));
+                }
+
+                _ => {}
+            }
+        }
+
+        for (node_id, group_names) in node_groups {
+            graph.add_to_cluster(&node_id, &group_names);
+        }
+
+        for (source, edge_template) in requirements {
+            let metadata = &edge_template.template.metadata;
+
+            let Some(node) = get_metadata_text(metadata, "tosca:node") else {
+                continue;
+            };
+
+            let target = match node_ids.get(&node) {
+                Some(_) => node,
+                None => graph.add_placeholder(&node),
+            };
+
+            graph.edges.push(GraphEdge {
+                source,
+                target,
+                target_port: get_metadata_text(metadata, "tosca:capability"),
+                label: edge_template.template.id.id.to_string(),
+            });
+        }
+
+        graph.assign_policy_targets(&policy_targets, &node_ids);
+
+        Ok(graph)
+    }
+}
+
+#[cfg(feature = "plugins")]
+impl ToGraph for Vertex {
+    // Relationships target capabilities, so we map them back to their nodes
+    fn to_graph<StoreT>(&self, store: &StoreT) -> Result<Graph, MainError>
+    where
+        StoreT: Store,
+    {
+        let mut graph = Graph::default();
+        let mut node_ids = BTreeMap::<String, Vec<String>>::default();
+        let mut node_groups = Vec::default();
+        let mut policy_targets = BTreeMap::default();
+        let mut capabilities = BTreeMap::default();
+        let mut relationships = Vec::default();
+
+        for (name, node) in node_instances(self, store)? {
+            let id = node.instance.id.to_string();
+            let mut graph_node =
+                GraphNode::new(id.clone(), format!("{} ({})", name, node.instance.id.id), GraphNodeKind::Node);
+
+            for (capability_name, capability) in capability_instances(&node, store)? {
+                capabilities.insert(capability.instance.id.to_string(), (id.clone(), capability_name.clone()));
+                graph_node.ports.push(capability_name);
+            }
+
+            for outgoing_edge_id in &node.outgoing_edge_ids {
+                if let Some(edge) = store.get_edge(outgoing_edge_id)? {
+                    relationships.push((id.clone(), edge));
+                }
+            }
+
+            node_groups.push((id.clone(), group_names(&node.instance.class_ids, store, &mut graph)?));
+            node_ids.entry(name).or_default().push(id);
+            graph.nodes.push(graph_node);
+        }
+
+        for contained_vertex_id in &self.contained_vertex_ids {
+            if let Some(vertex) = store.get_vertex(contained_vertex_id)?
+                && is_tosca_entity(&vertex.instance.metadata, POLICY_TEMPLATE_NAME)
+                && let Some(origin_template_id) = &vertex.instance.origin_template_id
+            {
+                let name = origin_template_id.id.to_string();
+                policy_targets.insert(name.clone(), get_metadata_texts(&vertex.instance.metadata, "tosca:targets"));
+                graph.clusters.push(GraphCluster::new(name, GraphClusterKind::Policy));
+            }
+        }
+
+        for (node_id, group_names) in node_groups {
+            graph.add_to_cluster(&node_id, &group_names);
+        }
+
+        for (source, edge) in relationships {
+            let target_id = edge.target_vertex_id.to_string();
+
+            let (target, target_port) = match capabilities.get(&target_id) {
+                Some((node_id, capability_name)) => (node_id.clone(), Some(capability_name.clone())),
+                None => match graph.get_node(&target_id) {
+                    Some(_) => (target_id, None),
+                    None => (graph.add_placeholder(&target_id), None),
+                },
+            };
+
+            let label = match &edge.instance.origin_template_id {
+                Some(origin_template_id) => origin_template_id.id.to_string(),
+                None => edge.instance.id.id.to_string(),
+            };
+
+            graph.edges.push(GraphEdge { source, target, target_port, label });
+        }
+
+        graph.assign_policy_targets(&policy_targets, &node_ids);
+
+        Ok(graph)
+    }
+}
+
+// Adds clusters for new groups
+fn group_names<StoreT>(class_ids: &Vec<ID>, store: &StoreT, graph: &mut Graph) -> Result<Vec<String>, MainError>
+where
+    StoreT: Store,
+{
+    let mut group_names = Vec::default();
+
+    for class_id in class_ids {
+        if let Some(class) = store.get_class(class_id)?
+            && is_tosca_entity(&class.metadata, GROUP_TEMPLATE_NAME)
+        {
+            let name = class.id.id.to_string();
+
+            if !graph.clusters.iter().any(|cluster| (cluster.kind == GraphClusterKind::Group) && (cluster.name == name))
+            {
+                graph.clusters.push(GraphCluster::new(name.clone(), GraphClusterKind::Group));
+            }
+
+            group_names.push(name);
+        }
+    }
+
+    Ok(group_names)
+}
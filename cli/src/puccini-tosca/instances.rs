@@ -0,0 +1,71 @@
+use super::{errors::*, metadata::*};
+
+use {
+    floria::*,
+    puccini_tosca::dialect::tosca_2_0::{CAPABILITY_NAME, NODE_TEMPLATE_NAME},
+};
+
+/// Node instances contained in the service, with the names of their node templates.
+///
+/// In the order in which they were instantiated.
+pub fn node_instances<StoreT>(service: &Vertex, store: &StoreT) -> Result<Vec<(String, Vertex)>, MainError>
+where
+    StoreT: Store,
+{
+    let mut nodes = Vec::with_capacity(service.contained_vertex_ids.len());
+
+    for contained_vertex_id in &service.contained_vertex_ids {
+        if let Some(vertex) = store.get_vertex(contained_vertex_id)?
+            && is_tosca_entity(&vertex.instance.metadata, NODE_TEMPLATE_NAME)
+            && let Some(origin_template_id) = &vertex.instance.origin_template_id
+        {
+            nodes.push((origin_template_id.id.to_string(), vertex));
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// The first node instance created from a node template.
+pub fn find_node_instance<StoreT>(
+    service: &Vertex,
+    node_template_name: &str,
+    store: &StoreT,
+) -> Result<Option<Vertex>, MainError>
+where
+    StoreT: Store,
+{
+    Ok(node_instances(service, store)?.into_iter().find(|(name, _)| name == node_template_name).map(|(_, node)| node))
+}
+
+/// Vertices contained in a vertex, with the names of their templates.
+pub fn contained_instances<StoreT>(vertex: &Vertex, store: &StoreT) -> Result<Vec<(String, Vertex)>, MainError>
+where
+    StoreT: Store,
+{
+    let mut vertices = Vec::with_capacity(vertex.contained_vertex_ids.len());
+
+    for contained_vertex_id in &vertex.contained_vertex_ids {
+        if let Some(vertex) = store.get_vertex(contained_vertex_id)? {
+            let name = match &vertex.instance.origin_template_id {
+                Some(origin_template_id) => origin_template_id.id.to_string(),
+                None => vertex.instance.id.to_string(),
+            };
+
+            vertices.push((name, vertex));
+        }
+    }
+
+    Ok(vertices)
+}
+
+/// Capability instances contained in a node instance, with the names of their capabilities.
+pub fn capability_instances<StoreT>(node: &Vertex, store: &StoreT) -> Result<Vec<(String, Vertex)>, MainError>
+where
+    StoreT: Store,
+{
+    Ok(contained_instances(node, store)?
+        .into_iter()
+        .filter(|(_, vertex)| is_tosca_entity(&vertex.instance.metadata, CAPABILITY_NAME))
+        .collect())
+}
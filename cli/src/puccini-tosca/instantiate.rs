@@ -1,10 +1,15 @@
-use super::{cli::*, errors::*};
+use super::{cli::*, errors::*, plugins::*, trace::*};
 
 use {
+    compris::normal::*,
     floria::{plugins::*, *},
     kutil::std::error::*,
+    puccini_tosca_2_0_core::*,
+    std::{fs, io, path::*},
 };
 
+const TRACE_FILE_NAME: &str = ".trace-functions";
+
 // TODO:
 // TOSCA inputs
 // TOSCA outputs
@@ -17,6 +22,8 @@ impl Compile {
         service_template_id: &ID,
         directory: &Directory,
         store: &StoreT,
+        plugin_files: &[PluginFile],
+        function_traces: &mut FunctionTraces,
         errors: &mut Errors<FloriaError>,
     ) -> Result<Option<Vertex>, MainError>
     where
@@ -24,12 +31,23 @@ impl Compile {
     {
         tracing::info!(directory = directory.to_string(), template = service_template_id.to_string(), "instantiating");
 
-        let floria_service_template = store
+        let mut floria_service_template = store
             .get_vertex_template(service_template_id)?
             .ok_or_else(|| StoreError::ID(service_template_id.to_string()))?;
 
+        let artifacts_path = self.artifacts_path();
+        set_artifacts_path(&mut floria_service_template, &artifacts_path);
+
+        // The plugin appends dispatched calls to this file (it can write to the artifacts area)
+        let trace_path = artifacts_path.join(TRACE_FILE_NAME);
+        if self.trace_functions {
+            fs::create_dir_all(&artifacts_path)?;
+            remove_if_exists(&trace_path)?;
+            set_trace_functions_path(&mut floria_service_template, &trace_path);
+        }
+
         let environment = Environment::default();
-        let mut library = Self::library(environment, store.clone())?;
+        let mut library = Self::library(environment, store.clone(), plugin_files)?;
 
         let mut floria_instance = floria_service_template.instantiate(&directory, None, &mut library, errors)?;
 
@@ -39,6 +57,69 @@ impl Compile {
 
         // TODO: update edges, too!
 
+        if self.trace_functions {
+            *function_traces = FunctionTraces::read(&trace_path)?;
+            remove_if_exists(&trace_path)?;
+        }
+
+        if self.teardown {
+            Self::teardown_artifacts(&artifacts_path)?;
+        }
+
         Ok(Some(floria_instance))
     }
+
+    /// Local artifact deployment area.
+    pub fn artifacts_path(&self) -> PathBuf {
+        artifacts_path(self.artifacts_path.as_ref())
+    }
+
+    /// Remove the artifacts that were scheduled for removal.
+    pub fn teardown_artifacts(artifacts_path: &Path) -> io::Result<()> {
+        let teardown_path = artifacts_path.join(ARTIFACTS_TEARDOWN_FILE_NAME);
+
+        let teardown = match fs::read_to_string(&teardown_path) {
+            Ok(teardown) => teardown,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        for path in teardown.lines().filter(|path| !path.is_empty()) {
+            tracing::info!(path, "removing artifact");
+            remove_if_exists(Path::new(path))?;
+        }
+
+        fs::remove_file(teardown_path)
+    }
+}
+
+/// Local artifact deployment area.
+///
+/// Defaults to $PUCCINI_ARTIFACTS and then to a temporary directory.
+pub fn artifacts_path(artifacts_path: Option<&PathBuf>) -> PathBuf {
+    artifacts_path.cloned().unwrap_or_else(default_artifacts_path)
+}
+
+/// Set the local artifact deployment area for the plugins.
+///
+/// The $get_artifact function stages artifacts into this directory. The plugin reads it from the
+/// service metadata, which the instance gets from the service template.
+pub fn set_artifacts_path(service_template: &mut VertexTemplate, artifacts_path: &Path) {
+    service_template.template.metadata.into_insert(ARTIFACTS_METADATA_KEY, artifacts_path.display().to_string());
+}
+
+/// Set the function trace file for the plugins.
+///
+/// The plugin reads it from the service metadata, which the instance gets from the service
+/// template.
+pub fn set_trace_functions_path(service_template: &mut VertexTemplate, trace_path: &Path) {
+    service_template.template.metadata.into_insert(TRACE_FUNCTIONS_METADATA_KEY, trace_path.display().to_string());
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Ok(_) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error),
+    }
 }
@@ -0,0 +1,476 @@
+use super::{cli::*, errors::*, utils::*};
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, *},
+    kutil::{
+        cli::{depict::*, log::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::grammar::*,
+    std::{fs, path::*},
+};
+
+const CONFIGURATION_FILE_NAME: &str = "puccini-lint.yaml";
+
+impl Lint {
+    /// Run lint subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        let rules = self.rules()?;
+
+        let url_context = url_context()?;
+        let source_id =
+            SourceID::url_or_default(self.input_path_or_url.clone().map(|input_path_or_url| input_path_or_url.into()));
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+        let warnings = catalog.lint(&source_id, &rules, &mut tosca_errors)?;
+
+        if !self.quiet {
+            let mut first = true;
+
+            // Depict TOSCA errors
+
+            if let Err(tosca_errors) = tosca_errors.check() {
+                first = false;
+
+                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            }
+
+            // Depict warnings
+
+            if !warnings.is_empty() {
+                if !first {
+                    println!();
+                }
+
+                warnings.print_default_depiction();
+            }
+        }
+
+        let success = tosca_errors.is_empty() && !(self.deny_warnings && !warnings.is_empty());
+
+        return if success { Ok(()) } else { Err(ExitError::new(1, None).into()) };
+    }
+
+    /// Enabled rules.
+    ///
+    /// Starts with all rules enabled, then applies the configuration file, and then `--enable` and
+    /// `--disable`.
+    pub fn rules(&self) -> Result<LintRules, MainError> {
+        let mut rules = LintRules::default();
+
+        let configuration_path = match &self.configuration_path {
+            Some(configuration_path) => Some(configuration_path.clone()),
+            None => {
+                let configuration_path = PathBuf::from(CONFIGURATION_FILE_NAME);
+                if configuration_path.is_file() { Some(configuration_path) } else { None }
+            }
+        };
+
+        if let Some(configuration_path) = configuration_path {
+            tracing::info!(path = configuration_path.display().to_string(), "reading lint configuration");
+            read_configuration(&configuration_path, &mut rules)?;
+        }
+
+        for name in &self.enable {
+            rules.set(get_rule(name)?, true);
+        }
+
+        for name in &self.disable {
+            rules.set(get_rule(name)?, false);
+        }
+
+        Ok(rules)
+    }
+}
+
+// The configuration file is YAML:
+//
+// rules:
+//   unused-type: false
+//   redundant-default: true
+fn read_configuration(path: &Path, rules: &mut LintRules) -> Result<(), MainError> {
+    let mut file = fs::File::open(path)?;
+    let configuration: Variant<WithoutAnnotations> = Parser::new(Format::YAML)
+        .parse_reader(&mut file)
+        .map_err(|error| MainError::LintConfiguration(error.to_string()))?;
+
+    match configuration.into_get("rules") {
+        Some(Variant::Map(map)) => {
+            for (name, enabled) in &map.inner {
+                match (name, enabled) {
+                    (Variant::Text(name), Variant::Boolean(enabled)) => {
+                        rules.set(get_rule(&name.inner)?, enabled.inner)
+                    }
+
+                    _ => {
+                        return Err(MainError::LintConfiguration(format!(
+                            "rule must be a name mapped to a boolean: {}",
+                            name
+                        )));
+                    }
+                }
+            }
+        }
+
+        Some(_) => return Err(MainError::LintConfiguration("\"rules\" not a map".into())),
+
+        None => {}
+    }
+
+    Ok(())
+}
+
+fn get_rule(name: &str) -> Result<LintRule, MainError> {
+    LintRule::from_name(name).ok_or_else(|| {
+        let names: Vec<_> = LintRule::ALL.iter().map(|rule| rule.name()).collect();
+        MainError::LintConfiguration(format!("unknown rule: {:?}, must be one of: {}", name, names.join(", ")))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, kutil::cli::clap::*, std::env};
+
+    // Fresh directory for a test's files
+    fn directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("puccini-lint-{}-{}", name, std::process::id()));
+        _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).expect("create directory");
+        directory
+    }
+
+    // Writes the files and lints the first one with only the rule enabled
+    fn lint(name: &str, rule: LintRule, files: &[(&str, &str)]) -> Vec<String> {
+        let directory = directory(name);
+        for (file_name, content) in files {
+            fs::write(directory.join(file_name), content).expect("write file");
+        }
+
+        let source_id = SourceID::url_or_default(Some(directory.join(files[0].0).display().to_string().into()));
+        let rules = LintRules { enabled: [rule].into() };
+
+        let url_context = url_context().expect("URL context");
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut errors).expect("load");
+        catalog.complete_entities(&mut errors).expect("complete");
+        let warnings = catalog.lint(&source_id, &rules, &mut errors).expect("lint");
+        assert!(errors.is_empty());
+
+        warnings
+            .0
+            .into_iter()
+            .map(|warning| {
+                assert_eq!(warning.rule, rule);
+                warning.message
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unused_type() {
+        let warnings = lint(
+            "unused-type",
+            LintRule::UnusedType,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Used: {}
+  Unused: {}
+service_template:
+  node_templates:
+    server:
+      type: Used
+"#,
+            )],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].ends_with("is never used: Unused"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn unused_input() {
+        let warnings = lint(
+            "unused-input",
+            LintRule::UnusedInput,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Server:
+    properties:
+      name:
+        type: string
+service_template:
+  inputs:
+    used:
+      type: string
+    unused:
+      type: string
+  node_templates:
+    server:
+      type: Server
+      properties:
+        name: { $get_input: used }
+"#,
+            )],
+        );
+
+        assert_eq!(warnings, ["input is never read: unused"]);
+    }
+
+    #[test]
+    fn unused_repository() {
+        let warnings = lint(
+            "unused-repository",
+            LintRule::UnusedRepository,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+repositories:
+  used:
+    url: https://example.com/used
+  unused:
+    url: https://example.com/unused
+artifact_types:
+  Image: {}
+node_types:
+  Server:
+    artifacts:
+      image:
+        type: Image
+        repository: used
+        file: image.qcow2
+service_template:
+  node_templates:
+    server:
+      type: Server
+"#,
+            )],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].ends_with("is never used: unused"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn unused_import() {
+        let warnings = lint(
+            "unused-import",
+            LintRule::UnusedImport,
+            &[
+                (
+                    "main.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+imports:
+- namespace: used
+  url: used.yaml
+- namespace: unused
+  url: unused.yaml
+service_template:
+  node_templates:
+    server:
+      type: used:Server
+"#,
+                ),
+                (
+                    "used.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Server: {}
+"#,
+                ),
+                (
+                    "unused.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Client: {}
+"#,
+                ),
+            ],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("namespace \"unused\" is never used: "), "{}", warnings[0]);
+        assert!(warnings[0].ends_with("unused.yaml"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn undescribed_node_template() {
+        let warnings = lint(
+            "undescribed-node-template",
+            LintRule::UndescribedNodeTemplate,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Described:
+    description: Described type.
+  Undescribed: {}
+service_template:
+  node_templates:
+    described-template:
+      type: Undescribed
+      description: Described template.
+    described-type:
+      type: Described
+    undescribed:
+      type: Undescribed
+"#,
+            )],
+        );
+
+        assert_eq!(warnings, ["neither node template \"undescribed\" nor its type has a description"]);
+    }
+
+    #[test]
+    fn duplicate_metadata_key() {
+        let warnings = lint(
+            "duplicate-metadata-key",
+            LintRule::DuplicateMetadataKey,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Server:
+    metadata:
+      owner-name: a
+      Owner_Name: b
+      team: c
+"#,
+            )],
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("\"Server\" has duplicate metadata keys: "), "{}", warnings[0]);
+        assert!(warnings[0].contains("\"owner-name\""), "{}", warnings[0]);
+        assert!(warnings[0].contains("\"Owner_Name\""), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn redundant_default() {
+        let warnings = lint(
+            "redundant-default",
+            LintRule::RedundantDefault,
+            &[(
+                "main.yaml",
+                r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Base:
+    properties:
+      port:
+        type: integer
+        default: 80
+      host:
+        type: string
+        default: localhost
+  Derived:
+    derived_from: Base
+    properties:
+      port:
+        type: integer
+        default: 80
+      host:
+        type: string
+        default: example.com
+service_template:
+  node_templates:
+    server:
+      type: Base
+      properties:
+        port: 80
+        host: example.com
+"#,
+            )],
+        );
+
+        // Completion order is not guaranteed
+        let mut warnings = warnings;
+        warnings.sort();
+
+        assert_eq!(
+            warnings,
+            [
+                "property \"port\" is assigned the default of its definition",
+                "property \"port\" redeclares the default of its parent without changing it",
+            ]
+        );
+    }
+
+    #[test]
+    fn configuration() {
+        let path = directory("configuration").join(CONFIGURATION_FILE_NAME);
+        fs::write(&path, "rules:\n  unused-type: false\n  redundant-default: true\n").expect("write file");
+
+        let mut rules = LintRules::default();
+        read_configuration(&path, &mut rules).expect("read configuration");
+
+        assert!(!rules.is_enabled(LintRule::UnusedType));
+        assert!(rules.is_enabled(LintRule::RedundantDefault));
+        assert!(rules.is_enabled(LintRule::UnusedInput));
+    }
+
+    #[test]
+    fn invalid_configuration() {
+        let directory = directory("invalid-configuration");
+
+        for (name, content) in [
+            ("unknown-rule", "rules:\n  no-such-rule: false\n"),
+            ("not-boolean", "rules:\n  unused-type: no-thanks\n"),
+            ("not-map", "rules: [unused-type]\n"),
+        ] {
+            let path = directory.join(format!("{}.yaml", name));
+            fs::write(&path, content).expect("write file");
+
+            let result = read_configuration(&path, &mut LintRules::default());
+            assert!(matches!(result, Err(MainError::LintConfiguration(_))), "{}: {:?}", name, result);
+        }
+    }
+
+    #[test]
+    fn command_line_overrides_configuration() {
+        let path = directory("command-line").join(CONFIGURATION_FILE_NAME);
+        fs::write(&path, "rules:\n  unused-type: false\n  unused-input: false\n").expect("write file");
+
+        let lint = Lint {
+            input_path_or_url: None,
+            configuration_path: Some(path),
+            enable: vec!["unused-type".into()],
+            disable: vec!["redundant-default".into()],
+            deny_warnings: false,
+            output_colorize: Colorize::True,
+            quiet: true,
+            verbose: 0,
+            log_path: None,
+            help: None,
+        };
+
+        let rules = lint.rules().expect("rules");
+        assert!(rules.is_enabled(LintRule::UnusedType));
+        assert!(!rules.is_enabled(LintRule::UnusedInput));
+        assert!(!rules.is_enabled(LintRule::RedundantDefault));
+        assert!(rules.is_enabled(LintRule::UnusedImport));
+    }
+}
@@ -6,11 +6,27 @@ Puccini
 
 mod cli;
 mod compile;
+mod compile_all;
+mod diagnostics;
 mod dialects;
+mod doc;
 mod errors;
+mod explain;
+mod graph;
+#[cfg(feature = "plugins")]
+mod instances;
 #[cfg(feature = "plugins")]
 mod instantiate;
+mod lint;
+mod metadata;
+#[cfg(feature = "plugins")]
+mod plugins;
+mod query;
+mod repl;
 mod run;
+#[cfg(feature = "plugins")]
+mod trace;
+mod types;
 mod utils;
 
 use run::*;
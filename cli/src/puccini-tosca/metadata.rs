@@ -0,0 +1,37 @@
+use {compris::normal::*, floria::*};
+
+/// True if the `tosca:entity` metadata is the entity.
+pub fn is_tosca_entity(metadata: &Metadata, entity: &str) -> bool {
+    get_tosca_entity(metadata).as_deref() == Some(entity)
+}
+
+/// The `tosca:entity` metadata.
+pub fn get_tosca_entity(metadata: &Metadata) -> Option<String> {
+    get_metadata_text(metadata, "tosca:entity")
+}
+
+/// Text metadata.
+pub fn get_metadata_text(metadata: &Metadata, key: &str) -> Option<String> {
+    match metadata.inner.get(&key.into()) {
+        Some(Variant::Text(text)) => Some(text.inner.to_string()),
+        _ => None,
+    }
+}
+
+/// List of texts metadata.
+///
+/// Items that are not texts are ignored.
+pub fn get_metadata_texts(metadata: &Metadata, key: &str) -> Vec<String> {
+    match metadata.inner.get(&key.into()) {
+        Some(Variant::List(list)) => list
+            .inner
+            .iter()
+            .filter_map(|item| match item {
+                Variant::Text(text) => Some(text.inner.to_string()),
+                _ => None,
+            })
+            .collect(),
+
+        _ => Default::default(),
+    }
+}
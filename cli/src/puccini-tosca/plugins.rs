@@ -0,0 +1,81 @@
+use super::{cli::*, errors::*};
+
+use {
+    kutil::{cli::run::*, std::immutable::*},
+    puccini_tosca::dialect::tosca_2_0,
+    std::{env, path::*},
+};
+
+const PLUGIN_PATH_VARIABLE: &str = "PUCCINI_PLUGIN_PATH";
+
+//
+// PluginFile
+//
+
+/// Wasm plugin file to register in addition to the plugins for supported dialects.
+pub struct PluginFile {
+    /// Plugin ID.
+    pub id: ByteString,
+
+    /// Path to the Wasm file.
+    pub path: PathBuf,
+}
+
+impl Compile {
+    /// Extra plugin files from `--plugin`.
+    pub fn plugin_files(&self) -> Result<Vec<PluginFile>, MainError> {
+        plugin_files(&self.plugins)
+    }
+}
+
+/// Extra plugin files from `--plugin`.
+///
+/// Relative paths that do not exist are searched for in $PUCCINI_PLUGIN_PATH. When the path is
+/// absent it defaults to `<id>.wasm`.
+pub fn plugin_files(plugins: &[String]) -> Result<Vec<PluginFile>, MainError> {
+    let search_paths: Vec<_> = match env::var_os(PLUGIN_PATH_VARIABLE) {
+        Some(search_paths) => env::split_paths(&search_paths).collect(),
+        None => Default::default(),
+    };
+
+    let mut plugin_files = Vec::with_capacity(plugins.len());
+
+    for plugin in plugins {
+        let (id, path) = match plugin.split_once('=') {
+            Some((id, path)) => (id, PathBuf::from(path)),
+            None => (plugin.as_str(), PathBuf::from(format!("{}.wasm", plugin))),
+        };
+
+        if id.is_empty() {
+            return Err(ExitError::new(1, Some(format!("`--plugin` missing ID: {}", plugin))).into());
+        }
+
+        if (id == tosca_2_0::DIALECT_ID) || plugin_files.iter().any(|plugin_file: &PluginFile| plugin_file.id == id) {
+            return Err(ExitError::new(1, Some(format!("`--plugin` ID already registered: {}", id))).into());
+        }
+
+        let path = find_plugin_file(path, &search_paths)
+            .ok_or_else(|| ExitError::new(1, Some(format!("`--plugin` file not found: {}", plugin))))?;
+
+        plugin_files.push(PluginFile { id: id.to_string().into(), path });
+    }
+
+    Ok(plugin_files)
+}
+
+fn find_plugin_file(path: PathBuf, search_paths: &[PathBuf]) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path);
+    }
+
+    if path.is_relative() {
+        for search_path in search_paths {
+            let path = search_path.join(&path);
+            if path.is_file() {
+                return Some(path);
+            }
+        }
+    }
+
+    None
+}
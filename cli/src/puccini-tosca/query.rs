@@ -0,0 +1,218 @@
+use super::{cli::*, errors::*};
+
+use kutil::cli::{log::*, run::*};
+
+#[cfg(feature = "plugins")]
+use {
+    super::{instances::*, instantiate::*, plugins::*, utils::*},
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, *},
+    floria::{plugins::*, *},
+    kutil::{cli::depict::*, std::error::*},
+    puccini_tosca::{dialect::tosca_2_0, grammar::*},
+};
+
+impl Query {
+    /// Run query subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        if self.output_format.as_ref().and_then(|format| format.to_graph()).is_some() {
+            return Err(ExitError::from("query results cannot be output as a graph").into());
+        }
+
+        #[cfg(not(feature = "plugins"))]
+        return Err(ExitError::from("to use `query` you must enable \"plugins\" feature in build").into());
+
+        #[cfg(feature = "plugins")]
+        self.query()
+    }
+
+    /// Query.
+    ///
+    /// Compiles, instantiates, and updates the service template, and then evaluates the expression
+    /// at the call site.
+    #[cfg(feature = "plugins")]
+    pub fn query(&self) -> Result<(), MainError> {
+        let url_context = url_context()?;
+        let source_id = SourceID::url_or_default(Some(self.input_path_or_url.clone().into()));
+        let plugin_files = plugin_files(&self.plugins)?;
+
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        // Calls to unregistered plugins will be reported as errors
+        catalog.add_plugin_id(tosca_2_0::DIALECT_ID);
+        for plugin_file in &plugin_files {
+            catalog.add_plugin_id(plugin_file.id.clone());
+        }
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+        let mut floria_errors = Errors::<FloriaError>::default();
+
+        // Load and complete
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+
+        // Compile expression
+
+        let expression = self.expression_variant()?;
+        let expression = tosca_2_0::compile_query(expression, &mut catalog, &source_id, &mut tosca_errors)?;
+
+        // Compile service template
+
+        let store = InMemoryStore::default();
+        let directory = floria_directory(self.directory.as_ref());
+        let floria_service_template_id =
+            catalog.compile_service_template(&directory, store.to_ref(), &source_id, &mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            if !self.quiet {
+                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        let Some(floria_service_template_id) = floria_service_template_id else {
+            return Err(ExitError::from("no service template").into());
+        };
+
+        // Instantiate and update
+
+        let mut floria_service_template = store
+            .get_vertex_template(&floria_service_template_id)?
+            .ok_or_else(|| StoreError::ID(floria_service_template_id.to_string()))?;
+
+        let artifacts_path = artifacts_path(self.artifacts_path.as_ref());
+        set_artifacts_path(&mut floria_service_template, &artifacts_path);
+
+        let mut library = Compile::library(Environment::default(), store.clone(), &plugin_files)?;
+
+        let mut floria_instance =
+            floria_service_template.instantiate(&directory, None, &mut library, &mut floria_errors)?;
+        floria_instance.update(&mut library, &mut floria_errors)?;
+
+        // Evaluate
+
+        let call_site_id = self.call_site_id(&floria_instance, &store)?;
+        tracing::info!(call_site = call_site_id.to_string(), "evaluating");
+
+        let result = match expression {
+            Some(expression) => {
+                let call_site = CallSite::new(call_site_id, Default::default());
+                match expression.evaluate(&call_site, &mut library) {
+                    Ok(result) => result,
+                    Err(error) => {
+                        floria_errors.give(error)?;
+                        None
+                    }
+                }
+            }
+
+            None => None,
+        };
+
+        if self.teardown {
+            Compile::teardown_artifacts(&artifacts_path)?;
+        }
+
+        if let Err(floria_errors) = floria_errors.check() {
+            if !self.quiet {
+                floria_errors.to_depict("Floria Errors").print_default_depiction();
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        // Output
+
+        if !self.quiet {
+            let result: Variant<WithoutAnnotations> = result.map(|result| result.into()).unwrap_or_default();
+
+            match self.output_format.as_ref().and_then(|format| format.to_compris()) {
+                Some(output_format) => {
+                    compris::ser::Serializer::new(output_format)
+                        .with_pretty(!self.output_plain)
+                        .print(&result)
+                        .expect("print");
+                }
+
+                None => {
+                    result.print_default_depiction();
+                    println!();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Expression as a variant.
+    #[cfg(feature = "plugins")]
+    pub fn expression_variant(&self) -> Result<Variant<WithAnnotations>, MainError> {
+        Parser::new(Format::YAML)
+            .parse_reader(&mut self.expression.as_bytes())
+            .map_err(|error| MainError::Query(error.to_string()))
+    }
+
+    /// ID of the call site vertex.
+    ///
+    /// The first node instantiated from the `--node` template, or else the service.
+    #[cfg(feature = "plugins")]
+    pub fn call_site_id<StoreT>(&self, floria_instance: &Vertex, store: &StoreT) -> Result<ID, MainError>
+    where
+        StoreT: Store,
+    {
+        let Some(node) = &self.node else {
+            return Ok(floria_instance.instance.id.clone());
+        };
+
+        match find_node_instance(floria_instance, node, store)? {
+            Some(node) => Ok(node.instance.id),
+            None => Err(MainError::Query(format!("node template not found: {}", node))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, kutil::cli::clap::*};
+
+    fn query(expression: &str, output_format: Option<OutputFormat>) -> Query {
+        Query {
+            input_path_or_url: "service.yaml".into(),
+            expression: expression.into(),
+            node: None,
+            output_format,
+            output_colorize: Colorize::True,
+            output_plain: false,
+            directory: None,
+            artifacts_path: None,
+            teardown: false,
+            plugins: Vec::default(),
+            quiet: true,
+            verbose: 0,
+            log_path: None,
+            help: None,
+        }
+    }
+
+    #[test]
+    fn reject_graph_formats() {
+        // Rejected before the service template is read
+        for output_format in [OutputFormat::DOT, OutputFormat::Mermaid] {
+            assert!(matches!(query("{ $get_input: name }", Some(output_format)).run(), Err(MainError::Exit(_))));
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn expression_variant() {
+        let variant = query("{ $get_input: name }", None).expression_variant().expect("expression");
+        assert!(matches!(variant, Variant::Map(_)));
+
+        assert!(matches!(query("{ unclosed", None).expression_variant(), Err(MainError::Query(_))));
+    }
+}
@@ -0,0 +1,157 @@
+use super::super::errors::*;
+
+//
+// Command
+//
+
+/// REPL command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// List the commands.
+    Help,
+
+    /// List the nodes.
+    Nodes,
+
+    /// List the capabilities of a node.
+    Capabilities(String),
+
+    /// Show the properties of a node or of one of its capabilities.
+    Properties(String, Option<String>),
+
+    /// Set the call site to a node, or to the service when absent.
+    At(Option<String>),
+
+    /// Evaluate an expression at the call site.
+    Eval(String),
+
+    /// Evaluate an expression at the call site and set a node attribute to the result.
+    Set(String, String, String),
+
+    /// Re-run the updaters and show what changed.
+    Update,
+
+    /// Exit.
+    Exit,
+}
+
+impl Command {
+    /// Command names and usage.
+    pub const USAGE: &[(&str, &str, &str)] = &[
+        ("help", "", "list the commands"),
+        ("nodes", "", "list the nodes"),
+        ("capabilities", "<node>", "list the capabilities of a node"),
+        ("properties", "<node> [<capability>]", "show the properties of a node or of one of its capabilities"),
+        ("at", "[<node>]", "evaluate at a node, or at the service when absent"),
+        ("eval", "<expression>", "evaluate a TOSCA expression in YAML"),
+        ("set", "<node> <attribute> <expression>", "evaluate a TOSCA expression and set a node attribute"),
+        ("update", "", "re-run the updaters and show what changed"),
+        ("exit", "", "exit the REPL"),
+    ];
+
+    /// Parse.
+    pub fn parse(line: &str) -> Result<Self, MainError> {
+        let line = line.trim();
+        let (name, arguments) = match line.split_once(char::is_whitespace) {
+            Some((name, arguments)) => (name, arguments.trim()),
+            None => (line, ""),
+        };
+
+        let mut words = arguments.split_whitespace();
+
+        Ok(match name {
+            "help" | "?" => Self::Help,
+
+            "nodes" => Self::Nodes,
+
+            "capabilities" => Self::Capabilities(required(words.next(), "node")?),
+
+            "properties" => Self::Properties(required(words.next(), "node")?, words.next().map(|word| word.into())),
+
+            "at" => Self::At(words.next().map(|word| word.into())),
+
+            "eval" => Self::Eval(required(Some(arguments), "expression")?),
+
+            "set" => {
+                let mut arguments = arguments.splitn(3, char::is_whitespace);
+                let node = required(arguments.next(), "node")?;
+                let attribute = required(arguments.next(), "attribute")?;
+                let expression = required(arguments.next().map(|expression| expression.trim()), "expression")?;
+                Self::Set(node, attribute, expression)
+            }
+
+            "update" => Self::Update,
+
+            "exit" | "quit" => Self::Exit,
+
+            _ => return Err(MainError::Repl(format!("unknown command: {}", name))),
+        })
+    }
+}
+
+fn required(argument: Option<&str>, name: &str) -> Result<String, MainError> {
+    match argument {
+        Some(argument) if !argument.is_empty() => Ok(argument.into()),
+        _ => Err(MainError::Repl(format!("missing {}", name))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Command {
+        Command::parse(line).expect("parse")
+    }
+
+    fn error(line: &str) -> String {
+        match Command::parse(line) {
+            Err(MainError::Repl(message)) => message,
+            result => panic!("not a REPL error: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(parse("help"), Command::Help);
+        assert_eq!(parse("?"), Command::Help);
+        assert_eq!(parse("  nodes  "), Command::Nodes);
+        assert_eq!(parse("capabilities db"), Command::Capabilities("db".into()));
+        assert_eq!(parse("properties db"), Command::Properties("db".into(), None));
+        assert_eq!(parse("properties db endpoint"), Command::Properties("db".into(), Some("endpoint".into())));
+        assert_eq!(parse("at"), Command::At(None));
+        assert_eq!(parse("at db"), Command::At(Some("db".into())));
+        assert_eq!(parse("update"), Command::Update);
+        assert_eq!(parse("exit"), Command::Exit);
+        assert_eq!(parse("quit"), Command::Exit);
+    }
+
+    #[test]
+    fn parse_expressions() {
+        // The expression is the rest of the line, whitespace included
+        assert_eq!(parse("eval { $concat: [ a, b ] }"), Command::Eval("{ $concat: [ a, b ] }".into()));
+        assert_eq!(
+            parse("set db status   { $concat: [ a, b ] }  "),
+            Command::Set("db".into(), "status".into(), "{ $concat: [ a, b ] }".into())
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(error("launch"), "unknown command: launch");
+        assert_eq!(error("capabilities"), "missing node");
+        assert_eq!(error("properties"), "missing node");
+        assert_eq!(error("eval"), "missing expression");
+        assert_eq!(error("eval   "), "missing expression");
+        assert_eq!(error("set"), "missing node");
+        assert_eq!(error("set db"), "missing attribute");
+        assert_eq!(error("set db status"), "missing expression");
+    }
+
+    #[test]
+    fn usage_covers_commands() {
+        for (name, _, _) in Command::USAGE {
+            assert!(Command::parse(name).is_ok() || error(name).starts_with("missing "), "{}", name);
+        }
+    }
+}
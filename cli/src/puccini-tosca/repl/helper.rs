@@ -0,0 +1,80 @@
+use super::command::*;
+
+use rustyline::{completion::*, highlight::*, hint::*, validate::*, *};
+
+//
+// ReplHelper
+//
+
+/// REPL helper.
+///
+/// Completes command names for the first word and node names for the rest.
+pub struct ReplHelper {
+    /// Node names.
+    pub node_names: Vec<String>,
+}
+
+impl ReplHelper {
+    /// Constructor.
+    pub fn new(node_names: Vec<String>) -> Self {
+        Self { node_names }
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _context: &Context<'_>) -> Result<(usize, Vec<Self::Candidate>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map(|index| index + 1).unwrap_or_default();
+        let prefix = &line[start..];
+
+        let candidates = if line[..start].trim().is_empty() {
+            Command::USAGE
+                .iter()
+                .map(|(name, _, _)| *name)
+                .filter(|name| name.starts_with(prefix))
+                .map(|name| name.into())
+                .collect()
+        } else {
+            self.node_names.iter().filter(|name| name.starts_with(prefix)).cloned().collect()
+        };
+
+        Ok((start, candidates))
+    }
+}
+
+impl Helper for ReplHelper {}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, rustyline::history::*};
+
+    fn complete(line: &str) -> (usize, Vec<String>) {
+        let history = DefaultHistory::new();
+        let helper = ReplHelper::new(vec!["db".into(), "db-replica".into(), "app".into()]);
+        helper.complete(line, line.len(), &Context::new(&history)).expect("complete")
+    }
+
+    #[test]
+    fn complete_command_names() {
+        assert_eq!(complete("ca"), (0, vec!["capabilities".into()]));
+        assert_eq!(complete("  e"), (2, vec!["eval".into(), "exit".into()]));
+        assert_eq!(complete("").1.len(), Command::USAGE.len());
+    }
+
+    #[test]
+    fn complete_node_names() {
+        assert_eq!(complete("properties d"), (11, vec!["db".into(), "db-replica".into()]));
+        assert_eq!(complete("at a"), (3, vec!["app".into()]));
+        assert_eq!(complete("at x"), (3, Vec::default()));
+    }
+}
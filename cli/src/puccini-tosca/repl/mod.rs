@@ -0,0 +1,91 @@
+#[cfg(feature = "repl")]
+mod command;
+#[cfg(feature = "repl")]
+mod helper;
+#[cfg(feature = "repl")]
+mod session;
+
+use super::{cli::*, errors::*};
+
+use kutil::cli::{log::*, run::*};
+
+#[cfg(feature = "repl")]
+use {
+    self::{command::*, helper::*, session::*},
+    anstream::{eprintln, println},
+    rustyline::{error::*, history::*, *},
+    std::{env, path::*},
+};
+
+#[cfg(feature = "repl")]
+const HISTORY_FILE_NAME: &str = ".puccini-tosca-history";
+
+impl Repl {
+    /// Run REPL subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        self.output_colorize.initialize();
+        initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+
+        #[cfg(not(feature = "repl"))]
+        return Err(ExitError::from("to use `repl` you must enable \"repl\" feature in build").into());
+
+        #[cfg(feature = "repl")]
+        self.repl()
+    }
+
+    /// REPL.
+    ///
+    /// Compiles, instantiates, and updates the service template once, and then reads commands
+    /// until "exit" or end of input.
+    #[cfg(feature = "repl")]
+    pub fn repl(&self) -> Result<(), MainError> {
+        let mut session = Session::new(self)?;
+
+        let mut editor = Editor::<ReplHelper, DefaultHistory>::new()?;
+        editor.set_helper(Some(ReplHelper::new(session.node_names()?)));
+
+        let history_path = self.history_path();
+        if let Some(history_path) = &history_path {
+            // It's OK if there is no history yet
+            _ = editor.load_history(history_path);
+        }
+
+        println!("type \"help\" for a list of commands");
+
+        loop {
+            let line = match editor.readline(&session.prompt()) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(error) => return Err(error.into()),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            editor.add_history_entry(line.as_str())?;
+
+            match Command::parse(&line).and_then(|command| session.execute(command)) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(error) => eprintln!("{}", error),
+            }
+        }
+
+        if let Some(history_path) = &history_path {
+            editor.save_history(history_path)?;
+        }
+
+        session.close()
+    }
+
+    /// History file path.
+    #[cfg(feature = "repl")]
+    pub fn history_path(&self) -> Option<PathBuf> {
+        match &self.history_path {
+            Some(history_path) => Some(history_path.clone()),
+            None => env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE_NAME)),
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use super::{
+    super::{cli::*, errors::*, instances::*, instantiate::*, plugins::*, utils::*},
+    command::*,
+};
+
+use {
+    anstream::println,
+    compris::{annotate::*, normal::*, parse::*, *},
+    floria::{plugins::*, *},
+    kutil::{
+        cli::{depict::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::{dialect::tosca_2_0, grammar::*},
+    std::{collections::*, path::*},
+};
+
+const PROMPT: &str = "tosca";
+
+type Map = BTreeMap<Variant<WithoutAnnotations>, Variant<WithoutAnnotations>>;
+
+//
+// Session
+//
+
+/// REPL session.
+///
+/// Holds the catalog, so that expressions can be compiled against the source, and the simulated
+/// instance in the store.
+pub struct Session {
+    /// Catalog.
+    pub catalog: Catalog,
+
+    /// Source ID.
+    pub source_id: SourceID,
+
+    /// Store.
+    pub store: InMemoryStore,
+
+    /// Library.
+    pub library: Library<InMemoryStore>,
+
+    /// Service ID.
+    pub service_id: ID,
+
+    /// Name of the node template of the call site; the service when [None].
+    pub call_site: Option<String>,
+
+    /// Local artifact deployment area.
+    pub artifacts_path: PathBuf,
+
+    /// Whether to tear down the artifacts when closing.
+    pub teardown: bool,
+}
+
+impl Session {
+    /// Constructor.
+    ///
+    /// Compiles, instantiates, and updates the service template.
+    pub fn new(repl: &Repl) -> Result<Self, MainError> {
+        let url_context = url_context()?;
+        let source_id = SourceID::url_or_default(Some(repl.input_path_or_url.clone().into()));
+        let plugin_files = plugin_files(&repl.plugins)?;
+
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        // Calls to unregistered plugins will be reported as errors
+        catalog.add_plugin_id(tosca_2_0::DIALECT_ID);
+        for plugin_file in &plugin_files {
+            catalog.add_plugin_id(plugin_file.id.clone());
+        }
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        // Load, complete, and compile
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+
+        let store = InMemoryStore::default();
+        let directory = floria_directory(repl.directory.as_ref());
+        let floria_service_template_id =
+            catalog.compile_service_template(&directory, store.to_ref(), &source_id, &mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            return Err(ExitError::new(1, None).into());
+        }
+
+        let Some(floria_service_template_id) = floria_service_template_id else {
+            return Err(ExitError::from("no service template").into());
+        };
+
+        // Instantiate and update
+
+        let mut floria_service_template = store
+            .get_vertex_template(&floria_service_template_id)?
+            .ok_or_else(|| StoreError::ID(floria_service_template_id.to_string()))?;
+
+        let artifacts_path = artifacts_path(repl.artifacts_path.as_ref());
+        set_artifacts_path(&mut floria_service_template, &artifacts_path);
+
+        let mut library = Compile::library(Environment::default(), store.clone(), &plugin_files)?;
+
+        let mut floria_errors = Errors::<FloriaError>::default();
+
+        let mut floria_instance =
+            floria_service_template.instantiate(&directory, None, &mut library, &mut floria_errors)?;
+        floria_instance.update(&mut library, &mut floria_errors)?;
+
+        if let Err(floria_errors) = floria_errors.check() {
+            floria_errors.to_depict("Floria Errors").print_default_depiction();
+            if repl.teardown {
+                Compile::teardown_artifacts(&artifacts_path)?;
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        Ok(Self {
+            catalog,
+            source_id,
+            store,
+            library,
+            service_id: floria_instance.instance.id,
+            call_site: None,
+            artifacts_path,
+            teardown: repl.teardown,
+        })
+    }
+
+    /// Prompt.
+    pub fn prompt(&self) -> String {
+        match &self.call_site {
+            Some(call_site) => format!("{}:{}> ", PROMPT, call_site),
+            None => format!("{}> ", PROMPT),
+        }
+    }
+
+    /// Service.
+    pub fn service(&self) -> Result<Vertex, MainError> {
+        Ok(self.store.get_vertex(&self.service_id)?.ok_or_else(|| StoreError::ID(self.service_id.to_string()))?)
+    }
+
+    /// Node.
+    pub fn node(&self, node_template_name: &str) -> Result<Vertex, MainError> {
+        find_node_instance(&self.service()?, node_template_name, &self.store)?
+            .ok_or_else(|| MainError::Repl(format!("node template not found: {}", node_template_name)))
+    }
+
+    /// Node names.
+    pub fn node_names(&self) -> Result<Vec<String>, MainError> {
+        Ok(node_instances(&self.service()?, &self.store)?.into_iter().map(|(name, _)| name).collect())
+    }
+
+    /// Execute a command.
+    ///
+    /// Returns false if the REPL should exit.
+    pub fn execute(&mut self, command: Command) -> Result<bool, MainError> {
+        match command {
+            Command::Help => {
+                let width = Command::USAGE
+                    .iter()
+                    .map(|(name, arguments, _)| name.len() + arguments.len() + 1)
+                    .max()
+                    .unwrap_or_default();
+
+                for (name, arguments, description) in Command::USAGE {
+                    let usage = format!("{} {}", name, arguments);
+                    println!("{}{}  {}", usage, " ".repeat(width - usage.len()), description);
+                }
+            }
+
+            Command::Nodes => {
+                for (name, node) in node_instances(&self.service()?, &self.store)? {
+                    println!("{} ({})", name, node.instance.id);
+                }
+            }
+
+            Command::Capabilities(node_template_name) => {
+                let node = self.node(&node_template_name)?;
+                for (name, capability) in capability_instances(&node, &self.store)? {
+                    println!("{} ({})", name, capability.instance.id);
+                }
+            }
+
+            Command::Properties(node_template_name, capability_name) => {
+                let mut vertex = self.node(&node_template_name)?;
+
+                if let Some(capability_name) = capability_name {
+                    vertex = capability_instances(&vertex, &self.store)?
+                        .into_iter()
+                        .find(|(name, _)| *name == capability_name)
+                        .map(|(_, vertex)| vertex)
+                        .ok_or_else(|| MainError::Repl(format!("capability not found: {}", capability_name)))?;
+                }
+
+                let mut properties = Map::default();
+                for (name, value) in property_values(&vertex) {
+                    properties.insert(name.into(), value);
+                }
+
+                Variant::from(properties).print_default_depiction();
+                println!();
+            }
+
+            Command::At(node_template_name) => {
+                if let Some(node_template_name) = &node_template_name {
+                    self.node(node_template_name)?;
+                }
+
+                self.call_site = node_template_name;
+            }
+
+            Command::Eval(expression) => {
+                let result: Variant<WithoutAnnotations> =
+                    self.evaluate(&expression)?.map(|result| result.into()).unwrap_or_default();
+                result.print_default_depiction();
+                println!();
+            }
+
+            Command::Set(node_template_name, attribute_name, expression) => {
+                let value = self.evaluate(&expression)?;
+
+                let mut node = self.node(&node_template_name)?;
+                let property = node
+                    .instance
+                    .properties
+                    .get_mut(attribute_name.as_str())
+                    .ok_or_else(|| MainError::Repl(format!("attribute not found: {}", attribute_name)))?;
+
+                if property.read_only {
+                    return Err(MainError::Repl(format!("not an attribute: {}", attribute_name)));
+                }
+
+                property.value = value;
+                self.store.add_vertex(node)?;
+            }
+
+            Command::Update => {
+                let before = self.snapshot()?;
+
+                let mut floria_errors = Errors::<FloriaError>::default();
+                self.service()?.update(&mut self.library, &mut floria_errors)?;
+
+                if let Err(floria_errors) = floria_errors.check() {
+                    floria_errors.to_depict("Floria Errors").print_default_depiction();
+                }
+
+                let after = self.snapshot()?;
+
+                let mut changed = false;
+                for (path, value) in &after {
+                    let before = before.get(path);
+                    if before != Some(value) {
+                        changed = true;
+                        let before = before.cloned().unwrap_or_default();
+                        println!("{}: {} -> {}", path, before, value);
+                    }
+                }
+
+                if !changed {
+                    println!("no changes");
+                }
+            }
+
+            Command::Exit => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Evaluate an expression at the call site.
+    pub fn evaluate(&mut self, expression: &str) -> Result<Option<floria::Expression>, MainError> {
+        let variant = Parser::new(Format::YAML)
+            .parse_reader(&mut expression.as_bytes())
+            .map_err(|error| MainError::Repl(error.to_string()))?;
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+        let expression = tosca_2_0::compile_query(variant, &mut self.catalog, &self.source_id, &mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            return Err(MainError::Repl("invalid expression".into()));
+        }
+
+        let Some(expression) = expression else {
+            return Ok(None);
+        };
+
+        let call_site_id = match &self.call_site {
+            Some(call_site) => self.node(call_site)?.instance.id,
+            None => self.service_id.clone(),
+        };
+
+        let call_site = CallSite::new(call_site_id, Default::default());
+        Ok(expression.evaluate(&call_site, &mut self.library)?)
+    }
+
+    /// Property values of the nodes and their capabilities by path.
+    pub fn snapshot(&self) -> Result<BTreeMap<String, Variant<WithoutAnnotations>>, MainError> {
+        let mut snapshot = BTreeMap::default();
+
+        for (node_name, node) in node_instances(&self.service()?, &self.store)? {
+            for (name, value) in property_values(&node) {
+                snapshot.insert(format!("{}.{}", node_name, name), value);
+            }
+
+            for (capability_name, capability) in capability_instances(&node, &self.store)? {
+                for (name, value) in property_values(&capability) {
+                    snapshot.insert(format!("{}.{}.{}", node_name, capability_name, name), value);
+                }
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Close.
+    ///
+    /// Tears down the artifacts if requested.
+    pub fn close(&self) -> Result<(), MainError> {
+        if self.teardown {
+            Compile::teardown_artifacts(&self.artifacts_path)?;
+        }
+        Ok(())
+    }
+}
+
+// Missing values are null
+fn property_values(vertex: &Vertex) -> Vec<(String, Variant<WithoutAnnotations>)> {
+    vertex
+        .instance
+        .properties
+        .iter()
+        .map(|(name, property)| {
+            (name.to_string(), property.value.clone().map(|value| value.into()).unwrap_or_default())
+        })
+        .collect()
+}
@@ -9,6 +9,13 @@ pub fn run() -> Result<(), MainError> {
         None => {}
         Some(subcommand) => match subcommand {
             SubCommand::Compile(compile) => compile.run()?,
+            SubCommand::CompileAll(compile_all) => compile_all.run()?,
+            SubCommand::Lint(lint) => lint.run()?,
+            SubCommand::Types(types) => types.run()?,
+            SubCommand::Doc(doc) => doc.run()?,
+            SubCommand::Explain(explain) => explain.run()?,
+            SubCommand::Query(query) => query.run()?,
+            SubCommand::Repl(repl) => repl.run()?,
             SubCommand::Version(version) => version.run::<CLI>(),
             SubCommand::Completion(completion) => completion.run::<CLI>(),
             SubCommand::Manual(manual) => manual.run::<CLI>()?,
@@ -0,0 +1,210 @@
+use {
+    compris::{annotate::*, normal::*},
+    kutil::{cli::depict::*, std::iter::*},
+    std::{collections::*, fs, io, path::*},
+};
+
+//
+// FunctionTrace
+//
+
+/// Trace of a dispatched function call.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionTrace {
+    /// Index in call order.
+    pub index: usize,
+
+    /// Nesting depth.
+    pub depth: usize,
+
+    /// Function name.
+    pub function: String,
+
+    /// Call site entity.
+    pub entity: String,
+
+    /// Call site property path.
+    pub property: String,
+
+    /// Arguments.
+    pub arguments: Vec<String>,
+
+    /// Result.
+    pub result: String,
+
+    /// Error.
+    pub error: Option<String>,
+}
+
+impl FunctionTrace {
+    /// Parse a trace file line.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split('\t').map(unescape);
+
+        let index = fields.next()?.parse().ok()?;
+        let depth = fields.next()?.parse().ok()?;
+        let function = fields.next()?;
+        let entity = fields.next()?;
+        let property = fields.next()?;
+        let ok = fields.next()? == "ok";
+        let result = fields.next()?;
+        let arguments = fields.collect();
+
+        Some(if ok {
+            Self { index, depth, function, entity, property, arguments, result, error: None }
+        } else {
+            Self {
+                index,
+                depth,
+                function,
+                entity,
+                property,
+                arguments,
+                result: Default::default(),
+                error: Some(result),
+            }
+        })
+    }
+
+    /// To variant.
+    pub fn to_variant(&self) -> Variant<WithoutAnnotations> {
+        let mut map = BTreeMap::<Variant<WithoutAnnotations>, Variant<WithoutAnnotations>>::default();
+        map.insert("index".into(), (self.index as u64).into());
+        map.insert("depth".into(), (self.depth as u64).into());
+        map.insert("function".into(), self.function.clone().into());
+        map.insert("entity".into(), self.entity.clone().into());
+        map.insert("property".into(), self.property.clone().into());
+
+        let arguments: Vec<Variant<WithoutAnnotations>> =
+            self.arguments.iter().map(|argument| argument.clone().into()).collect();
+        map.insert("arguments".into(), arguments.into());
+
+        match &self.error {
+            Some(error) => map.insert("error".into(), error.clone().into()),
+            None => map.insert("result".into(), self.result.clone().into()),
+        };
+
+        map.into()
+    }
+}
+
+//
+// FunctionTraces
+//
+
+/// Function traces, in call order.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionTraces(pub Vec<FunctionTrace>);
+
+impl FunctionTraces {
+    /// Read trace file.
+    ///
+    /// A missing file means that no functions were called.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Default::default()),
+            Err(error) => return Err(error),
+        };
+
+        let mut traces: Vec<_> = content.lines().filter_map(FunctionTrace::parse).collect();
+        traces.sort_by_key(|trace| trace.index);
+        Ok(Self(traces))
+    }
+
+    /// Write as JSON.
+    pub fn write_json(&self, path: &Path) -> io::Result<()> {
+        let traces: Vec<_> = self.0.iter().map(|trace| trace.to_variant()).collect();
+        let variant: Variant<WithoutAnnotations> = traces.into();
+
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        compris::ser::Serializer::new(compris::Format::JSON)
+            .with_pretty(true)
+            .write(&variant, &mut file)
+            .map_err(io::Error::other)
+    }
+
+    // Children of the trace at an index (or of the root)
+    fn children(&self, parent: Option<usize>) -> Vec<usize> {
+        let (start, depth) = match parent {
+            Some(parent) => (parent + 1, self.0[parent].depth + 1),
+            None => (0, 0),
+        };
+
+        let mut children = Vec::default();
+        for (index, trace) in self.0.iter().enumerate().skip(start) {
+            if trace.depth < depth {
+                break;
+            } else if trace.depth == depth {
+                children.push(index);
+            }
+        }
+
+        children
+    }
+
+    fn depict_children<WriteT>(
+        &self,
+        parent: Option<usize>,
+        writer: &mut WriteT,
+        context: &DepictionContext,
+    ) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        for (index, last) in IterateWithLast::new(self.children(parent)) {
+            let trace = &self.0[index];
+
+            context.indent_into_branch(writer, last)?;
+            context.theme.write_name(writer, &trace.function)?;
+            context.theme.write_delimiter(writer, '(')?;
+            write!(writer, "{}", trace.arguments.join(", "))?;
+            context.theme.write_delimiter(writer, ')')?;
+
+            match &trace.error {
+                Some(error) => write!(writer, " {}", context.theme.error(error))?,
+
+                None => {
+                    write!(writer, " {} {}", context.theme.delimiter("="), trace.result)?;
+                }
+            }
+
+            if !trace.entity.is_empty() {
+                write!(writer, " {}", context.theme.meta(format!("@ {} {}", trace.entity, trace.property).trim_end()))?;
+            }
+
+            self.depict_children(Some(index), writer, &context.child().increase_indentation_branch(last))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Depict for FunctionTraces {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.theme.write_heading(writer, "Function Trace")?;
+        self.depict_children(None, writer, context)
+    }
+}
+
+fn unescape(field: &str) -> String {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut characters = field.chars();
+    while let Some(character) = characters.next() {
+        if character == '\\' {
+            match characters.next() {
+                Some('t') => unescaped.push('\t'),
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some(character) => unescaped.push(character),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(character);
+        }
+    }
+    unescaped
+}
@@ -0,0 +1,222 @@
+use super::{cli::*, errors::*, utils::*};
+
+use {
+    compris::{annotate::*, normal::*},
+    kutil::{
+        cli::{depict::*, log::*, run::*},
+        std::error::*,
+    },
+    puccini_tosca::grammar::*,
+    std::collections::*,
+};
+
+type Map = BTreeMap<Variant<WithoutAnnotations>, Variant<WithoutAnnotations>>;
+
+impl Types {
+    /// Run types subcommand.
+    pub fn run(&self) -> Result<(), MainError> {
+        if !self.quiet {
+            self.output_colorize.initialize();
+            initialize_tracing(self.verbose + 2, self.log_path.as_ref())?;
+        }
+
+        if self.output_format.as_ref().and_then(|format| format.to_graph()).is_some() {
+            return Err(ExitError::from("type hierarchies cannot be output as a graph").into());
+        }
+
+        let name_pattern = match &self.name_pattern {
+            Some(name_pattern) => Some(glob::Pattern::new(name_pattern).map_err(|error| {
+                ExitError::new(1, Some(format!("invalid glob pattern: {}: {}", name_pattern, error)))
+            })?),
+
+            None => None,
+        };
+
+        let url_context = url_context()?;
+        let source_id =
+            SourceID::url_or_default(self.input_path_or_url.clone().map(|input_path_or_url| input_path_or_url.into()));
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+
+        let mut tosca_errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut tosca_errors)?;
+        catalog.complete_entities(&mut tosca_errors)?;
+        let mut hierarchy = catalog.type_hierarchy(&source_id, &mut tosca_errors)?;
+
+        if let Err(tosca_errors) = tosca_errors.check() {
+            if !self.quiet {
+                tosca_errors.annotated_depictions(Some("TOSCA Errors".into())).print_default_depiction();
+            }
+            return Err(ExitError::new(1, None).into());
+        }
+
+        if !self.kinds.is_empty() {
+            hierarchy.retain_entity_kinds(&self.kinds);
+        }
+
+        if let Some(descendants_of) = &self.descendants_of {
+            hierarchy.retain_descendants_of(descendants_of);
+        }
+
+        if let Some(name_pattern) = &name_pattern {
+            hierarchy.retain_names(|name| name_pattern.matches(name));
+        }
+
+        if !self.quiet {
+            match self.output_format.as_ref().and_then(|format| format.to_compris()) {
+                Some(output_format) => {
+                    compris::ser::Serializer::new(output_format)
+                        .with_pretty(!self.output_plain)
+                        .print(&hierarchy_variant(&hierarchy))
+                        .expect("print");
+                }
+
+                None => {
+                    if !hierarchy.is_empty() {
+                        hierarchy.print_default_depiction();
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Entity kind names mapped to lists of root type nodes
+fn hierarchy_variant(hierarchy: &TypeHierarchy) -> Variant<WithoutAnnotations> {
+    let mut map = Map::default();
+    for (entity_kind_name, roots) in &hierarchy.kinds {
+        let roots: Vec<_> = roots.iter().map(type_node_variant).collect();
+        map.insert(entity_kind_name.clone().into(), roots.into());
+    }
+    map.into()
+}
+
+fn type_node_variant(type_node: &TypeNode) -> Variant<WithoutAnnotations> {
+    let info = &type_node.info;
+
+    let mut map = Map::default();
+    map.insert("name".into(), info.full_name.to_string().into());
+    map.insert("source".into(), info.type_id.source_id.to_string().into());
+
+    if let Some(version) = &info.version {
+        map.insert("version".into(), version.clone().into());
+    }
+
+    if let Some(description) = &info.description {
+        map.insert("description".into(), description.clone().into());
+    }
+
+    if !type_node.children.is_empty() {
+        let children: Vec<_> = type_node.children.iter().map(type_node_variant).collect();
+        map.insert("children".into(), children.into());
+    }
+
+    map.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{env, fs},
+    };
+
+    // Writes the files and returns the hierarchy of the first one
+    fn type_hierarchy(name: &str, files: &[(&str, &str)]) -> TypeHierarchy {
+        let directory = env::temp_dir().join(format!("puccini-types-{}-{}", name, std::process::id()));
+        _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).expect("create directory");
+        for (file_name, content) in files {
+            fs::write(directory.join(file_name), content).expect("write file");
+        }
+
+        let source_id = SourceID::url_or_default(Some(directory.join(files[0].0).display().to_string().into()));
+
+        let url_context = url_context().expect("URL context");
+        let mut catalog = Compile::catalog::<WithAnnotations>();
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        catalog.load_source_with_annotations(&source_id, &url_context, &mut errors).expect("load");
+        catalog.complete_entities(&mut errors).expect("complete");
+        let hierarchy = catalog.type_hierarchy(&source_id, &mut errors).expect("type hierarchy");
+        assert!(errors.is_empty());
+
+        hierarchy
+    }
+
+    fn get<'own>(
+        variant: &'own Variant<WithoutAnnotations>,
+        key: &'static str,
+    ) -> Option<&'own Variant<WithoutAnnotations>> {
+        match variant {
+            Variant::Map(map) => map.inner.get(&Variant::from(key)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn imported_types() {
+        let mut hierarchy = type_hierarchy(
+            "imported",
+            &[
+                (
+                    "main.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+imports:
+- namespace: base
+  url: base.yaml
+node_types:
+  Server:
+    derived_from: base:Root
+    version: 1.0.0
+    description: Server.
+data_types:
+  Root:
+    derived_from: base:Root
+"#,
+                ),
+                (
+                    "base.yaml",
+                    r#"
+tosca_definitions_version: tosca_2_0
+node_types:
+  Root: {}
+data_types:
+  Root: {}
+"#,
+                ),
+            ],
+        );
+
+        hierarchy.retain_descendants_of("base:Root");
+
+        // Types with the same name but different kinds stay apart
+        let variant = hierarchy_variant(&hierarchy);
+        let Variant::Map(kinds) = &variant else { panic!("not a map: {}", variant) };
+        assert_eq!(kinds.inner.len(), 2);
+
+        for (entity_kind_name, roots) in &kinds.inner {
+            let Variant::List(roots) = roots else { panic!("not a list: {}", roots) };
+            assert_eq!(roots.inner.len(), 1, "{}", entity_kind_name);
+
+            let root = &roots.inner[0];
+            assert_eq!(get(root, "name"), Some(&"base:Root".into()));
+            assert!(get(root, "source").expect("source").to_string().contains("base.yaml"));
+
+            let Some(Variant::List(children)) = get(root, "children") else { panic!("no children: {}", root) };
+            assert_eq!(children.inner.len(), 1);
+
+            let child = &children.inner[0];
+            if entity_kind_name.to_string().contains("node") {
+                assert_eq!(get(child, "name"), Some(&"Server".into()));
+                assert_eq!(get(child, "version"), Some(&"1.0.0".into()));
+                assert_eq!(get(child, "description"), Some(&"Server.".into()));
+            } else {
+                assert_eq!(get(child, "name"), Some(&"Root".into()));
+            }
+        }
+    }
+}
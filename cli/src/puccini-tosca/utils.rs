@@ -1,19 +1,47 @@
-/// Entity must support both into_expression and to_depict
+use super::errors::*;
+
+use read_url::*;
+
+/// URL context.
+pub fn url_context() -> Result<UrlContextRef, MainError> {
+    let url_context = UrlContext::new();
+
+    #[cfg(feature = "filesystem")]
+    let base_urls = url_context.working_dir_url_vec()?;
+
+    #[cfg(not(feature = "filesystem"))]
+    let base_urls = Vec::default();
+
+    Ok(url_context.with_base_urls(base_urls))
+}
+
+/// Floria directory.
+///
+/// The root directory when [None].
+pub fn floria_directory(directory: Option<&String>) -> floria::Directory {
+    directory.map(|directory| floria::ID::parse_directory(directory)).unwrap_or_default()
+}
+
+/// Entity must support into_expression, to_depict, and to_graph
 macro_rules! output {
     ( $self:ident, $store:ident, $entity:ident $(,)? ) => {
-        match $self.get_output_format() {
-            Some(output_format) => {
-                let expression = $entity.into_expression(true, &$store)?;
-                let variant: Variant<WithoutAnnotations> = expression.into();
-                compris::ser::Serializer::new(output_format)
-                    .with_pretty(!$self.output_plain)
-                    .with_base64($self.output_base64)
-                    .print(&variant)
-                    .expect("print");
-            }
+        if let Some(graph_format) = $self.get_graph_format() {
+            anstream::print!("{}", $entity.to_graph(&$store)?.to_format(graph_format));
+        } else {
+            match $self.get_output_format() {
+                Some(output_format) => {
+                    let expression = $entity.into_expression(true, &$store)?;
+                    let variant: Variant<WithoutAnnotations> = expression.into();
+                    compris::ser::Serializer::new(output_format)
+                        .with_pretty(!$self.output_plain)
+                        .with_base64($self.output_base64)
+                        .print(&variant)
+                        .expect("print");
+                }
 
-            None => {
-                $entity.to_depict(&$store).print_default_depiction();
+                None => {
+                    $entity.to_depict(&$store).print_default_depiction();
+                }
             }
         }
     };
@@ -0,0 +1,24 @@
+use std::{env, path::*};
+
+/// Environment variable for the default local artifact deployment area.
+pub const ARTIFACTS_VARIABLE: &str = "PUCCINI_ARTIFACTS";
+
+/// Name of the default local artifact deployment area in the temporary directory.
+pub const ARTIFACTS_DIRECTORY_NAME: &str = "puccini-artifacts";
+
+/// Name of the file in the local artifact deployment area that lists the artifacts to remove at
+/// teardown.
+pub const ARTIFACTS_TEARDOWN_FILE_NAME: &str = ".teardown";
+
+/// Service metadata key for the local artifact deployment area.
+pub const ARTIFACTS_METADATA_KEY: &str = "puccini:artifacts";
+
+/// Default local artifact deployment area.
+///
+/// $PUCCINI_ARTIFACTS or else a directory in the temporary directory.
+pub fn default_artifacts_path() -> PathBuf {
+    match env::var_os(ARTIFACTS_VARIABLE) {
+        Some(artifacts_path) => artifacts_path.into(),
+        None => env::temp_dir().join(ARTIFACTS_DIRECTORY_NAME),
+    }
+}
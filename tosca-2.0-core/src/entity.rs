@@ -0,0 +1,39 @@
+// These are the values of the "tosca:entity" metadata emitted by the compiler and checked by the
+// functions plugin
+
+// Templates
+
+/// Service template name.
+pub const SERVICE_TEMPLATE_NAME: &str = "ServiceTemplate";
+
+/// Group template name.
+pub const GROUP_TEMPLATE_NAME: &str = "GroupTemplate";
+
+/// Node template name.
+pub const NODE_TEMPLATE_NAME: &str = "NodeTemplate";
+
+/// Policy template name.
+pub const POLICY_TEMPLATE_NAME: &str = "PolicyTemplate";
+
+/// Relationship template name.
+pub const RELATIONSHIP_TEMPLATE_NAME: &str = "RelationshipTemplate";
+
+// Other
+
+/// Artifact name.
+pub const ARTIFACT_NAME: &str = "Artifact";
+
+/// Capability name.
+pub const CAPABILITY_NAME: &str = "Capability";
+
+/// Requirement name.
+pub const REQUIREMENT_NAME: &str = "Requirement";
+
+/// Property name.
+pub const PROPERTY_NAME: &str = "Property";
+
+/// Attribute name.
+pub const ATTRIBUTE_NAME: &str = "Attribute";
+
+/// Parameter name.
+pub const PARAMETER_NAME: &str = "Parameter";
@@ -0,0 +1,88 @@
+use super::{host::*, number::*, operand::*};
+
+/// Subtract allocations from a capacity, resulting in the available allocation.
+///
+/// The result is negative if the capacity is already over-allocated.
+pub fn subtract_allocations<ScalarT, AllocationsT>(
+    capacity: Operand<ScalarT>,
+    allocations: AllocationsT,
+) -> Result<Operand<ScalarT>, String>
+where
+    ScalarT: ScalarOperand,
+    AllocationsT: IntoIterator<Item = Operand<ScalarT>>,
+{
+    let mut available = capacity;
+    for allocation in allocations {
+        available = available.apply(allocation, Number::subtract)?;
+    }
+    Ok(available)
+}
+
+/// Allocate from the available allocation.
+///
+/// Returns the remaining available allocation, or [None] if the allocation is over the available
+/// allocation.
+pub fn allocate<ScalarT>(
+    available: &Operand<ScalarT>,
+    allocation: &Operand<ScalarT>,
+) -> Result<Option<Operand<ScalarT>>, String>
+where
+    ScalarT: ScalarOperand,
+{
+    let remaining = available.clone().apply(allocation.clone(), Number::subtract)?;
+    Ok(if remaining.is_negative() { None } else { Some(remaining) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    fn number(number: i64) -> Operand<TestScalar> {
+        Operand::Number(number.into())
+    }
+
+    fn scalar_operand(text: &str) -> Operand<TestScalar> {
+        Operand::Scalar(parse_scalar(text).expect("scalar"))
+    }
+
+    #[test]
+    fn available() {
+        let available = subtract_allocations(number(10), vec![number(3), number(4)]).expect("available");
+        assert_eq!(available.to_string(), "3");
+
+        let available =
+            subtract_allocations(scalar_operand("2 GB"), vec![scalar_operand("500 MB")]).expect("available");
+        assert_eq!(available.into_value(), scalar(1500000000, "B"));
+    }
+
+    #[test]
+    fn available_over_capacity() {
+        // Existing allocations can exceed the capacity
+        let available = subtract_allocations(number(10), vec![number(8), number(4)]).expect("available");
+        assert!(available.is_negative());
+        assert_eq!(allocate(&available, &number(0)).expect("allocate").map(|remaining| remaining.to_string()), None);
+    }
+
+    #[test]
+    fn allocate_within_capacity() {
+        let remaining = allocate(&number(10), &number(10)).expect("allocate");
+        assert_eq!(remaining.map(|remaining| remaining.to_string()), Some("0".into()));
+
+        let remaining = allocate(&scalar_operand("1 GB"), &scalar_operand("1000 MB")).expect("allocate");
+        assert_eq!(remaining.map(|remaining| remaining.into_value()), Some(scalar(0, "B")));
+    }
+
+    #[test]
+    fn allocate_over_capacity() {
+        assert!(allocate(&number(10), &number(11)).expect("allocate").is_none());
+        assert!(allocate(&scalar_operand("1 GB"), &scalar_operand("1001 MB")).expect("allocate").is_none());
+    }
+
+    #[test]
+    fn allocate_incompatible() {
+        assert_eq!(
+            allocate(&number(10), &scalar_operand("1 GB")).map(|remaining| remaining.is_some()),
+            Err("cannot mix numbers and scalars: 10, 1000000000 B".into())
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use super::{host::*, value::*};
+
+/// Evaluate arguments.
+///
+/// Returns [None] if an argument cannot be evaluated by the host.
+pub fn evaluate_arguments<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Vec<Value<HostT::Custom>>>, String>
+where
+    HostT: FunctionHost,
+{
+    let mut evaluated = Vec::with_capacity(arguments.len());
+    for argument in arguments {
+        match host.evaluate(argument)? {
+            Some(argument) => evaluated.push(argument),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(evaluated))
+}
+
+/// Comparator.
+///
+/// Numbers and strings are compared as they are and custom values via the host.
+pub fn comparator<HostT>(value: Value<HostT::Custom>, host: &HostT) -> Result<Value<HostT::Custom>, String>
+where
+    HostT: FunctionHost,
+{
+    match value {
+        Value::Integer(_) | Value::UnsignedInteger(_) | Value::Float(_) | Value::Text(_) => Ok(value),
+        Value::Custom(custom) => host.custom_comparator(&custom),
+        _ => Err(format!("cannot compare {}", value.type_name())),
+    }
+}
+
+/// Deep equality.
+///
+/// Lists and maps are compared recursively. Numbers are compared numerically, so that integers
+/// and floats can be equal. Custom values are compared via their [comparator].
+pub fn deep_equal<HostT>(
+    left: &Value<HostT::Custom>,
+    right: &Value<HostT::Custom>,
+    host: &HostT,
+) -> Result<bool, String>
+where
+    HostT: FunctionHost,
+{
+    match (left, right) {
+        (Value::List(left), Value::List(right)) => {
+            if left.len() != right.len() {
+                return Ok(false);
+            }
+
+            for (left, right) in left.iter().zip(right) {
+                if !deep_equal(left, right, host)? {
+                    return Ok(false);
+                }
+            }
+
+            Ok(true)
+        }
+
+        (Value::Map(left), Value::Map(right)) => {
+            if left.len() != right.len() {
+                return Ok(false);
+            }
+
+            for (key, value) in left {
+                match right.iter().find(|(right_key, _)| right_key == key) {
+                    Some((_, right_value)) => {
+                        if !deep_equal(value, right_value, host)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    None => return Ok(false),
+                }
+            }
+
+            Ok(true)
+        }
+
+        (Value::Custom(_), _) | (_, Value::Custom(_)) => {
+            // Values that cannot be coerced are simply not equal
+            let Ok(left) = host.coerce_if_custom(left.clone(), right) else {
+                return Ok(false);
+            };
+            let Ok(right) = host.coerce_if_custom(right.clone(), &left) else {
+                return Ok(false);
+            };
+
+            let (Value::Custom(left_custom), Value::Custom(right_custom)) = (&left, &right) else {
+                return Ok(false);
+            };
+
+            if host.custom_kind(left_custom) != host.custom_kind(right_custom) {
+                return Ok(false);
+            }
+
+            match (host.custom_comparator(left_custom), host.custom_comparator(right_custom)) {
+                (Ok(left), Ok(right)) => deep_equal(&left, &right, host),
+
+                // Unsupported custom kinds
+                _ => Ok(left == right),
+            }
+        }
+
+        (
+            Value::Integer(_) | Value::UnsignedInteger(_) | Value::Float(_),
+            Value::Integer(_) | Value::UnsignedInteger(_) | Value::Float(_),
+        ) => Ok(left
+            .to_number()
+            .zip(right.to_number())
+            .and_then(|(left, right)| left.compare(right))
+            .is_some_and(|ordering| ordering.is_eq())),
+
+        _ => Ok(left == right),
+    }
+}
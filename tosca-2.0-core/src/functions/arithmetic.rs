@@ -0,0 +1,356 @@
+use super::{arguments::*, host::*, number::*, operand::*, value::*};
+
+/// The $sum function takes one or more arguments of either integer, float, or scalar type. The
+/// result is of the same type as the arguments and its value is the arithmetic sum of the
+/// arguments' values.
+pub fn sum<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.is_empty() {
+        return Err("must have at least one argument".into());
+    }
+
+    let Some(operands) = evaluate_operands(arguments, host)? else {
+        return Ok(None);
+    };
+    let mut operands = operands.into_iter();
+
+    let mut sum = operands.next().expect("not empty");
+    for operand in operands {
+        sum = sum.apply(operand, Number::add)?;
+    }
+
+    Ok(Some(sum.into_value()))
+}
+
+/// The $difference function takes two arguments of either integer, float, or scalar type. The
+/// result is of the same type as the arguments and its value is the arithmetic subtraction of the
+/// second argument value from the first argument value.
+pub fn difference<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 2 {
+        return Err("must have 2 arguments".into());
+    }
+
+    let Some(mut operands) = evaluate_operands(arguments, host)? else {
+        return Ok(None);
+    };
+    let left = operands.remove(0);
+    let right = operands.remove(0);
+
+    Ok(Some(left.apply(right, Number::subtract)?.into_value()))
+}
+
+/// The $product function takes either:
+///
+/// * Two arguments where the first argument is of a scalar type and the second argument is of an
+///   integer or float type. The result is of the same type as the first argument and its value is
+///   the arithmetic product of the first argument value and the second argument value.
+///
+/// * Any number of arguments of type integer or float. If all inputs are of type integer, then the
+///   result is of type integer, otherwise it is of type float. The result value is the arithmetic
+///   product of all the arguments values.
+pub fn product<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.is_empty() {
+        return Err("must have at least one argument".into());
+    }
+
+    let Some(operands) = evaluate_operands(arguments, host)? else {
+        return Ok(None);
+    };
+    let mut operands = operands.into_iter().enumerate();
+    let (_, first) = operands.next().expect("not empty");
+
+    let mut product = match first {
+        Operand::Scalar(scalar) => {
+            let Some((_, factor)) = operands.next() else {
+                return Err("must have 2 arguments when the first argument is a scalar".into());
+            };
+
+            if operands.next().is_some() {
+                return Err("must have 2 arguments when the first argument is a scalar".into());
+            }
+
+            let factor = factor.must_be_number().map_err(|error| format!("second argument {}", error))?;
+            let number = scalar.number().multiply(factor)?;
+            return Ok(Some(Operand::Scalar(scalar.with_number(number)?).into_value()));
+        }
+
+        Operand::Number(number) => number,
+    };
+
+    for (index, operand) in operands {
+        let factor = operand.must_be_number().map_err(|error| format!("argument {} {}", index, error))?;
+        product = product.multiply(factor)?.must_be_a_number()?;
+    }
+
+    Ok(Some(product.into()))
+}
+
+/// The $quotient function takes two arguments where the first argument is of an integer, float, or
+/// scalar type and the second argument is of an integer or float type. The result is of
+///
+/// * A scalar type if the first argument is a scalar, and its value is the arithmetic division of
+///   the first argument value by the second argument value. If necessary, the result might be
+///   truncated, as decided by the implementation.
+///
+/// * A float if the first argument is an integer or a float. Note that to transform the float to
+///   an integer a round or ceil or floor function must be used.
+pub fn quotient<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 2 {
+        return Err("must have 2 arguments".into());
+    }
+
+    let Some(mut operands) = evaluate_operands(arguments, host)? else {
+        return Ok(None);
+    };
+    let dividend = operands.remove(0);
+    let divisor = operands.remove(0).must_be_number().map_err(|error| format!("second argument {}", error))?;
+
+    Ok(Some(match dividend {
+        Operand::Number(dividend) => dividend.divide(divisor)?.into(),
+
+        // Integer scalars are truncated
+        Operand::Scalar(scalar) => {
+            let mut number = scalar.number().divide(divisor)?;
+            if scalar.number().is_integer() {
+                number = number.truncate();
+            }
+            Operand::Scalar(scalar.with_number(number)?).into_value()
+        }
+    }))
+}
+
+/// The $remainder function takes two arguments where the first argument is of an integer, or
+/// scalar type and the second argument is of an integer. The result is of the same type as the
+/// first argument and its value is the remainder of the division to the second argument.
+pub fn remainder<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 2 {
+        return Err("must have 2 arguments".into());
+    }
+
+    let Some(mut operands) = evaluate_operands(arguments, host)? else {
+        return Ok(None);
+    };
+    let dividend = operands.remove(0);
+    let divisor = operands.remove(0).must_be_number().map_err(|error| format!("second argument {}", error))?;
+
+    if !divisor.is_integer() {
+        return Err(format!("second argument not an integer: {}", divisor));
+    }
+
+    Ok(Some(match dividend {
+        Operand::Number(dividend) => {
+            if !dividend.is_integer() {
+                return Err(format!("first argument not an integer or a scalar: {}", dividend));
+            }
+
+            dividend.remainder(divisor)?.into()
+        }
+
+        Operand::Scalar(scalar) => {
+            let number = scalar.number().remainder(divisor)?;
+            Operand::Scalar(scalar.with_number(number)?).into_value()
+        }
+    }))
+}
+
+/// The $round function takes a float argument. The result is an integer with the closest value to
+/// the float argument. Equal value distance is rounded down (e.g. 3.5 is rounded down to 3, while
+/// 3.53 is rounded up to 4).
+pub fn round<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    // Equal distance is rounded down
+    to_integer(arguments, host, |float| (float - 0.5).ceil())
+}
+
+/// The $floor function takes a float argument. The result is an integer with the closest value
+/// that is less or equal to the value of the float argument.
+pub fn floor<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    to_integer(arguments, host, f64::floor)
+}
+
+/// The $ceil function takes a float argument. The result is an integer with the closest value that
+/// is greater or equal to the value of the float argument.
+pub fn ceil<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    to_integer(arguments, host, f64::ceil)
+}
+
+fn to_integer<HostT, OperationT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+    operation: OperationT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+    OperationT: Fn(f64) -> f64,
+{
+    if arguments.len() != 1 {
+        return Err("must have 1 argument".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+    let argument = arguments.remove(0);
+
+    let Some(number) = argument.to_number() else {
+        return Err(format!("argument not a float: {}", argument.type_name()));
+    };
+
+    let float = match number.must_be_a_number()? {
+        Number::Float(float) => float,
+
+        // Already an integer
+        _ => return Ok(Some(argument)),
+    };
+
+    let integer: i64 = Number::Float(operation(float)).try_into()?;
+    Ok(Some(integer.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn sum_of_numbers() {
+        assert_eq!(sum(vec![1.into(), 2.into(), 3.into()], &HOST), Ok(Some(Value::Integer(6))));
+        assert_eq!(sum(vec![1.into(), Value::Float(0.5)], &HOST), Ok(Some(Value::Float(1.5))));
+    }
+
+    #[test]
+    fn sum_of_scalars() {
+        assert_eq!(sum(vec![scalar(1, "B"), scalar(2, "B")], &HOST), Ok(Some(scalar(3, "B"))));
+    }
+
+    #[test]
+    fn sum_coerces_strings_into_scalars() {
+        assert_eq!(sum(vec!["1 B".into(), scalar(2, "B")], &HOST), Ok(Some(scalar(3, "B"))));
+    }
+
+    #[test]
+    fn sum_of_strings_in_different_units() {
+        assert_eq!(sum(vec!["1 GB".into(), "512 MB".into()], &HOST), Ok(Some(scalar(1_512_000_000, "B"))));
+        assert_eq!(difference(vec!["1 GB".into(), "512 MB".into()], &HOST), Ok(Some(scalar(488_000_000, "B"))));
+        assert_eq!(sum(vec!["512 MB".into(), scalar(1, "B")], &HOST), Ok(Some(scalar(512_000_001, "B"))));
+    }
+
+    #[test]
+    fn sum_of_strings_in_incompatible_units() {
+        assert_eq!(
+            sum(vec!["1 GB".into(), "1 s".into()], &HOST),
+            Err("incompatible scalars: 1000000000 B, 1 s".into())
+        );
+        assert_eq!(sum(vec!["1 GB".into(), "1 parsec".into()], &HOST), Err("unsupported unit: parsec".into()));
+    }
+
+    #[test]
+    fn sum_of_strings_without_units() {
+        // The host cannot tell whether the strings are scalars
+        assert_eq!(sum(vec!["1 GB".into(), "512 MB".into()], &HOST_WITHOUT_UNITS), Ok(None));
+        assert_eq!(sum(vec![1.into(), 2.into()], &HOST_WITHOUT_UNITS), Ok(Some(Value::Integer(3))));
+    }
+
+    #[test]
+    fn sum_of_incompatible_scalars() {
+        assert_eq!(sum(vec![scalar(1, "B"), scalar(2, "s")], &HOST), Err("incompatible scalars: 1 B, 2 s".into()));
+    }
+
+    #[test]
+    fn sum_of_numbers_and_scalars() {
+        assert_eq!(sum(vec![scalar(1, "B"), 2.into()], &HOST), Err("cannot mix numbers and scalars: 1 B, 2".into()));
+    }
+
+    #[test]
+    fn sum_overflow() {
+        assert_eq!(sum(vec![i64::MAX.into(), 1.into()], &HOST), Err("number overflow".into()));
+    }
+
+    #[test]
+    fn difference_of_scalars() {
+        assert_eq!(difference(vec![scalar(5, "B"), scalar(2, "B")], &HOST), Ok(Some(scalar(3, "B"))));
+        assert_eq!(difference(vec![scalar(2, "B"), scalar(5, "B")], &HOST), Ok(Some(scalar(-3, "B"))));
+    }
+
+    #[test]
+    fn product_of_numbers() {
+        assert_eq!(product(vec![2.into()], &HOST), Ok(Some(Value::Integer(2))));
+        assert_eq!(product(vec![2.into(), 3.into(), 4.into()], &HOST), Ok(Some(Value::Integer(24))));
+        assert_eq!(product(vec![2.into(), Value::Float(0.5)], &HOST), Ok(Some(Value::Float(1.))));
+        assert_eq!(product(vec![i64::MAX.into(), 2.into()], &HOST), Err("number overflow".into()));
+    }
+
+    #[test]
+    fn product_of_scalar() {
+        assert_eq!(product(vec![scalar(2, "B"), 3.into()], &HOST), Ok(Some(scalar(6, "B"))));
+        assert_eq!(
+            product(vec![scalar(2, "B")], &HOST),
+            Err("must have 2 arguments when the first argument is a scalar".into())
+        );
+        assert_eq!(
+            product(vec![scalar(2, "B"), 3.into(), 4.into()], &HOST),
+            Err("must have 2 arguments when the first argument is a scalar".into())
+        );
+        assert_eq!(
+            product(vec![scalar(2, "B"), scalar(3, "B")], &HOST),
+            Err("second argument not an integer or a float: 3 B".into())
+        );
+    }
+
+    #[test]
+    fn quotient_of_numbers() {
+        assert_eq!(quotient(vec![7.into(), 2.into()], &HOST), Ok(Some(Value::Float(3.5))));
+        assert_eq!(quotient(vec![7.into(), 0.into()], &HOST), Err("division by zero".into()));
+    }
+
+    #[test]
+    fn quotient_of_scalar() {
+        // Integer scalars are truncated
+        assert_eq!(
+            quotient(vec![scalar(7, "B"), 2.into()], &HOST),
+            Ok(Some(Value::Custom(TestScalar::new(Number::Float(3.), "B"))))
+        );
+        assert_eq!(
+            quotient(vec![Value::Custom(TestScalar::new(Number::Float(7.), "s")), 2.into()], &HOST),
+            Ok(Some(Value::Custom(TestScalar::new(Number::Float(3.5), "s"))))
+        );
+        assert_eq!(quotient(vec![scalar(7, "B"), 0.into()], &HOST), Err("division by zero".into()));
+    }
+
+    #[test]
+    fn remainder_of_scalar() {
+        assert_eq!(remainder(vec![7.into(), 2.into()], &HOST), Ok(Some(Value::Integer(1))));
+        assert_eq!(remainder(vec![scalar(7, "B"), 2.into()], &HOST), Ok(Some(scalar(1, "B"))));
+        assert_eq!(remainder(vec![7.into(), Value::Float(2.)], &HOST), Err("second argument not an integer: 2".into()));
+    }
+
+    #[test]
+    fn round_floor_ceil() {
+        assert_eq!(round(vec![Value::Float(3.5)], &HOST), Ok(Some(Value::Integer(3))));
+        assert_eq!(round(vec![Value::Float(3.53)], &HOST), Ok(Some(Value::Integer(4))));
+        assert_eq!(floor(vec![Value::Float(-1.5)], &HOST), Ok(Some(Value::Integer(-2))));
+        assert_eq!(ceil(vec![Value::Float(-1.5)], &HOST), Ok(Some(Value::Integer(-1))));
+        assert_eq!(ceil(vec![3.into()], &HOST), Ok(Some(Value::Integer(3))));
+    }
+}
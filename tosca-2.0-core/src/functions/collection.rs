@@ -0,0 +1,251 @@
+use super::{arguments::*, host::*, value::*};
+
+/// The $length function takes an argument of type string, list, or map. It returns the number of
+/// Unicode characters in the string, or the numbers of values in the list, or the number of
+/// key-values pairs in the map.
+pub fn length<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 1 {
+        return Err("must have one argument".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+    let value = arguments.remove(0);
+
+    let length = match &value {
+        Value::Text(text) => text.chars().count(),
+        Value::List(list) => list.len(),
+        Value::Map(map) => map.len(),
+
+        // Extension: bytes
+        Value::Bytes(bytes) => bytes.len(),
+
+        _ => return Err(format!("argument not a string, list, map, or bytes: {}", value.type_name())),
+    };
+
+    let Ok(length) = i64::try_from(length) else {
+        return Err("length won't fit in integer".into());
+    };
+
+    Ok(Some(length.into()))
+}
+
+/// The $concat function takes one or more arguments of either the type string or the type list
+/// with the same type of their entry_schema. In the case of strings, it returns a string which is
+/// the concatenation of the argument strings. In the case of lists, it returns a list that
+/// contains all the entries of all the argument lists. Order is preserved both for strings and
+/// lists. This function does not recurse into the entries of the lists.
+pub fn concat<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    let Some(arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let mut result: Option<Value<HostT::Custom>> = None;
+
+    for argument in arguments {
+        let type_name = argument.type_name();
+
+        let Some(result) = &mut result else {
+            match argument {
+                Value::Text(_) | Value::List(_) | Value::Bytes(_) => result = Some(argument),
+                _ => return Err(format!("argument not a string, list, or bytes: {}", type_name)),
+            }
+            continue;
+        };
+
+        match (result, argument) {
+            (Value::Text(result), Value::Text(text)) => result.push_str(&text),
+            (Value::List(result), Value::List(list)) => result.extend(list),
+
+            // Extension: bytes
+            (Value::Bytes(result), Value::Bytes(bytes)) => result.extend(bytes),
+
+            (Value::Text(_), _) => return Err(format!("argument not a string: {}", type_name)),
+            (Value::List(_), _) => return Err(format!("argument not a list: {}", type_name)),
+            _ => return Err(format!("argument not bytes: {}", type_name)),
+        }
+    }
+
+    match result {
+        Some(result) => Ok(Some(result)),
+        None => Err("no arguments provided".into()),
+    }
+}
+
+/// The $join function takes either one or two arguments where the first one is of type list of
+/// strings and the second (optional) argument is of type string. It returns a string that is the
+/// joining of the entries in the first argument while adding an optional delimiter between the
+/// strings.
+pub fn join<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    let length = arguments.len();
+    if (length != 1) && (length != 2) {
+        return Err("must have 1 or 2 arguments".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let argument = arguments.remove(0);
+    let Value::List(list) = argument else {
+        return Err(format!("first argument not a list: {}", argument.type_name()));
+    };
+
+    let delimiter = if !arguments.is_empty() {
+        let argument = arguments.remove(0);
+        let Value::Text(delimiter) = argument else {
+            return Err(format!("second argument not a string: {}", argument.type_name()));
+        };
+        delimiter
+    } else {
+        Default::default()
+    };
+
+    let mut strings = Vec::with_capacity(list.len());
+    for (index, item) in list.iter().enumerate() {
+        let Value::Text(string) = item else {
+            return Err(format!("first argument item {} not a string: {}", index, item.type_name()));
+        };
+        strings.push(string.as_str());
+    }
+
+    Ok(Some(strings.join(&delimiter).into()))
+}
+
+/// The $token function is used within a TOSCA service template on a string to parse out (tokenize)
+/// substrings separated by one or more token characters within a larger string.
+///
+/// Arguments:
+///
+/// * The composite string that contains one or more substrings separated by token characters.
+/// * The string that contains one or more token characters that separate substrings within the
+///   composite string.
+/// * The integer indicates the index of the substring to return from the composite string. Note
+///   that the first substring is denoted by using the '0' (zero) integer value.
+pub fn token<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 3 {
+        return Err("must have 3 arguments".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let argument = arguments.remove(0);
+    let Value::Text(string) = argument else {
+        return Err(format!("first argument not a string: {}", argument.type_name()));
+    };
+
+    let argument = arguments.remove(0);
+    let Value::Text(separators) = argument else {
+        return Err(format!("second argument not a string: {}", argument.type_name()));
+    };
+
+    if separators.is_empty() {
+        return Err("second argument is empty".into());
+    }
+
+    let argument = arguments.remove(0);
+    let index: usize = match argument {
+        Value::Integer(integer) => {
+            integer.try_into().map_err(|_| format!("third argument is negative: {}", integer))?
+        }
+
+        Value::UnsignedInteger(unsigned_integer) => {
+            unsigned_integer.try_into().map_err(|_| format!("third argument too large: {}", unsigned_integer))?
+        }
+
+        _ => return Err(format!("third argument not an integer: {}", argument.type_name())),
+    };
+
+    // Each character is a separator, and consecutive separators count as one
+    let mut tokens = string.split(|character| separators.contains(character)).filter(|token| !token.is_empty());
+    let count = tokens.clone().count();
+
+    match tokens.nth(index) {
+        Some(token) => Ok(Some(token.into())),
+        None => Err(format!("index out of bounds: {} (tokens: {})", index, count)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn length_counts_characters() {
+        assert_eq!(length(vec!["hello".into()], &HOST), Ok(Some(5.into())));
+
+        // Multibyte characters
+        assert_eq!(length(vec!["héllo".into()], &HOST), Ok(Some(5.into())));
+        assert_eq!(length(vec!["日本語".into()], &HOST), Ok(Some(3.into())));
+        assert_eq!(length(vec!["👍🏽".into()], &HOST), Ok(Some(2.into())));
+        assert_eq!(length(vec!["".into()], &HOST), Ok(Some(0.into())));
+    }
+
+    #[test]
+    fn length_of_collections() {
+        assert_eq!(length(vec![vec![1.into(), 2.into()].into()], &HOST), Ok(Some(2.into())));
+        assert_eq!(length(vec![Value::Map(vec![("a".into(), 1.into())])], &HOST), Ok(Some(1.into())));
+        assert_eq!(length(vec![Value::Bytes("日本".into())], &HOST), Ok(Some(6.into())));
+        assert_eq!(length(vec![1.into()], &HOST), Err("argument not a string, list, map, or bytes: integer".into()));
+    }
+
+    #[test]
+    fn token_skips_empty_tokens() {
+        // Consecutive, leading, and trailing separators do not make empty tokens
+        let arguments = |index: i64| vec!["::a:,b,,:c::".into(), ":,".into(), index.into()];
+        assert_eq!(token(arguments(0), &HOST), Ok(Some("a".into())));
+        assert_eq!(token(arguments(1), &HOST), Ok(Some("b".into())));
+        assert_eq!(token(arguments(2), &HOST), Ok(Some("c".into())));
+        assert_eq!(token(arguments(3), &HOST), Err("index out of bounds: 3 (tokens: 3)".into()));
+    }
+
+    #[test]
+    fn token_multibyte() {
+        assert_eq!(token(vec!["日本·語".into(), "·".into(), 1.into()], &HOST), Ok(Some("語".into())));
+    }
+
+    #[test]
+    fn token_out_of_range() {
+        assert_eq!(
+            token(vec!["".into(), ":".into(), 0.into()], &HOST),
+            Err("index out of bounds: 0 (tokens: 0)".into())
+        );
+        assert_eq!(
+            token(vec![":::".into(), ":".into(), 0.into()], &HOST),
+            Err("index out of bounds: 0 (tokens: 0)".into())
+        );
+        assert_eq!(
+            token(vec!["a:b".into(), ":".into(), (-1).into()], &HOST),
+            Err("third argument is negative: -1".into())
+        );
+        assert_eq!(
+            token(vec!["a:b".into(), ":".into(), Value::UnsignedInteger(2)], &HOST),
+            Err("index out of bounds: 2 (tokens: 2)".into())
+        );
+    }
+
+    #[test]
+    fn token_invalid_arguments() {
+        assert_eq!(token(vec!["a:b".into(), "".into(), 0.into()], &HOST), Err("second argument is empty".into()));
+        assert_eq!(
+            token(vec!["a:b".into(), ":".into(), "0".into()], &HOST),
+            Err("third argument not an integer: string".into())
+        );
+        assert_eq!(token(vec!["a:b".into(), ":".into()], &HOST), Err("must have 3 arguments".into()));
+    }
+}
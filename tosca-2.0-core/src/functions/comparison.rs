@@ -0,0 +1,163 @@
+use super::{arguments::*, host::*, value::*};
+
+use std::cmp::*;
+
+/// The $equal function takes two arguments that have the same type. It evaluates to true if the
+/// arguments are equal. An $equal function that uses arguments of different types SHOULD be
+/// flagged as an error.
+pub fn equal<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    let Some((left, right)) = evaluate_comparable(arguments, host)? else {
+        return Ok(None);
+    };
+
+    // Note: equal can work on any type!
+    // Custom values, such as scalars, are equal if their comparators are equal
+
+    Ok(Some(
+        if deep_equal(&left, &right, host)? {
+            true
+        } else {
+            host.set_assert_reason(format!("{} = {}", left, right))?;
+            false
+        }
+        .into(),
+    ))
+}
+
+/// The $greater_than function takes two arguments of integer, float, string, timestamp, version,
+/// any scalar type, or their derivations. It evaluates to true if both arguments are of the same
+/// type, and if the first argument is greater than the second argument and evaluates to false
+/// otherwise.
+pub fn greater_than<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    compare(arguments, host, ">", Ordering::is_gt)
+}
+
+/// The $greater_or_equal function takes two arguments of integer, float, string, timestamp,
+/// version, any scalar type, or their derivations. It evaluates to true if both arguments are of
+/// the same type, and if the first argument is greater than or equal to the second argument and
+/// evaluates to false otherwise.
+pub fn greater_or_equal<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    compare(arguments, host, ">=", Ordering::is_ge)
+}
+
+/// The $less_than function takes two arguments of integer, float, string, timestamp, version, any
+/// scalar type, or their derivations. It evaluates to true if both arguments are of the same type,
+/// and if the first argument is less than the second argument and evaluates to false otherwise.
+pub fn less_than<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    compare(arguments, host, "<", Ordering::is_lt)
+}
+
+/// The $less_or_equal function takes two arguments of integer, float, string, timestamp, version,
+/// any scalar type, or their derivations. It evaluates to true if both arguments are of the same
+/// type, and if the first argument is less than or equal to the second argument and evaluates to
+/// false otherwise
+pub fn less_or_equal<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    compare(arguments, host, "<=", Ordering::is_le)
+}
+
+fn compare<HostT, TestT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+    operator: &str,
+    test: TestT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+    TestT: Fn(Ordering) -> bool,
+{
+    let Some((left, right)) = evaluate_comparable(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let left = comparator(left, host)?;
+    let right = comparator(right, host)?;
+
+    Ok(Some(
+        if left.compare(&right).is_some_and(test) {
+            true
+        } else {
+            host.set_assert_reason(format!("{} {} {}", left, operator, right))?;
+            false
+        }
+        .into(),
+    ))
+}
+
+// Two arguments coerced to each other's custom kind and of the same type
+#[allow(clippy::type_complexity)]
+fn evaluate_comparable<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<(Value<HostT::Custom>, Value<HostT::Custom>)>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 2 {
+        return Err("must have 2 arguments".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let left = arguments.remove(0);
+    let right = arguments.remove(0);
+
+    let left = host.coerce_if_custom(left, &right)?;
+    let right = host.coerce_if_custom(right, &left)?;
+
+    let same_type = match (&left, &right) {
+        (Value::Custom(left), Value::Custom(right)) => host.custom_kind(left) == host.custom_kind(right),
+        _ => left.same_type(&right),
+    };
+
+    if !same_type {
+        return Err(format!("arguments must be of the same type: {}, {}", left.type_name(), right.type_name()));
+    }
+
+    Ok(Some((left, right)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    #[test]
+    fn equal_scalars_in_different_units() {
+        // The original representations differ, but the canonical values are equal
+        let gigabyte = Value::Custom(parse_scalar("1 GB").expect("scalar"));
+        assert_eq!(equal(vec![gigabyte.clone(), "1000 MB".into()], &HOST), Ok(Some(true.into())));
+        assert_eq!(equal(vec![gigabyte, "999 MB".into()], &HOST), Ok(Some(false.into())));
+    }
+
+    #[test]
+    fn compare_scalars_in_different_units() {
+        let gigabyte = Value::Custom(parse_scalar("1 GB").expect("scalar"));
+        assert_eq!(greater_than(vec![gigabyte.clone(), "999 MB".into()], &HOST), Ok(Some(true.into())));
+        assert_eq!(less_than(vec![gigabyte, "1000 MB".into()], &HOST), Ok(Some(false.into())));
+    }
+}
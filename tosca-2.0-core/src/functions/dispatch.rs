@@ -0,0 +1,91 @@
+use super::{arithmetic::*, collection::*, comparison::*, host::*, logic::*, set::*, value::*};
+
+/// Built-in functions that have a shared implementation.
+///
+/// Their results depend only on their arguments, so they can be evaluated by any
+/// [FunctionHost].
+pub const CORE_FUNCTIONS: &[&str] = &[
+    // Boolean logic
+    "and",
+    "or",
+    "not",
+    "xor",
+    // Boolean comparison
+    "equal",
+    "greater_than",
+    "greater_or_equal",
+    "less_than",
+    "less_or_equal",
+    // Collection
+    "length",
+    "concat",
+    "join",
+    "token",
+    // Set
+    "union",
+    "intersection",
+    // Arithmetic
+    "sum",
+    "difference",
+    "product",
+    "quotient",
+    "remainder",
+    "round",
+    "floor",
+    "ceil",
+];
+
+/// True if the built-in function has a shared implementation.
+pub fn is_core_function(function_name: &str) -> bool {
+    CORE_FUNCTIONS.contains(&function_name)
+}
+
+/// Dispatch a built-in function with a shared implementation.
+///
+/// Returns [None] if the function is not in [CORE_FUNCTIONS] or if the host could not evaluate
+/// its arguments.
+pub fn dispatch<HostT>(
+    function_name: &str,
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    match function_name {
+        // Boolean logic
+        "and" => and(arguments, host),
+        "or" => or(arguments, host),
+        "not" => not(arguments, host),
+        "xor" => xor(arguments, host),
+
+        // Boolean comparison
+        "equal" => equal(arguments, host),
+        "greater_than" => greater_than(arguments, host),
+        "greater_or_equal" => greater_or_equal(arguments, host),
+        "less_than" => less_than(arguments, host),
+        "less_or_equal" => less_or_equal(arguments, host),
+
+        // Collection
+        "length" => length(arguments, host),
+        "concat" => concat(arguments, host),
+        "join" => join(arguments, host),
+        "token" => token(arguments, host),
+
+        // Set
+        "union" => union(arguments, host),
+        "intersection" => intersection(arguments, host),
+
+        // Arithmetic
+        "sum" => sum(arguments, host),
+        "difference" => difference(arguments, host),
+        "product" => product(arguments, host),
+        "quotient" => quotient(arguments, host),
+        "remainder" => remainder(arguments, host),
+        "round" => round(arguments, host),
+        "floor" => floor(arguments, host),
+        "ceil" => ceil(arguments, host),
+
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,110 @@
+use super::{number::*, value::*};
+
+use std::{convert::*, fmt};
+
+//
+// FunctionHost
+//
+
+/// Host for the built-in functions.
+///
+/// The compiler implements it for native evaluation and the `tosca-2.0-functions` plugin
+/// implements it over the Floria call site.
+pub trait FunctionHost {
+    /// Unevaluated argument.
+    type Argument;
+
+    /// Custom value, e.g. a scalar, a timestamp, or a version.
+    type Custom: Clone + fmt::Display + PartialEq;
+
+    /// Scalar operand for arithmetic.
+    type Scalar: ScalarOperand<Custom = Self::Custom>;
+
+    /// Evaluate an argument.
+    ///
+    /// Returns [None] if the argument cannot be evaluated by this host, in which case the function
+    /// returns [None] as well.
+    fn evaluate(&self, argument: Self::Argument) -> Result<Option<Value<Self::Custom>>, String>;
+
+    /// If the other value is custom, coerce into its kind.
+    fn coerce_if_custom(
+        &self,
+        value: Value<Self::Custom>,
+        _other: &Value<Self::Custom>,
+    ) -> Result<Value<Self::Custom>, String> {
+        Ok(value)
+    }
+
+    /// Coerce a string into a scalar when no other operand is a scalar.
+    ///
+    /// Hosts that have a scalar schema coerce it into the schema's canonical unit. Others return
+    /// the value as is, which makes it an invalid operand. Returns [None] if the host cannot tell
+    /// whether the string is a scalar, in which case the function returns [None] as well.
+    fn coerce_scalar(&self, value: Value<Self::Custom>) -> Result<Option<Value<Self::Custom>>, String> {
+        Ok(Some(value))
+    }
+
+    /// Custom kind, e.g. "scalar".
+    fn custom_kind(&self, custom: &Self::Custom) -> String;
+
+    /// Comparable value for a custom value.
+    ///
+    /// The result must not be custom.
+    fn custom_comparator(&self, custom: &Self::Custom) -> Result<Value<Self::Custom>, String>;
+
+    /// Set the reason for a false assertion.
+    fn set_assert_reason(&self, _reason: String) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+//
+// ScalarOperand
+//
+
+/// Scalar that can be used as an arithmetic [Operand](super::Operand).
+pub trait ScalarOperand: Clone + fmt::Display + Sized {
+    /// Custom value.
+    type Custom;
+
+    /// From a custom value.
+    ///
+    /// The result is in the canonical unit.
+    fn from_custom(custom: Self::Custom) -> Result<Self, String>;
+
+    /// Into a custom value.
+    fn into_custom(self) -> Self::Custom;
+
+    /// Number in the canonical unit.
+    fn number(&self) -> Number;
+
+    /// True if both scalars share the same canonical unit.
+    fn is_compatible(&self, other: &Self) -> bool;
+
+    /// With a new number in the canonical unit.
+    fn with_number(self, number: Number) -> Result<Self, String>;
+}
+
+impl ScalarOperand for Infallible {
+    type Custom = Infallible;
+
+    fn from_custom(custom: Self::Custom) -> Result<Self, String> {
+        match custom {}
+    }
+
+    fn into_custom(self) -> Self::Custom {
+        self
+    }
+
+    fn number(&self) -> Number {
+        match *self {}
+    }
+
+    fn is_compatible(&self, _other: &Self) -> bool {
+        match *self {}
+    }
+
+    fn with_number(self, _number: Number) -> Result<Self, String> {
+        match self {}
+    }
+}
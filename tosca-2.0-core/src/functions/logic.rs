@@ -0,0 +1,102 @@
+use super::{arguments::*, host::*, value::*};
+
+/// The $and function takes one or more Boolean arguments. It evaluates to true if all its
+/// arguments evaluate to true. It evaluates to false in all other cases.
+///
+/// (TOSCA 2.0 requires two or more arguments, but a single argument is allowed as errata.)
+pub fn and<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    short_circuit(arguments, host, false)
+}
+
+/// The $or function takes one or more Boolean arguments. It evaluates to false if all of its
+/// arguments evaluate to false. It evaluates to true in all other cases.
+///
+/// (TOSCA 2.0 requires two or more arguments, but a single argument is allowed as errata.)
+pub fn or<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    short_circuit(arguments, host, true)
+}
+
+/// The $not function takes one Boolean argument. It evaluates to true if its argument evaluates to
+/// false and evaluates to false if its argument evaluates to true.
+pub fn not<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 1 {
+        return Err("must have one boolean argument".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let argument = arguments.remove(0);
+    let Value::Boolean(value) = argument else {
+        return Err(format!("first argument not a boolean: {}", argument.type_name()));
+    };
+
+    Ok(Some((!value).into()))
+}
+
+/// The $xor function takes two Boolean arguments. It evaluates to false if both arguments either
+/// evaluate to true or both arguments evaluate to false, and evaluates to true otherwise.
+pub fn xor<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.len() != 2 {
+        return Err("must have 2 boolean arguments".into());
+    }
+
+    let Some(mut arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let argument = arguments.remove(0);
+    let Value::Boolean(left) = argument else {
+        return Err(format!("first argument not a boolean: {}", argument.type_name()));
+    };
+
+    let argument = arguments.remove(0);
+    let Value::Boolean(right) = argument else {
+        return Err(format!("second argument not a boolean: {}", argument.type_name()));
+    };
+
+    Ok(Some((left != right).into()))
+}
+
+// Arguments are evaluated in order until one of them is the decisive value
+fn short_circuit<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+    decisive: bool,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.is_empty() {
+        return Err("must have at least one boolean argument".into());
+    }
+
+    for argument in arguments {
+        let Some(argument) = host.evaluate(argument)? else {
+            return Ok(None);
+        };
+
+        let Value::Boolean(argument) = argument else {
+            return Err(format!("argument not a boolean: {}", argument.type_name()));
+        };
+
+        if argument == decisive {
+            return Ok(Some(decisive.into()));
+        }
+    }
+
+    Ok(Some((!decisive).into()))
+}
@@ -0,0 +1,21 @@
+mod allocation;
+mod arguments;
+mod arithmetic;
+mod collection;
+mod comparison;
+mod dispatch;
+mod host;
+mod logic;
+mod number;
+mod operand;
+mod set;
+mod value;
+
+#[cfg(test)]
+mod test;
+
+#[allow(unused_imports)]
+pub use {
+    allocation::*, arguments::*, arithmetic::*, collection::*, comparison::*, dispatch::*, host::*, logic::*,
+    number::*, operand::*, set::*, value::*,
+};
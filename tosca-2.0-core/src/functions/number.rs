@@ -1,10 +1,11 @@
 use {
-    floria_plugin_sdk::data::*,
     num_traits::*,
-    std::{fmt, str::*},
+    std::{cmp::*, fmt, str::*},
 };
 
 const OVERFLOW_ERROR: &str = "number overflow";
+const NAN_ERROR: &str = "not a number (NaN)";
+const DIVISION_BY_ZERO_ERROR: &str = "division by zero";
 
 //
 // Number
@@ -103,6 +104,126 @@ impl Number {
     }
 }
 
+impl Number {
+    /// Error if NaN.
+    pub fn must_be_a_number(self) -> Result<Self, String> {
+        match self {
+            Self::Float(float) if float.is_nan() => Err(NAN_ERROR.into()),
+            _ => Ok(self),
+        }
+    }
+
+    /// True if integer or unsigned integer.
+    pub fn is_integer(self) -> bool {
+        !matches!(self, Self::Float(_))
+    }
+
+    /// Truncate the fractional part of a float.
+    pub fn truncate(self) -> Self {
+        match self {
+            Self::Float(float) => Self::Float(float.trunc()),
+            _ => self,
+        }
+    }
+
+    /// True if 0.
+    pub fn is_zero(self) -> bool {
+        match self {
+            Self::Integer(integer) => integer == 0,
+            Self::UnsignedInteger(unsigned_integer) => unsigned_integer == 0,
+            Self::Float(float) => float == 0.,
+        }
+    }
+
+    /// True if less than 0.
+    pub fn is_negative(self) -> bool {
+        match self {
+            Self::Integer(integer) => integer < 0,
+            Self::UnsignedInteger(_) => false,
+            Self::Float(float) => float < 0.,
+        }
+    }
+
+    /// Add.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, right: Self) -> Result<Self, String> {
+        match Operands::new(self, right)? {
+            Operands::Integers(left, right) => left.checked_add(right).map(Self::Integer).ok_or(OVERFLOW_ERROR.into()),
+
+            Operands::UnsignedIntegers(left, right) => {
+                left.checked_add(right).map(Self::UnsignedInteger).ok_or(OVERFLOW_ERROR.into())
+            }
+
+            Operands::Floats(left, right) => Self::Float(left + right).must_be_a_number(),
+        }
+    }
+
+    /// Subtract.
+    pub fn subtract(self, right: Self) -> Result<Self, String> {
+        match Operands::new(self, right)? {
+            Operands::Integers(left, right) => left.checked_sub(right).map(Self::Integer).ok_or(OVERFLOW_ERROR.into()),
+
+            Operands::UnsignedIntegers(left, right) => {
+                if left >= right {
+                    Ok(Self::UnsignedInteger(left - right))
+                } else {
+                    // The result is negative
+                    Self::Integer(cast(left).ok_or(OVERFLOW_ERROR)?).subtract(right.into())
+                }
+            }
+
+            Operands::Floats(left, right) => Self::Float(left - right).must_be_a_number(),
+        }
+    }
+
+    /// Divide.
+    ///
+    /// The result is always a float.
+    pub fn divide(self, right: Self) -> Result<Self, String> {
+        if right.is_zero() {
+            return Err(DIVISION_BY_ZERO_ERROR.into());
+        }
+
+        let left: f64 = self.try_into()?;
+        let right: f64 = right.try_into()?;
+        Self::Float(left / right).must_be_a_number()
+    }
+
+    /// Compare.
+    ///
+    /// Integers are compared exactly and floats numerically.
+    pub fn compare(self, right: Self) -> Option<Ordering> {
+        match (self, right) {
+            (Self::Integer(left), Self::Integer(right)) => Some(left.cmp(&right)),
+            (Self::UnsignedInteger(left), Self::UnsignedInteger(right)) => Some(left.cmp(&right)),
+            (Self::Integer(left), Self::UnsignedInteger(right)) => Some((left as i128).cmp(&(right as i128))),
+            (Self::UnsignedInteger(left), Self::Integer(right)) => Some((left as i128).cmp(&(right as i128))),
+            _ => {
+                let left: f64 = self.try_into().ok()?;
+                let right: f64 = right.try_into().ok()?;
+                left.partial_cmp(&right)
+            }
+        }
+    }
+
+    /// Remainder.
+    pub fn remainder(self, right: Self) -> Result<Self, String> {
+        if right.is_zero() {
+            return Err(DIVISION_BY_ZERO_ERROR.into());
+        }
+
+        match Operands::new(self, right)? {
+            Operands::Integers(left, right) => left.checked_rem(right).map(Self::Integer).ok_or(OVERFLOW_ERROR.into()),
+
+            Operands::UnsignedIntegers(left, right) => {
+                left.checked_rem(right).map(Self::UnsignedInteger).ok_or(OVERFLOW_ERROR.into())
+            }
+
+            Operands::Floats(left, right) => Self::Float(left % right).must_be_a_number(),
+        }
+    }
+}
+
 impl Default for Number {
     fn default() -> Self {
         Self::Integer(0)
@@ -135,20 +256,7 @@ impl FromStr for Number {
             return Ok(Self::Float(float));
         }
 
-        Err(format!("not a number: {}", representation).into())
-    }
-}
-
-impl TryFrom<&Expression> for Number {
-    type Error = String;
-
-    fn try_from(expression: &Expression) -> Result<Self, Self::Error> {
-        match expression {
-            Expression::Integer(integer) => Ok(Self::Integer(*integer)),
-            Expression::UnsignedInteger(unsigned_integer) => Ok(Self::UnsignedInteger(*unsigned_integer)),
-            Expression::Float(float) => Ok(Self::Float(*float)),
-            _ => Err(format!("not an integer, unsigned integer, or float: {}", expression.type_name())),
-        }
+        Err(format!("not a number: {}", representation))
     }
 }
 
@@ -206,12 +314,47 @@ impl TryInto<f64> for Number {
     }
 }
 
-impl Into<Expression> for Number {
-    fn into(self) -> Expression {
-        match self {
-            Self::Integer(integer) => integer.into(),
-            Self::UnsignedInteger(unsigned_integer) => unsigned_integer.into(),
-            Self::Float(float) => float.into(),
-        }
+impl From<i64> for Number {
+    fn from(integer: i64) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl From<u64> for Number {
+    fn from(unsigned_integer: u64) -> Self {
+        Self::UnsignedInteger(unsigned_integer)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(float: f64) -> Self {
+        Self::Float(float)
+    }
+}
+
+//
+// Operands
+//
+
+// Operands converted to a common type
+enum Operands {
+    Integers(i64, i64),
+    UnsignedIntegers(u64, u64),
+    Floats(f64, f64),
+}
+
+impl Operands {
+    fn new(left: Number, right: Number) -> Result<Self, String> {
+        let left = left.must_be_a_number()?;
+        let right = right.must_be_a_number()?;
+
+        Ok(match (left, right) {
+            (Number::Integer(left), Number::Integer(right)) => Self::Integers(left, right),
+            (Number::UnsignedInteger(left), Number::UnsignedInteger(right)) => Self::UnsignedIntegers(left, right),
+            (Number::Integer(_) | Number::UnsignedInteger(_), Number::Integer(_) | Number::UnsignedInteger(_)) => {
+                Self::Integers(left.try_into()?, right.try_into()?)
+            }
+            _ => Self::Floats(left.try_into()?, right.try_into()?),
+        })
     }
 }
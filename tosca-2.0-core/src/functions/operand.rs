@@ -0,0 +1,134 @@
+use super::{arguments::*, host::*, number::*, value::*};
+
+use std::fmt;
+
+//
+// Operand
+//
+
+/// Arithmetic operand.
+#[derive(Clone, Debug)]
+pub enum Operand<ScalarT> {
+    /// Number.
+    Number(Number),
+
+    /// Scalar in its canonical unit.
+    Scalar(ScalarT),
+}
+
+impl<ScalarT> Operand<ScalarT>
+where
+    ScalarT: ScalarOperand,
+{
+    /// Constructor.
+    pub fn new(value: Value<ScalarT::Custom>) -> Result<Self, String> {
+        match value {
+            Value::Integer(_) | Value::UnsignedInteger(_) | Value::Float(_) => {
+                let number = value.to_number().expect("number");
+                Ok(Self::Number(number.must_be_a_number()?))
+            }
+
+            Value::Custom(custom) => Ok(Self::Scalar(ScalarT::from_custom(custom)?)),
+
+            _ => Err(format!("not an integer, a float, or a scalar: {}", value.type_name())),
+        }
+    }
+
+    /// As number.
+    pub fn must_be_number(self) -> Result<Number, String> {
+        match self {
+            Self::Number(number) => Ok(number),
+            Self::Scalar(scalar) => Err(format!("not an integer or a float: {}", scalar)),
+        }
+    }
+
+    /// True if less than 0.
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Self::Number(number) => number.is_negative(),
+            Self::Scalar(scalar) => scalar.number().is_negative(),
+        }
+    }
+
+    /// Apply a binary operation.
+    ///
+    /// Both operands must be numbers or both must be compatible scalars.
+    pub fn apply<OperationT>(self, right: Self, operation: OperationT) -> Result<Self, String>
+    where
+        OperationT: Fn(Number, Number) -> Result<Number, String>,
+    {
+        match (self, right) {
+            (Self::Number(left), Self::Number(right)) => Ok(Self::Number(operation(left, right)?)),
+
+            (Self::Scalar(left), Self::Scalar(right)) => {
+                if !left.is_compatible(&right) {
+                    return Err(format!("incompatible scalars: {}, {}", left, right));
+                }
+
+                let number = operation(left.number(), right.number())?;
+                Ok(Self::Scalar(left.with_number(number)?))
+            }
+
+            (left, right) => Err(format!("cannot mix numbers and scalars: {}, {}", left, right)),
+        }
+    }
+
+    /// Into a value.
+    pub fn into_value(self) -> Value<ScalarT::Custom> {
+        match self {
+            Self::Number(number) => number.into(),
+            Self::Scalar(scalar) => Value::Custom(scalar.into_custom()),
+        }
+    }
+}
+
+impl<ScalarT> fmt::Display for Operand<ScalarT>
+where
+    ScalarT: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => fmt::Display::fmt(number, formatter),
+            Self::Scalar(scalar) => fmt::Display::fmt(scalar, formatter),
+        }
+    }
+}
+
+/// Evaluate arguments into operands.
+///
+/// String arguments will be coerced into scalars: into the kind of the first scalar argument if
+/// there is one, otherwise via [FunctionHost::coerce_scalar]. Other arguments are not coerced.
+///
+/// Returns [None] if an argument cannot be evaluated by the host.
+pub fn evaluate_operands<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Vec<Operand<HostT::Scalar>>>, String>
+where
+    HostT: FunctionHost,
+{
+    let Some(evaluated) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let custom = evaluated.iter().find(|argument| matches!(argument, Value::Custom(_))).cloned();
+
+    let mut operands = Vec::with_capacity(evaluated.len());
+    for (index, argument) in evaluated.into_iter().enumerate() {
+        // Numbers are left as they are, e.g. the factor in `$product: [ <scalar>, <number> ]`
+        let argument = match (&custom, &argument) {
+            (Some(custom), Value::Text(_)) => host.coerce_if_custom(argument, custom)?,
+
+            (None, Value::Text(_)) => match host.coerce_scalar(argument)? {
+                Some(argument) => argument,
+                None => return Ok(None),
+            },
+
+            _ => argument,
+        };
+
+        operands.push(Operand::new(argument).map_err(|error| format!("argument {}: {}", index, error))?);
+    }
+
+    Ok(Some(operands))
+}
@@ -0,0 +1,190 @@
+use super::{arguments::*, host::*, value::*};
+
+/// The $union function takes one or more list arguments, all having the entry schema of the same
+/// type. The result is a list that contains all non-duplicate entries from all the argument lists.
+/// By non-duplicate is meant that no two entries in the result list are equal.
+pub fn union<HostT>(arguments: Vec<HostT::Argument>, host: &HostT) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    let Some(lists) = evaluate_lists(arguments, host)? else {
+        return Ok(None);
+    };
+
+    // Keep the order of first occurrence
+    let mut union = Vec::default();
+    for entry in lists.into_iter().flatten() {
+        if !contains_entry(&union, &entry, host)? {
+            union.push(entry);
+        }
+    }
+
+    Ok(Some(union.into()))
+}
+
+/// The $intersection function takes one or more list arguments, all having the entry schema of the
+/// same type. The result is a list that contains all entries that can be found in each of the
+/// argument lists.
+pub fn intersection<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Value<HostT::Custom>>, String>
+where
+    HostT: FunctionHost,
+{
+    let Some(lists) = evaluate_lists(arguments, host)? else {
+        return Ok(None);
+    };
+    let mut lists = lists.into_iter();
+    let first = lists.next().unwrap_or_default();
+    let others: Vec<_> = lists.collect();
+
+    // Keep the order of first occurrence in the first list
+    let mut intersection = Vec::default();
+    'entries: for entry in first {
+        if contains_entry(&intersection, &entry, host)? {
+            continue;
+        }
+
+        for other in &others {
+            if !contains_entry(other, &entry, host)? {
+                continue 'entries;
+            }
+        }
+
+        intersection.push(entry);
+    }
+
+    Ok(Some(intersection.into()))
+}
+
+// String entries will be coerced if there are custom entries (e.g. scalars). All entries must be
+// of the same type, where all numbers are considered the same type.
+#[allow(clippy::type_complexity)]
+fn evaluate_lists<HostT>(
+    arguments: Vec<HostT::Argument>,
+    host: &HostT,
+) -> Result<Option<Vec<Vec<Value<HostT::Custom>>>>, String>
+where
+    HostT: FunctionHost,
+{
+    if arguments.is_empty() {
+        return Err("must have at least one list argument".into());
+    }
+
+    let Some(arguments) = evaluate_arguments(arguments, host)? else {
+        return Ok(None);
+    };
+
+    let mut lists = Vec::with_capacity(arguments.len());
+    for (index, argument) in arguments.into_iter().enumerate() {
+        let Value::List(list) = argument else {
+            return Err(format!("argument {} not a list: {}", index, argument.type_name()));
+        };
+        lists.push(list);
+    }
+
+    let custom = lists.iter().flatten().find(|entry| matches!(entry, Value::Custom(_))).cloned();
+
+    let mut entry_type: Option<String> = None;
+    for (index, list) in lists.iter_mut().enumerate() {
+        for entry in list.iter_mut() {
+            if let Some(custom) = &custom {
+                *entry = host.coerce_if_custom(entry.clone(), custom)?;
+            }
+
+            let type_ = entry_type_name(entry, host);
+            match &entry_type {
+                Some(entry_type) => {
+                    if *entry_type != type_ {
+                        return Err(format!(
+                            "argument {} entry schema mismatch: {} instead of {}",
+                            index, type_, entry_type
+                        ));
+                    }
+                }
+
+                None => entry_type = Some(type_),
+            }
+        }
+    }
+
+    Ok(Some(lists))
+}
+
+// True if the entries contain an entry that is deeply equal
+fn contains_entry<HostT>(
+    entries: &[Value<HostT::Custom>],
+    entry: &Value<HostT::Custom>,
+    host: &HostT,
+) -> Result<bool, String>
+where
+    HostT: FunctionHost,
+{
+    for entry_ in entries {
+        if deep_equal(entry_, entry, host)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Custom values are identified by their kind, and numbers are all the same type
+fn entry_type_name<HostT>(entry: &Value<HostT::Custom>, host: &HostT) -> String
+where
+    HostT: FunctionHost,
+{
+    match entry {
+        Value::Custom(custom) => host.custom_kind(custom),
+        Value::Integer(_) | Value::UnsignedInteger(_) | Value::Float(_) => "number".into(),
+        _ => entry.type_name().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    fn list(entries: Vec<Value<TestScalar>>) -> Value<TestScalar> {
+        entries.into()
+    }
+
+    #[test]
+    fn union_of_mixed_numbers() {
+        let union =
+            union(vec![list(vec![1.into(), Value::Float(1.5)]), list(vec![Value::Float(1.0), 2.into()])], &HOST);
+        assert_eq!(union, Ok(Some(list(vec![1.into(), Value::Float(1.5), 2.into()]))));
+    }
+
+    #[test]
+    fn intersection_of_mixed_numbers() {
+        let intersection = intersection(
+            vec![list(vec![1.into(), Value::Float(2.5), 3.into()]), list(vec![Value::Float(3.0), Value::Float(2.5)])],
+            &HOST,
+        );
+        assert_eq!(intersection, Ok(Some(list(vec![Value::Float(2.5), 3.into()]))));
+    }
+
+    #[test]
+    fn union_of_scalars() {
+        // Text entries are coerced into scalars and compared on their canonical values
+        let gigabyte = Value::Custom(parse_scalar("1 GB").expect("scalar"));
+        let megabyte = Value::Custom(parse_scalar("1 MB").expect("scalar"));
+        let union = union(vec![list(vec![gigabyte.clone()]), list(vec!["1000 MB".into(), "1 MB".into()])], &HOST);
+        assert_eq!(union, Ok(Some(list(vec![gigabyte, megabyte]))));
+    }
+
+    #[test]
+    fn entry_schema_mismatch() {
+        assert_eq!(
+            union(vec![list(vec![1.into()]), list(vec!["a".into()])], &HOST_WITHOUT_UNITS),
+            Err("argument 1 entry schema mismatch: string instead of number".into())
+        );
+    }
+
+    #[test]
+    fn empty_arguments() {
+        assert_eq!(union(vec![], &HOST), Err("must have at least one list argument".into()));
+        assert_eq!(intersection(vec![list(vec![])], &HOST), Ok(Some(list(vec![]))));
+    }
+}
@@ -0,0 +1,124 @@
+use super::{host::*, number::*, value::*};
+
+use std::{cmp::*, fmt};
+
+// Scalar that is always in its canonical unit, but remembers its original representation
+#[derive(Clone, Debug)]
+pub struct TestScalar {
+    number: Number,
+    unit: &'static str,
+    original: Option<String>,
+}
+
+impl TestScalar {
+    pub fn new(number: Number, unit: &'static str) -> Self {
+        Self { number, unit, original: None }
+    }
+}
+
+// Unlike the comparator, also compares the original representation
+impl PartialEq for TestScalar {
+    fn eq(&self, other: &Self) -> bool {
+        (self.unit == other.unit)
+            && (self.number.compare(other.number) == Some(Ordering::Equal))
+            && (self.original == other.original)
+    }
+}
+
+impl fmt::Display for TestScalar {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} {}", self.number, self.unit)
+    }
+}
+
+impl ScalarOperand for TestScalar {
+    type Custom = Self;
+
+    fn from_custom(custom: Self::Custom) -> Result<Self, String> {
+        Ok(custom)
+    }
+
+    fn into_custom(self) -> Self::Custom {
+        self
+    }
+
+    fn number(&self) -> Number {
+        self.number
+    }
+
+    fn is_compatible(&self, other: &Self) -> bool {
+        self.unit == other.unit
+    }
+
+    fn with_number(self, number: Number) -> Result<Self, String> {
+        Ok(Self::new(number, self.unit))
+    }
+}
+
+// Units with their factor to the canonical unit, like the TOSCA scalar types
+const UNITS: &[(&str, i64, &str)] = &[("B", 1, "B"), ("MB", 1_000_000, "B"), ("GB", 1_000_000_000, "B"), ("s", 1, "s")];
+
+// Strings such as "2 MB" are coerced into scalars in their canonical unit
+pub fn parse_scalar(text: &str) -> Result<TestScalar, String> {
+    let Some((number, unit)) = text.split_once(' ') else {
+        return Err(format!("malformed scalar: {}", text));
+    };
+
+    let Some((_, factor, canonical_unit)) = UNITS.iter().find(|(unit_, _, _)| *unit_ == unit) else {
+        return Err(format!("unsupported unit: {}", unit));
+    };
+
+    let number: Number = number.parse()?;
+    let mut scalar = TestScalar::new(number.multiply(Number::Integer(*factor))?, canonical_unit);
+    scalar.original = Some(text.into());
+    Ok(scalar)
+}
+
+// Arguments are already evaluated; the host has a scalar schema if it has units
+pub struct TestHost {
+    units: bool,
+}
+
+pub const HOST: TestHost = TestHost { units: true };
+pub const HOST_WITHOUT_UNITS: TestHost = TestHost { units: false };
+
+impl FunctionHost for TestHost {
+    type Argument = Value<TestScalar>;
+    type Custom = TestScalar;
+    type Scalar = TestScalar;
+
+    fn evaluate(&self, argument: Self::Argument) -> Result<Option<Value<Self::Custom>>, String> {
+        Ok(Some(argument))
+    }
+
+    fn coerce_if_custom(
+        &self,
+        value: Value<Self::Custom>,
+        other: &Value<Self::Custom>,
+    ) -> Result<Value<Self::Custom>, String> {
+        match (&value, other) {
+            (Value::Text(text), Value::Custom(_)) => Ok(Value::Custom(parse_scalar(text)?)),
+            _ => Ok(value),
+        }
+    }
+
+    fn coerce_scalar(&self, value: Value<Self::Custom>) -> Result<Option<Value<Self::Custom>>, String> {
+        match &value {
+            Value::Text(text) if self.units => Ok(Some(Value::Custom(parse_scalar(text)?))),
+            Value::Text(_) => Ok(None),
+            _ => Ok(Some(value)),
+        }
+    }
+
+    fn custom_kind(&self, _custom: &Self::Custom) -> String {
+        "scalar".into()
+    }
+
+    fn custom_comparator(&self, custom: &Self::Custom) -> Result<Value<Self::Custom>, String> {
+        Ok(custom.number.into())
+    }
+}
+
+pub fn scalar(number: i64, unit: &'static str) -> Value<TestScalar> {
+    Value::Custom(TestScalar::new(number.into(), unit))
+}
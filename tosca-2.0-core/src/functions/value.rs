@@ -0,0 +1,228 @@
+use super::number::*;
+
+use std::{cmp::*, fmt};
+
+//
+// Value
+//
+
+/// Evaluated function argument or result.
+///
+/// Both the compiler's normal values and the plugin's expressions are converted into this
+/// representation so that the built-in functions can have a single implementation. Custom values,
+/// e.g. scalars, timestamps, and versions, are opaque and handled by the [FunctionHost](super::FunctionHost).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<CustomT> {
+    /// Null.
+    Null,
+
+    /// Integer.
+    Integer(i64),
+
+    /// Unsigned integer.
+    UnsignedInteger(u64),
+
+    /// Float.
+    Float(f64),
+
+    /// Boolean.
+    Boolean(bool),
+
+    /// Text.
+    Text(String),
+
+    /// Bytes.
+    Bytes(Vec<u8>),
+
+    /// List.
+    List(Vec<Value<CustomT>>),
+
+    /// Map.
+    Map(Vec<(Value<CustomT>, Value<CustomT>)>),
+
+    /// Custom.
+    Custom(CustomT),
+}
+
+impl<CustomT> Value<CustomT> {
+    /// Type name.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Integer(_) => "integer",
+            Self::UnsignedInteger(_) => "unsigned integer",
+            Self::Float(_) => "float",
+            Self::Boolean(_) => "boolean",
+            Self::Text(_) => "string",
+            Self::Bytes(_) => "bytes",
+            Self::List(_) => "list",
+            Self::Map(_) => "map",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// As number.
+    ///
+    /// Returns [None] if not an integer, an unsigned integer, or a float.
+    pub fn to_number(&self) -> Option<Number> {
+        match self {
+            Self::Integer(integer) => Some(Number::Integer(*integer)),
+            Self::UnsignedInteger(unsigned_integer) => Some(Number::UnsignedInteger(*unsigned_integer)),
+            Self::Float(float) => Some(Number::Float(*float)),
+            _ => None,
+        }
+    }
+
+    /// True if the values are of the same type.
+    ///
+    /// Integers and unsigned integers are considered the same type. Custom values are not compared
+    /// here.
+    pub fn same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(_) | Self::UnsignedInteger(_), Self::Integer(_) | Self::UnsignedInteger(_)) => true,
+            _ => self.rank() == other.rank(),
+        }
+    }
+
+    /// Compare.
+    ///
+    /// Numbers are compared numerically, and lists and maps lexicographically. Values of different
+    /// types are ordered by type.
+    pub fn compare(&self, other: &Self) -> Option<Ordering>
+    where
+        CustomT: PartialEq,
+    {
+        match (self, other) {
+            (Self::Null, Self::Null) => Some(Ordering::Equal),
+            (Self::Boolean(left), Self::Boolean(right)) => Some(left.cmp(right)),
+            (Self::Text(left), Self::Text(right)) => Some(left.cmp(right)),
+            (Self::Bytes(left), Self::Bytes(right)) => Some(left.cmp(right)),
+
+            (Self::List(left), Self::List(right)) => {
+                for (left, right) in left.iter().zip(right) {
+                    match left.compare(right)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+                Some(left.len().cmp(&right.len()))
+            }
+
+            (Self::Map(left), Self::Map(right)) => {
+                for ((left_key, left_value), (right_key, right_value)) in left.iter().zip(right) {
+                    match left_key.compare(right_key)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                    match left_value.compare(right_value)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+                Some(left.len().cmp(&right.len()))
+            }
+
+            (Self::Custom(left), Self::Custom(right)) => (left == right).then_some(Ordering::Equal),
+
+            _ => match (self.to_number(), other.to_number()) {
+                (Some(left), Some(right)) => left.compare(right),
+                _ => Some(self.rank().cmp(&other.rank())),
+            },
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Null => 0,
+            Self::Boolean(_) => 1,
+            Self::Integer(_) | Self::UnsignedInteger(_) => 2,
+            Self::Float(_) => 3,
+            Self::Text(_) => 4,
+            Self::Bytes(_) => 5,
+            Self::List(_) => 6,
+            Self::Map(_) => 7,
+            Self::Custom(_) => 8,
+        }
+    }
+}
+
+impl<CustomT> From<Number> for Value<CustomT> {
+    fn from(number: Number) -> Self {
+        match number {
+            Number::Integer(integer) => Self::Integer(integer),
+            Number::UnsignedInteger(unsigned_integer) => Self::UnsignedInteger(unsigned_integer),
+            Number::Float(float) => Self::Float(float),
+        }
+    }
+}
+
+impl<CustomT> From<bool> for Value<CustomT> {
+    fn from(boolean: bool) -> Self {
+        Self::Boolean(boolean)
+    }
+}
+
+impl<CustomT> From<i64> for Value<CustomT> {
+    fn from(integer: i64) -> Self {
+        Self::Integer(integer)
+    }
+}
+
+impl<CustomT> From<String> for Value<CustomT> {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl<CustomT> From<&str> for Value<CustomT> {
+    fn from(text: &str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl<CustomT> From<Vec<Value<CustomT>>> for Value<CustomT> {
+    fn from(list: Vec<Value<CustomT>>) -> Self {
+        Self::List(list)
+    }
+}
+
+impl<CustomT> fmt::Display for Value<CustomT>
+where
+    CustomT: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => formatter.write_str("null"),
+            Self::Integer(integer) => fmt::Display::fmt(integer, formatter),
+            Self::UnsignedInteger(unsigned_integer) => fmt::Display::fmt(unsigned_integer, formatter),
+            Self::Float(float) => fmt::Display::fmt(float, formatter),
+            Self::Boolean(boolean) => fmt::Display::fmt(boolean, formatter),
+            Self::Text(text) => fmt::Debug::fmt(text, formatter),
+            Self::Bytes(bytes) => write!(formatter, "{} bytes", bytes.len()),
+
+            Self::List(list) => {
+                formatter.write_str("[")?;
+                for (index, item) in list.iter().enumerate() {
+                    if index != 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    fmt::Display::fmt(item, formatter)?;
+                }
+                formatter.write_str("]")
+            }
+
+            Self::Map(map) => {
+                formatter.write_str("{")?;
+                for (index, (key, value)) in map.iter().enumerate() {
+                    if index != 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    write!(formatter, "{}: {}", key, value)?;
+                }
+                formatter.write_str("}")
+            }
+
+            Self::Custom(custom) => fmt::Display::fmt(custom, formatter),
+        }
+    }
+}
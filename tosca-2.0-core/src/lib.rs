@@ -0,0 +1,17 @@
+// https://stackoverflow.com/a/61417700
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![warn(missing_docs)]
+
+/*!
+TOSCA 2.0 core shared by the compiler and the functions plugin.
+*/
+
+mod artifacts;
+mod entity;
+mod functions;
+mod location;
+mod tosca_path;
+mod trace;
+
+#[allow(unused_imports)]
+pub use {artifacts::*, entity::*, functions::*, location::*, tosca_path::*, trace::*};
@@ -0,0 +1,172 @@
+use std::{fmt, str::*};
+
+//
+// SourceLocation
+//
+
+/// Source location of a TOSCA value, e.g. `file.yaml:12:5`.
+///
+/// The compiler passes it to the functions plugin so that errors can be mapped back to the TOSCA
+/// source. Rows and columns are zero-based, as in annotation spans.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Source URL.
+    pub source: String,
+
+    /// Zero-based row and column.
+    pub position: Option<(usize, usize)>,
+}
+
+impl SourceLocation {
+    /// Constructor.
+    pub fn new(source: String, position: Option<(usize, usize)>) -> Self {
+        Self { source, position }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((row, column)) => write!(formatter, "{}:{}:{}", self.source, row, column),
+            None => fmt::Display::fmt(&self.source, formatter),
+        }
+    }
+}
+
+impl FromStr for SourceLocation {
+    type Err = String;
+
+    fn from_str(location: &str) -> Result<Self, Self::Err> {
+        if location.is_empty() {
+            return Err("empty source location".into());
+        }
+
+        // The source itself may contain ":" (e.g. "file:///"), so the position is parsed from the end
+        let mut split = location.rsplitn(3, ':');
+        if let (Some(column), Some(row), Some(source)) = (split.next(), split.next(), split.next())
+            && let (Ok(row), Ok(column)) = (row.parse(), column.parse())
+            && !source.is_empty()
+        {
+            return Ok(Self::new(source.into(), Some((row, column))));
+        }
+
+        Ok(Self::new(location.into(), None))
+    }
+}
+
+//
+// LocatedError
+//
+
+/// Error located at a TOSCA source location.
+///
+/// Functions that are given a [SourceLocation] report errors in this format so that the host can
+/// extract the location and the kind. It displays as `<location>: <kind>: <message>`, e.g.
+/// `file.yaml:12:5: coerce: properties.ports: not an integer`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocatedError {
+    /// Location.
+    pub location: SourceLocation,
+
+    /// Kind, in snake case.
+    pub kind: String,
+
+    /// Message.
+    pub message: String,
+}
+
+impl LocatedError {
+    /// Constructor.
+    pub fn new(location: SourceLocation, kind: String, message: String) -> Self {
+        Self { location, kind, message }
+    }
+
+    /// Find a located error in a text, returning the text before it too.
+    ///
+    /// The text may have a prefix, e.g. when the error has been wrapped by the host. The location
+    /// must have a position.
+    pub fn find(text: &str) -> Option<(&str, Self)> {
+        for (index, _) in text.match_indices(": ") {
+            // The location starts after the last whitespace of the prefix
+            let start = text[..index].rfind(char::is_whitespace).map(|whitespace| whitespace + 1).unwrap_or_default();
+
+            let Ok(location) = text[start..index].parse::<SourceLocation>() else {
+                continue;
+            };
+
+            if location.position.is_none() {
+                continue;
+            }
+
+            let Some((kind, message)) = text[index + 2..].split_once(": ") else {
+                continue;
+            };
+
+            if !is_kind(kind) {
+                continue;
+            }
+
+            return Some((&text[..start], Self::new(location, kind.into(), message.into())));
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for LocatedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: {}: {}", self.location, self.kind, self.message)
+    }
+}
+
+fn is_kind(kind: &str) -> bool {
+    !kind.is_empty() && kind.chars().all(|c| c.is_ascii_lowercase() || (c == '_'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_location_round_trip() {
+        for location in ["file.yaml:12:5", "file:///tmp/file.yaml:0:0", "file.yaml"] {
+            assert_eq!(location.parse::<SourceLocation>().unwrap().to_string(), location);
+        }
+
+        let location: SourceLocation = "file:///tmp/file.yaml:12:5".parse().unwrap();
+        assert_eq!(location, SourceLocation::new("file:///tmp/file.yaml".into(), Some((12, 5))));
+
+        let location: SourceLocation = "file:///tmp/file.yaml".parse().unwrap();
+        assert_eq!(location, SourceLocation::new("file:///tmp/file.yaml".into(), None));
+
+        assert!("".parse::<SourceLocation>().is_err());
+    }
+
+    #[test]
+    fn find_located_error() {
+        let error = LocatedError::new(
+            SourceLocation::new("file:///tmp/file.yaml".into(), Some((12, 5))),
+            "coerce".into(),
+            "properties.ports: not an integer: \"a\"".into(),
+        );
+
+        let text = error.to_string();
+        assert_eq!(text, "file:///tmp/file.yaml:12:5: coerce: properties.ports: not an integer: \"a\"");
+        assert_eq!(LocatedError::find(&text), Some(("", error.clone())));
+
+        // Wrapped by the host
+        let text = format!("plugin error: {}", error);
+        assert_eq!(LocatedError::find(&text), Some(("plugin error: ", error)));
+    }
+
+    #[test]
+    fn find_no_located_error() {
+        assert_eq!(LocatedError::find("plugin error: not an integer"), None);
+
+        // No position
+        assert_eq!(LocatedError::find("file.yaml: coerce: not an integer"), None);
+
+        // No kind
+        assert_eq!(LocatedError::find("file.yaml:12:5: not an integer"), None);
+    }
+}
@@ -0,0 +1,116 @@
+use super::{super::functions::*, selector::*};
+
+use std::fmt;
+
+//
+// ToscaPathGraph
+//
+
+/// Graph for following TOSCA Paths.
+///
+/// The `tosca-2.0-functions` plugin implements it over Floria entities.
+pub trait ToscaPathGraph {
+    /// Site, e.g. a node, a relationship, or a capability.
+    type Site;
+
+    /// Path argument and property value.
+    type Value: Clone + fmt::Display + From<Vec<Self::Value>>;
+
+    /// Kind of site.
+    fn site_kind(&self, site: &Self::Site) -> Result<ToscaPathSiteKind, String>;
+
+    /// Nodes created from a node template.
+    fn find_nodes(
+        &self,
+        site: &Self::Site,
+        node_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Self::Site>, String>;
+
+    /// Relationships created from a relationship template.
+    fn find_relationships(
+        &self,
+        site: &Self::Site,
+        relationship_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Self::Site>, String>;
+
+    /// Outgoing relationships of a node for a requirement.
+    fn outgoing_relationships(
+        &self,
+        site: &Self::Site,
+        requirement_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Self::Site>, String>;
+
+    /// Capability of a node.
+    fn capability(&self, site: &Self::Site, capability_name: &str) -> Result<Option<Self::Site>, String>;
+
+    /// Incoming relationships of a capability.
+    fn incoming_relationships(
+        &self,
+        site: &Self::Site,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Self::Site>, String>;
+
+    /// Source node of a relationship.
+    fn source_node(&self, site: &Self::Site) -> Result<Self::Site, String>;
+
+    /// Target node of a relationship.
+    fn target_node(&self, site: &Self::Site) -> Result<Self::Site, String>;
+
+    /// Target capability of a relationship.
+    fn target_capability(&self, site: &Self::Site) -> Result<Self::Site, String>;
+
+    /// Property or attribute.
+    ///
+    /// Returns [None] if not found.
+    fn property(
+        &self,
+        site: &Self::Site,
+        property_name: &str,
+    ) -> Result<Option<ToscaPathProperty<Self::Value>>, String>;
+
+    /// Nested value for a key or an index.
+    fn get(&self, value: &Self::Value, key: &Self::Value) -> Option<Self::Value>;
+
+    /// As text.
+    fn as_text<'value>(&self, value: &'value Self::Value) -> Option<&'value str>;
+
+    /// As number.
+    fn as_number(&self, value: &Self::Value) -> Option<Number>;
+
+    /// Type name.
+    fn type_name(&self, value: &Self::Value) -> String;
+}
+
+//
+// ToscaPathSiteKind
+//
+
+/// Kind of TOSCA Path site.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ToscaPathSiteKind {
+    /// Node.
+    Node,
+
+    /// Relationship.
+    Relationship,
+
+    /// Other.
+    Other(String),
+}
+
+//
+// ToscaPathProperty
+//
+
+/// TOSCA Path property or attribute.
+#[derive(Clone, Debug)]
+pub struct ToscaPathProperty<ValueT> {
+    /// Value.
+    pub value: Option<ValueT>,
+
+    /// Whether it is read only, i.e. a property rather than an attribute.
+    pub read_only: bool,
+}
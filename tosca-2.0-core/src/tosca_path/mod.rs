@@ -0,0 +1,9 @@
+mod graph;
+mod parser;
+mod selector;
+
+#[cfg(test)]
+mod test;
+
+#[allow(unused_imports)]
+pub use {graph::*, parser::*, selector::*};
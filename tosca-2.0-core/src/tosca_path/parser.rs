@@ -0,0 +1,565 @@
+use super::{super::functions::*, graph::*, selector::*};
+
+use std::{iter::*, slice::*};
+
+//
+// ToscaPathParser
+//
+
+/// TOSCA Path parser.
+///
+/// Because `ALL` selectors can fan out, the path is followed over lists of sites. When any `ALL`
+/// selector was used the final result should be a list.
+pub struct ToscaPathParser<'own, GraphT>
+where
+    GraphT: ToscaPathGraph,
+{
+    graph: &'own GraphT,
+    iterator: Peekable<Iter<'own, GraphT::Value>>,
+    consumed: Vec<&'own GraphT::Value>,
+    all: bool,
+}
+
+impl<'own, GraphT> ToscaPathParser<'own, GraphT>
+where
+    GraphT: ToscaPathGraph,
+{
+    /// Constructor.
+    pub fn new(graph: &'own GraphT, arguments: &'own [GraphT::Value]) -> Self {
+        Self { graph, iterator: arguments.iter().peekable(), consumed: Default::default(), all: false }
+    }
+
+    /// Whether an `ALL` selector was used.
+    pub fn is_all(&self) -> bool {
+        self.all
+    }
+
+    /// The part of the path that has been parsed so far.
+    pub fn path(&self) -> String {
+        self.consumed.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Next site.
+    ///
+    /// The path must select exactly one site.
+    pub fn next_site(&mut self, path_site: GraphT::Site) -> Result<GraphT::Site, String> {
+        let mut sites = self.next_sites(path_site)?;
+        if self.all || (sites.len() != 1) {
+            return Err(self.error("does not select a single entity"));
+        }
+
+        Ok(sites.remove(0))
+    }
+
+    /// Next sites.
+    pub fn next_sites(&mut self, path_site: GraphT::Site) -> Result<Vec<GraphT::Site>, String> {
+        let argument = self.next().ok_or("invalid TOSCA path: empty")?;
+        match self.graph.as_text(argument) {
+            Some(text) => match text {
+                // SELF, <node_context> |
+                // SELF, <rel_context>
+                "SELF" => match self.graph.site_kind(&path_site)? {
+                    ToscaPathSiteKind::Node => self.next_sites_after_nodes(vec![path_site]),
+
+                    ToscaPathSiteKind::Relationship => self.next_sites_after_relationships(vec![path_site]),
+
+                    ToscaPathSiteKind::Other(kind) => {
+                        Err(self.error(&format!("entity not a node or a relationship: {}", kind)))
+                    }
+                },
+
+                // <node_symbolic_name>, <idx>, <node_context> |
+                // <relationship_symbolic_name>, <idx>, <rel_context>
+                name => {
+                    let selector = self.next_selector()?;
+
+                    let nodes = self.graph.find_nodes(&path_site, name, selector.clone())?;
+                    if !nodes.is_empty() {
+                        return self.next_sites_after_nodes(nodes);
+                    }
+
+                    let relationships = self.graph.find_relationships(&path_site, name, selector)?;
+                    if !relationships.is_empty() {
+                        return self.next_sites_after_relationships(relationships);
+                    }
+
+                    Err(self.error("node or relationship not found"))
+                }
+            },
+
+            None => Err(format!("invalid TOSCA path: argument not a string: {}", self.graph.type_name(argument))),
+        }
+    }
+
+    /// Next sites after nodes.
+    pub fn next_sites_after_nodes(&mut self, path_sites: Vec<GraphT::Site>) -> Result<Vec<GraphT::Site>, String> {
+        match self.peek_keyword() {
+            // RELATIONSHIP, <requirement_name>, <idx>, <rel_context>
+            Some("RELATIONSHIP") => {
+                self.next();
+                let requirement_name = self.next_name("requirement")?;
+                let selector = self.next_selector()?;
+
+                let mut relationships = Vec::default();
+                for path_site in path_sites {
+                    relationships.extend(self.graph.outgoing_relationships(
+                        &path_site,
+                        requirement_name,
+                        selector.clone(),
+                    )?);
+                }
+
+                if relationships.is_empty() {
+                    return Err(self.error("requirement not found"));
+                }
+
+                self.next_sites_after_relationships(relationships)
+            }
+
+            // CAPABILITY, <capability_name>, RELATIONSHIP, <idx>, <rel_context> |
+            // CAPABILITY, <capability_name>
+            Some("CAPABILITY") => {
+                self.next();
+                let capability_name = self.next_name("capability")?;
+
+                let mut capabilities = Vec::with_capacity(path_sites.len());
+                for path_site in path_sites {
+                    let capability = self
+                        .graph
+                        .capability(&path_site, capability_name)?
+                        .ok_or_else(|| self.error("capability not found"))?;
+                    capabilities.push(capability);
+                }
+
+                self.next_sites_after_capabilities(capabilities)
+            }
+
+            _ => Ok(path_sites),
+        }
+    }
+
+    /// Next sites after relationships.
+    pub fn next_sites_after_relationships(
+        &mut self,
+        path_sites: Vec<GraphT::Site>,
+    ) -> Result<Vec<GraphT::Site>, String> {
+        match self.peek_keyword() {
+            // SOURCE, <node_context>
+            Some("SOURCE") => {
+                self.next();
+                let nodes = self.map_sites(path_sites, |path_site| self.graph.source_node(path_site))?;
+                self.next_sites_after_nodes(nodes)
+            }
+
+            // TARGET, <node_context>
+            Some("TARGET") => {
+                self.next();
+                let nodes = self.map_sites(path_sites, |path_site| self.graph.target_node(path_site))?;
+                self.next_sites_after_nodes(nodes)
+            }
+
+            // CAPABILITY, RELATIONSHIP <idx>, <rel_context> | CAPABILITY
+            Some("CAPABILITY") => {
+                self.next();
+                let capabilities = self.map_sites(path_sites, |path_site| self.graph.target_capability(path_site))?;
+                self.next_sites_after_capabilities(capabilities)
+            }
+
+            _ => Ok(path_sites),
+        }
+    }
+
+    /// Next sites after capabilities.
+    pub fn next_sites_after_capabilities(
+        &mut self,
+        path_sites: Vec<GraphT::Site>,
+    ) -> Result<Vec<GraphT::Site>, String> {
+        match self.peek_keyword() {
+            // RELATIONSHIP <idx>, <rel_context>
+            Some("RELATIONSHIP") => {
+                self.next();
+                let selector = self.next_selector()?;
+
+                let mut relationships = Vec::default();
+                for path_site in path_sites {
+                    relationships.extend(self.graph.incoming_relationships(&path_site, selector.clone())?);
+                }
+
+                if relationships.is_empty() {
+                    return Err(self.error("incoming relationship not found"));
+                }
+
+                self.next_sites_after_relationships(relationships)
+            }
+
+            _ => Ok(path_sites),
+        }
+    }
+
+    /// Next selector for node or relationship.
+    pub fn next_selector(&mut self) -> Result<ToscaInstanceSelector, String> {
+        let Some(argument) = self.iterator.peek().copied() else {
+            return Ok(Default::default());
+        };
+
+        let selector = match (self.graph.as_text(argument), self.graph.as_number(argument)) {
+            // ALL
+            (Some("ALL"), _) => ToscaInstanceSelector::All,
+
+            // <integer_index>
+            (_, Some(Number::Integer(integer))) => {
+                if integer < 0 {
+                    self.next();
+                    return Err(self.error("negative index"));
+                }
+
+                ToscaInstanceSelector::Index(integer as usize)
+            }
+
+            // <integer_index>
+            (_, Some(Number::UnsignedInteger(unsigned_integer))) => {
+                ToscaInstanceSelector::Index(unsigned_integer as usize)
+            }
+
+            _ => return Ok(Default::default()),
+        };
+
+        self.next();
+
+        if selector.is_all() {
+            self.all = true;
+        }
+
+        Ok(selector)
+    }
+
+    /// Next argument.
+    pub fn next_argument(&mut self) -> Option<&'own GraphT::Value> {
+        self.next()
+    }
+
+    /// Next property value for all sites.
+    ///
+    /// Consumes the rest of the path. If an `ALL` selector was used, the result is a list with the
+    /// value for each site.
+    pub fn next_property_values(
+        &mut self,
+        path_sites: &[GraphT::Site],
+        read_only: bool,
+    ) -> Result<GraphT::Value, String> {
+        let property_name = self.next_name(property_or_attribute(read_only))?;
+
+        let mut keys = Vec::default();
+        while let Some(argument) = self.next() {
+            keys.push(argument);
+        }
+
+        let mut values = Vec::with_capacity(path_sites.len());
+        for path_site in path_sites {
+            values.push(self.get_value(path_site, property_name, read_only, &keys)?);
+        }
+
+        if self.all {
+            Ok(values.into())
+        } else if values.len() == 1 {
+            Ok(values.remove(0))
+        } else {
+            Err(self.error("does not select a single entity"))
+        }
+    }
+
+    fn get_value(
+        &self,
+        path_site: &GraphT::Site,
+        property_name: &str,
+        read_only: bool,
+        keys: &[&GraphT::Value],
+    ) -> Result<GraphT::Value, String> {
+        let property = self
+            .graph
+            .property(path_site, property_name)?
+            .ok_or_else(|| self.error(&format!("{} not found", property_or_attribute(read_only))))?;
+
+        if property.read_only != read_only {
+            return Err(self.error(&format!(
+                "{} found but {}read only",
+                property_or_attribute(read_only),
+                if read_only { "" } else { "not " },
+            )));
+        }
+
+        let Some(mut current_value) = property.value else {
+            return Err(self.error(&format!("no value for property: {}", property_name)));
+        };
+
+        for key in keys {
+            current_value = self
+                .graph
+                .get(&current_value, key)
+                .ok_or_else(|| self.error(&format!("{} not found in value", key)))?;
+        }
+
+        Ok(current_value)
+    }
+
+    fn map_sites<FunctionT>(
+        &self,
+        path_sites: Vec<GraphT::Site>,
+        function: FunctionT,
+    ) -> Result<Vec<GraphT::Site>, String>
+    where
+        FunctionT: Fn(&GraphT::Site) -> Result<GraphT::Site, String>,
+    {
+        path_sites
+            .iter()
+            .map(|path_site| function(path_site).map_err(|error| format!("{} (at {})", error, self.path())))
+            .collect()
+    }
+
+    fn next_name(&mut self, kind: &str) -> Result<&'own str, String> {
+        match self.next() {
+            Some(argument) => match self.graph.as_text(argument) {
+                Some(name) => Ok(name),
+                None => Err(format!(
+                    "invalid TOSCA path: {} name not a string: {} (at {})",
+                    kind,
+                    self.graph.type_name(argument),
+                    self.path()
+                )),
+            },
+
+            None => Err(format!("invalid TOSCA path: missing {} name (at {})", kind, self.path())),
+        }
+    }
+
+    fn peek_keyword(&mut self) -> Option<&'own str> {
+        let argument = self.iterator.peek().copied()?;
+        self.graph.as_text(argument)
+    }
+
+    fn next(&mut self) -> Option<&'own GraphT::Value> {
+        let argument = self.iterator.next()?;
+        self.consumed.push(argument);
+        Some(argument)
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("TOSCA path: {} (at {})", message, self.path())
+    }
+}
+
+fn property_or_attribute(read_only: bool) -> &'static str {
+    match read_only {
+        true => "property",
+        false => "attribute",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::test::*, *};
+
+    fn property_value(site: usize, arguments: &[&str]) -> Result<TestValue, String> {
+        let graph = graph();
+        let arguments = path(arguments);
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+        let sites = parser.next_sites(site)?;
+        parser.next_property_values(&sites, true)
+    }
+
+    fn site(site: usize, arguments: &[&str]) -> Result<usize, String> {
+        let graph = graph();
+        let arguments = path(arguments);
+        ToscaPathParser::new(&graph, &arguments).next_site(site)
+    }
+
+    #[test]
+    fn next_site() {
+        assert_eq!(site(APP, &["SELF"]), Ok(APP));
+        assert_eq!(site(APP, &["db"]), Ok(0));
+        assert_eq!(site(APP, &["db", "1"]), Ok(1));
+        assert_eq!(
+            site(APP, &["db", "ALL"]),
+            Err("TOSCA path: does not select a single entity (at \"db\", \"ALL\")".into())
+        );
+        assert_eq!(site(APP, &["db", "2"]), Err("TOSCA path: node or relationship not found (at \"db\", 2)".into()));
+        assert_eq!(site(APP, &[]), Err("invalid TOSCA path: empty".into()));
+    }
+
+    #[test]
+    fn next_site_self_not_node_or_relationship() {
+        assert_eq!(
+            site(ENDPOINT, &["SELF"]),
+            Err("TOSCA path: entity not a node or a relationship: capability (at \"SELF\")".into())
+        );
+    }
+
+    #[test]
+    fn next_property_values() {
+        assert_eq!(property_value(APP, &["SELF", "name"]), Ok("app".into()));
+        assert_eq!(property_value(APP, &["db", "name"]), Ok("db0".into()));
+        assert_eq!(property_value(APP, &["db", "1", "name"]), Ok("db1".into()));
+
+        // Keys into the value
+        assert_eq!(property_value(APP, &["db", "ports", "1"]), Ok(5433.into()));
+    }
+
+    #[test]
+    fn relationship_chain() {
+        // RELATIONSHIP, <requirement_name>, <idx>, TARGET
+        assert_eq!(property_value(APP, &["SELF", "RELATIONSHIP", "database", "TARGET", "name"]), Ok("db0".into()));
+        assert_eq!(property_value(APP, &["SELF", "RELATIONSHIP", "database", "1", "TARGET", "name"]), Ok("db1".into()));
+
+        // Relationship property
+        assert_eq!(property_value(APP, &["SELF", "RELATIONSHIP", "database", "1", "weight"]), Ok(2.into()));
+
+        // <relationship_symbolic_name>
+        assert_eq!(property_value(APP, &["replica", "TARGET", "name"]), Ok("db1".into()));
+
+        // SOURCE
+        assert_eq!(property_value(PRIMARY, &["SELF", "SOURCE", "name"]), Ok("app".into()));
+        assert_eq!(
+            property_value(PRIMARY, &["SELF", "SOURCE", "RELATIONSHIP", "database", "1", "TARGET", "name"]),
+            Ok("db1".into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["SELF", "RELATIONSHIP", "storage", "TARGET", "name"]),
+            Err("TOSCA path: requirement not found (at \"SELF\", \"RELATIONSHIP\", \"storage\")".into())
+        );
+    }
+
+    #[test]
+    fn capability_chain() {
+        // CAPABILITY, <capability_name>
+        assert_eq!(property_value(APP, &["db", "1", "CAPABILITY", "endpoint", "port"]), Ok(6432.into()));
+
+        // Relationship target CAPABILITY
+        assert_eq!(property_value(APP, &["SELF", "RELATIONSHIP", "database", "CAPABILITY", "port"]), Ok(5432.into()));
+
+        // CAPABILITY, <capability_name>, RELATIONSHIP, <idx>
+        assert_eq!(property_value(APP, &["db", "1", "CAPABILITY", "endpoint", "RELATIONSHIP", "weight"]), Ok(2.into()));
+        assert_eq!(
+            property_value(APP, &["db", "CAPABILITY", "endpoint", "RELATIONSHIP", "0", "SOURCE", "name"]),
+            Ok("app".into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["db", "CAPABILITY", "storage", "port"]),
+            Err("TOSCA path: capability not found (at \"db\", \"CAPABILITY\", \"storage\")".into())
+        );
+        assert_eq!(
+            property_value(APP, &["db", "CAPABILITY", "endpoint", "RELATIONSHIP", "1", "weight"]),
+            Err("TOSCA path: incoming relationship not found (at \"db\", \"CAPABILITY\", \"endpoint\", \"RELATIONSHIP\", 1)"
+                .into())
+        );
+    }
+
+    #[test]
+    fn all_fan_out() {
+        assert_eq!(property_value(APP, &["db", "ALL", "name"]), Ok(vec!["db0".into(), "db1".into()].into()));
+
+        assert_eq!(
+            property_value(APP, &["SELF", "RELATIONSHIP", "database", "ALL", "TARGET", "ports", "0"]),
+            Ok(vec![5432.into(), 6432.into()].into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["db", "ALL", "CAPABILITY", "endpoint", "RELATIONSHIP", "SOURCE", "name"]),
+            Ok(vec!["app".into(), "app".into()].into())
+        );
+
+        // A single result is still a list
+        assert_eq!(property_value(APP, &["app", "ALL", "name"]), Ok(vec!["app".into()].into()));
+
+        // Missing in one of the sites
+        assert_eq!(
+            property_value(APP, &["db", "ALL", "ports", "1"]),
+            Err("TOSCA path: 1 not found in value (at \"db\", \"ALL\", \"ports\", 1)".into())
+        );
+    }
+
+    #[test]
+    fn property_errors() {
+        assert_eq!(
+            property_value(APP, &["SELF", "missing"]),
+            Err("TOSCA path: property not found (at \"SELF\", \"missing\")".into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["db", "status"]),
+            Err("TOSCA path: property found but read only (at \"db\", \"status\")".into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["SELF", "unset"]),
+            Err("TOSCA path: no value for property: unset (at \"SELF\", \"unset\")".into())
+        );
+
+        assert_eq!(
+            property_value(APP, &["SELF"]),
+            Err("invalid TOSCA path: missing property name (at \"SELF\")".into())
+        );
+    }
+
+    #[test]
+    fn attribute_values() {
+        let graph = graph();
+        let arguments = path(&["db", "status"]);
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+        let sites = parser.next_sites(APP).expect("sites");
+        assert_eq!(parser.next_property_values(&sites, false), Ok("up".into()));
+
+        let arguments = path(&["db", "name"]);
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+        let sites = parser.next_sites(APP).expect("sites");
+        assert_eq!(
+            parser.next_property_values(&sites, false),
+            Err("TOSCA path: attribute found but not read only (at \"db\", \"name\")".into())
+        );
+    }
+
+    #[test]
+    fn selector_all() {
+        let arguments = path(&["ALL", "port"]);
+        let graph = graph();
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+
+        assert!(matches!(parser.next_selector(), Ok(ToscaInstanceSelector::All)));
+        assert!(parser.is_all());
+        assert_eq!(parser.next_argument(), Some(&"port".into()));
+    }
+
+    #[test]
+    fn selector_index() {
+        let arguments = vec![Value::UnsignedInteger(2), Value::Integer(1)];
+        let graph = graph();
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+
+        assert!(matches!(parser.next_selector(), Ok(ToscaInstanceSelector::Index(2))));
+        assert!(matches!(parser.next_selector(), Ok(ToscaInstanceSelector::Index(1))));
+        assert!(!parser.is_all());
+        assert!(parser.next_argument().is_none());
+    }
+
+    #[test]
+    fn selector_default() {
+        // A keyword or a name is not a selector and is not consumed
+        let arguments = path(&["CAPABILITY"]);
+        let graph = graph();
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+
+        assert!(matches!(parser.next_selector(), Ok(ToscaInstanceSelector::Index(0))));
+        assert_eq!(parser.next_argument(), Some(&"CAPABILITY".into()));
+        assert!(matches!(parser.next_selector(), Ok(ToscaInstanceSelector::Index(0))));
+    }
+
+    #[test]
+    fn selector_negative_index() {
+        let arguments = path(&["-1"]);
+        let graph = graph();
+        let mut parser = ToscaPathParser::new(&graph, &arguments);
+
+        assert!(matches!(parser.next_selector(), Err(reason) if reason.starts_with("TOSCA path: negative index")));
+    }
+}
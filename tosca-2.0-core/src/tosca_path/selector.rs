@@ -0,0 +1,72 @@
+use std::fmt;
+
+//
+// ToscaInstanceSelector
+//
+
+/// TOSCA instance selector.
+#[derive(Clone, Debug)]
+pub enum ToscaInstanceSelector {
+    /// Index.
+    Index(usize),
+
+    /// All.
+    All,
+}
+
+impl ToscaInstanceSelector {
+    /// Select from instances.
+    ///
+    /// An index selects at most one instance. All selects all instances.
+    pub fn select<ItemT>(&self, mut items: Vec<ItemT>) -> Vec<ItemT> {
+        match self {
+            Self::Index(index) => {
+                if *index < items.len() {
+                    vec![items.swap_remove(*index)]
+                } else {
+                    Default::default()
+                }
+            }
+
+            Self::All => items,
+        }
+    }
+
+    /// Whether this selects all instances.
+    pub fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+}
+
+impl Default for ToscaInstanceSelector {
+    fn default() -> Self {
+        Self::Index(0)
+    }
+}
+
+impl fmt::Display for ToscaInstanceSelector {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index(index) => fmt::Display::fmt(index, formatter),
+            Self::All => fmt::Display::fmt("ALL", formatter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_index() {
+        assert_eq!(ToscaInstanceSelector::Index(1).select(vec!["a", "b", "c"]), vec!["b"]);
+        assert_eq!(ToscaInstanceSelector::default().select(vec!["a", "b", "c"]), vec!["a"]);
+        assert!(ToscaInstanceSelector::Index(3).select(vec!["a", "b", "c"]).is_empty());
+    }
+
+    #[test]
+    fn select_all() {
+        assert_eq!(ToscaInstanceSelector::All.select(vec!["a", "b", "c"]), vec!["a", "b", "c"]);
+        assert!(ToscaInstanceSelector::All.select(Vec::<&str>::default()).is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+use super::{super::functions::*, graph::*, selector::*};
+
+pub type TestValue = Value<String>;
+
+//
+// TestEntity
+//
+
+#[derive(Default)]
+pub struct TestEntity {
+    pub kind: &'static str,
+    pub name: &'static str,
+    pub requirement: &'static str,
+    pub source: usize,
+    pub target: usize,
+    pub container: usize,
+    pub properties: Vec<(&'static str, TestValue, bool)>,
+}
+
+impl TestEntity {
+    pub fn node(name: &'static str, properties: Vec<(&'static str, TestValue, bool)>) -> Self {
+        Self { kind: "node", name, properties, ..Default::default() }
+    }
+
+    pub fn capability(name: &'static str, container: usize, properties: Vec<(&'static str, TestValue, bool)>) -> Self {
+        Self { kind: "capability", name, container, properties, ..Default::default() }
+    }
+
+    pub fn relationship(
+        name: &'static str,
+        requirement: &'static str,
+        source: usize,
+        target: usize,
+        properties: Vec<(&'static str, TestValue, bool)>,
+    ) -> Self {
+        Self { kind: "relationship", name, requirement, source, target, properties, ..Default::default() }
+    }
+}
+
+//
+// TestGraph
+//
+
+/// Sites are indexes of entities.
+pub struct TestGraph(pub Vec<TestEntity>);
+
+impl TestGraph {
+    fn sites<FilterT>(&self, filter: FilterT) -> Vec<usize>
+    where
+        FilterT: Fn(&TestEntity) -> bool,
+    {
+        self.0.iter().enumerate().filter(|(_, entity)| filter(entity)).map(|(site, _)| site).collect()
+    }
+}
+
+impl ToscaPathGraph for TestGraph {
+    type Site = usize;
+    type Value = TestValue;
+
+    fn site_kind(&self, site: &usize) -> Result<ToscaPathSiteKind, String> {
+        Ok(match self.0[*site].kind {
+            "node" => ToscaPathSiteKind::Node,
+            "relationship" => ToscaPathSiteKind::Relationship,
+            kind => ToscaPathSiteKind::Other(kind.into()),
+        })
+    }
+
+    fn find_nodes(
+        &self,
+        _site: &usize,
+        node_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<usize>, String> {
+        Ok(selector.select(self.sites(|entity| (entity.kind == "node") && (entity.name == node_template_name))))
+    }
+
+    fn find_relationships(
+        &self,
+        _site: &usize,
+        relationship_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<usize>, String> {
+        Ok(selector.select(
+            self.sites(|entity| (entity.kind == "relationship") && (entity.name == relationship_template_name)),
+        ))
+    }
+
+    fn outgoing_relationships(
+        &self,
+        site: &usize,
+        requirement_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<usize>, String> {
+        Ok(selector.select(self.sites(|entity| {
+            (entity.kind == "relationship") && (entity.source == *site) && (entity.requirement == requirement_name)
+        })))
+    }
+
+    fn capability(&self, site: &usize, capability_name: &str) -> Result<Option<usize>, String> {
+        Ok(self
+            .sites(|entity| {
+                (entity.kind == "capability") && (entity.container == *site) && (entity.name == capability_name)
+            })
+            .first()
+            .copied())
+    }
+
+    fn incoming_relationships(&self, site: &usize, selector: ToscaInstanceSelector) -> Result<Vec<usize>, String> {
+        Ok(selector.select(self.sites(|entity| (entity.kind == "relationship") && (entity.target == *site))))
+    }
+
+    fn source_node(&self, site: &usize) -> Result<usize, String> {
+        Ok(self.0[*site].source)
+    }
+
+    fn target_node(&self, site: &usize) -> Result<usize, String> {
+        Ok(self.0[self.target_capability(site)?].container)
+    }
+
+    fn target_capability(&self, site: &usize) -> Result<usize, String> {
+        match self.0[*site].kind {
+            "relationship" => Ok(self.0[*site].target),
+            kind => Err(format!("not a relationship: {}", kind)),
+        }
+    }
+
+    fn property(&self, site: &usize, property_name: &str) -> Result<Option<ToscaPathProperty<TestValue>>, String> {
+        Ok(self.0[*site].properties.iter().find(|(name, _, _)| *name == property_name).map(|(_, value, read_only)| {
+            ToscaPathProperty {
+                value: if matches!(value, Value::Null) { None } else { Some(value.clone()) },
+                read_only: *read_only,
+            }
+        }))
+    }
+
+    fn get(&self, value: &TestValue, key: &TestValue) -> Option<TestValue> {
+        match (value, key) {
+            (Value::List(list), Value::Integer(index)) => list.get(usize::try_from(*index).ok()?).cloned(),
+            (Value::Map(map), key) => map.iter().find(|(map_key, _)| map_key == key).map(|(_, value)| value.clone()),
+            _ => None,
+        }
+    }
+
+    fn as_text<'value>(&self, value: &'value TestValue) -> Option<&'value str> {
+        match value {
+            Value::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn as_number(&self, value: &TestValue) -> Option<Number> {
+        value.to_number()
+    }
+
+    fn type_name(&self, value: &TestValue) -> String {
+        value.type_name().into()
+    }
+}
+
+/// Graph:
+///
+/// * 0: "db" node with capability 2
+/// * 1: "db" node with capability 3
+/// * 2: "endpoint" capability of 0
+/// * 3: "endpoint" capability of 1
+/// * 4: "app" node with "database" requirements to 2 and 3
+/// * 5: "primary" relationship from 4 to 2
+/// * 6: "replica" relationship from 4 to 3
+pub fn graph() -> TestGraph {
+    TestGraph(vec![
+        TestEntity::node(
+            "db",
+            vec![
+                ("name", "db0".into(), true),
+                ("ports", vec![5432.into(), 5433.into()].into(), true),
+                ("status", "up".into(), false),
+            ],
+        ),
+        TestEntity::node("db", vec![("name", "db1".into(), true), ("ports", vec![6432.into()].into(), true)]),
+        TestEntity::capability("endpoint", 0, vec![("port", 5432.into(), true)]),
+        TestEntity::capability("endpoint", 1, vec![("port", 6432.into(), true)]),
+        TestEntity::node("app", vec![("name", "app".into(), true), ("unset", Value::Null, true)]),
+        TestEntity::relationship("primary", "database", 4, 2, vec![("weight", 1.into(), true)]),
+        TestEntity::relationship("replica", "database", 4, 3, vec![("weight", 2.into(), true)]),
+    ])
+}
+
+pub const APP: usize = 4;
+pub const ENDPOINT: usize = 2;
+pub const PRIMARY: usize = 5;
+
+/// Path arguments.
+pub fn path(arguments: &[&str]) -> Vec<TestValue> {
+    arguments
+        .iter()
+        .map(|argument| match argument.parse::<i64>() {
+            Ok(integer) => integer.into(),
+            Err(_) => (*argument).into(),
+        })
+        .collect()
+}
@@ -0,0 +1,7 @@
+/// Service metadata key for the function trace file.
+///
+/// When set, the functions plugin appends every dispatched call to the file as a line of
+/// tab-separated fields: index, depth, function name, call site entity, call site property path,
+/// "ok" or "error", the result or the error, and then the arguments. Tabs, newlines, and
+/// backslashes in fields are escaped.
+pub const TRACE_FUNCTIONS_METADATA_KEY: &str = "puccini:trace_functions";
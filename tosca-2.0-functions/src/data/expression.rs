@@ -2,6 +2,7 @@ use super::{super::dispatcher::*, comparator::*, scalar::*, timestamp::*, versio
 
 use {
     floria_plugin_sdk::{data::*, dispatch_bindings::*},
+    puccini_tosca_2_0_core::*,
     std::collections::*,
 };
 
@@ -33,6 +34,20 @@ pub trait ExpressionUtilities: Sized {
     /// Comparator.
     fn comparator(self) -> Result<Expression, String>;
 
+    /// Deep equality.
+    ///
+    /// Lists and maps are compared recursively. Custom values are compared via their
+    /// [comparator](ExpressionUtilities::comparator).
+    fn deep_equal(&self, other: &Expression) -> Result<bool, String>;
+
+    /// As number.
+    fn to_number(&self) -> Result<Number, String>;
+
+    /// Into a value for the core functions.
+    ///
+    /// Custom expressions are kept as they are. Must be evaluated.
+    fn into_value(self) -> Result<Value<Expression>, String>;
+
     /// Coerce into a data kind.
     fn must_coerce(self, data_kind: &str) -> Result<Expression, String> {
         self.coerce(data_kind).map(|(expression, _modified)| expression)
@@ -237,4 +252,121 @@ impl ExpressionUtilities for Expression {
             _ => Err(format!("cannot compare {}", self.type_name())),
         }
     }
+
+    fn deep_equal(&self, other: &Expression) -> Result<bool, String> {
+        match (self, other) {
+            (Expression::List(list_resource), Expression::List(other_list_resource)) => {
+                let list = &list_resource.list().inner;
+                let other_list = &other_list_resource.list().inner;
+
+                if list.len() != other_list.len() {
+                    return Ok(false);
+                }
+
+                for (item, other_item) in list.iter().zip(other_list) {
+                    if !item.deep_equal(other_item)? {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(true)
+            }
+
+            (Expression::Map(map_resource), Expression::Map(other_map_resource)) => {
+                let map = &map_resource.map().inner;
+                let other_map = &other_map_resource.map().inner;
+
+                if map.len() != other_map.len() {
+                    return Ok(false);
+                }
+
+                for (key, value) in map {
+                    match other_map.get(key) {
+                        Some(other_value) => {
+                            if !value.deep_equal(other_value)? {
+                                return Ok(false);
+                            }
+                        }
+
+                        None => return Ok(false),
+                    }
+                }
+
+                Ok(true)
+            }
+
+            (Expression::Custom(_), _) | (_, Expression::Custom(_)) => {
+                // Values that cannot be coerced are simply not equal
+                let Ok(left) = self.clone().coerce_if_custom(other) else {
+                    return Ok(false);
+                };
+                let Ok(right) = other.clone().coerce_if_custom(&left) else {
+                    return Ok(false);
+                };
+
+                let (Expression::Custom(left_custom), Expression::Custom(right_custom)) = (&left, &right) else {
+                    return Ok(false);
+                };
+
+                if left_custom.custom().kind != right_custom.custom().kind {
+                    return Ok(false);
+                }
+
+                match (left.clone().comparator(), right.clone().comparator()) {
+                    (Ok(left), Ok(right)) => Ok(left == right),
+
+                    // Unsupported custom kinds
+                    _ => Ok(left == right),
+                }
+            }
+
+            (Expression::Integer(integer), Expression::UnsignedInteger(unsigned_integer))
+            | (Expression::UnsignedInteger(unsigned_integer), Expression::Integer(integer)) => {
+                Ok(u64::try_from(*integer).map_or(false, |integer| integer == *unsigned_integer))
+            }
+
+            _ => Ok(self == other),
+        }
+    }
+
+    fn to_number(&self) -> Result<Number, String> {
+        match self {
+            Expression::Integer(integer) => Ok(Number::Integer(*integer)),
+            Expression::UnsignedInteger(unsigned_integer) => Ok(Number::UnsignedInteger(*unsigned_integer)),
+            Expression::Float(float) => Ok(Number::Float(*float)),
+            _ => Err(format!("not an integer, unsigned integer, or float: {}", self.type_name())),
+        }
+    }
+
+    fn into_value(self) -> Result<Value<Expression>, String> {
+        Ok(match self {
+            Expression::Null => Value::Null,
+            Expression::Integer(integer) => Value::Integer(integer),
+            Expression::UnsignedInteger(unsigned_integer) => Value::UnsignedInteger(unsigned_integer),
+            Expression::Float(float) => Value::Float(float),
+            Expression::Boolean(boolean) => Value::Boolean(boolean),
+            Expression::Text(text) => Value::Text(text),
+            Expression::Blob(blob) => Value::Bytes(blob),
+
+            Expression::List(list_resource) => {
+                let mut list = Vec::default();
+                for item in &list_resource.list().inner {
+                    list.push(item.clone().into_value()?);
+                }
+                Value::List(list)
+            }
+
+            Expression::Map(map_resource) => {
+                let mut map = Vec::default();
+                for (key, value) in &map_resource.map().inner {
+                    map.push((key.clone().into_value()?, value.clone().into_value()?));
+                }
+                Value::Map(map)
+            }
+
+            Expression::Custom(_) => Value::Custom(self),
+
+            Expression::Call(_) => return Err("expression not evaluated".into()),
+        })
+    }
 }
@@ -0,0 +1,58 @@
+use super::{super::functions::*, expression::*, scalar::*, value::*};
+
+use {floria_plugin_sdk::data::*, puccini_tosca_2_0_core::*};
+
+//
+// PluginHost
+//
+
+/// [FunctionHost] for a Floria call site.
+///
+/// Arguments are evaluated in the plugin and must have a value.
+pub struct PluginHost {
+    /// Call site.
+    pub call_site: CallSite,
+}
+
+impl PluginHost {
+    /// Constructor.
+    pub fn new(call_site: CallSite) -> Self {
+        Self { call_site }
+    }
+}
+
+impl FunctionHost for PluginHost {
+    type Argument = Expression;
+    type Custom = Expression;
+    type Scalar = Scalar;
+
+    fn evaluate(&self, argument: Self::Argument) -> Result<Option<Value<Self::Custom>>, String> {
+        Ok(Some(argument.must_evaluate(&self.call_site)?.into_value()?))
+    }
+
+    fn coerce_if_custom(
+        &self,
+        value: Value<Self::Custom>,
+        other: &Value<Self::Custom>,
+    ) -> Result<Value<Self::Custom>, String> {
+        match other {
+            Value::Custom(other) => value.into_expression().coerce_if_custom(other)?.into_value(),
+            _ => Ok(value),
+        }
+    }
+
+    fn custom_kind(&self, custom: &Self::Custom) -> String {
+        match custom {
+            Expression::Custom(custom_resource) => custom_resource.custom().kind.clone(),
+            _ => custom.type_name().into(),
+        }
+    }
+
+    fn custom_comparator(&self, custom: &Self::Custom) -> Result<Value<Self::Custom>, String> {
+        custom.clone().comparator()?.into_value()
+    }
+
+    fn set_assert_reason(&self, reason: String) -> Result<(), String> {
+        set_assert_reason(Some(reason))
+    }
+}
@@ -1,15 +1,14 @@
 mod comparator;
 mod expression;
-mod number;
+mod host;
 mod scalar;
 mod schema;
 mod timestamp;
 mod tosca_entity;
-mod tosca_instance_selector;
+mod value;
 mod version;
 
 #[allow(unused_imports)]
 pub use {
-    comparator::*, expression::*, number::*, scalar::*, schema::*, timestamp::*, tosca_entity::*,
-    tosca_instance_selector::*, version::*,
+    comparator::*, expression::*, host::*, scalar::*, schema::*, timestamp::*, tosca_entity::*, value::*, version::*,
 };
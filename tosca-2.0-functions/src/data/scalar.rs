@@ -1,7 +1,8 @@
-use super::{comparator::*, number::*, schema::*};
+use super::{comparator::*, expression::*, schema::*, value::*};
 
 use {
     floria_plugin_sdk::data::*,
+    puccini_tosca_2_0_core::*,
     std::{collections::*, fmt, str::*},
 };
 
@@ -23,6 +24,11 @@ pub struct Scalar {
     /// Unit.
     pub unit: String,
 
+    /// Original representation, if it was parsed from text.
+    ///
+    /// Not used for equality and ordering, which are on the canonical value only.
+    pub original: Option<String>,
+
     // Schema.
     pub schema: ScalarSchema,
 }
@@ -30,14 +36,25 @@ pub struct Scalar {
 impl Scalar {
     /// Constructor.
     pub fn new(number: Number, unit: String, schema: ScalarSchema) -> Self {
-        Self { number, unit, schema }
+        Self { number, unit, original: None, schema }
     }
 
     /// Constructor.
+    ///
+    /// The result is always in the schema's canonical unit, so that coercing an already coerced
+    /// scalar is stable.
     pub fn new_from_expression(expression: Expression, schema: &ScalarSchema) -> Result<Self, String> {
         match expression {
             Expression::Text(text) => Self::new_from_str(&text, schema),
-            Expression::Custom(custom_resource) => custom_resource.custom().try_into(),
+
+            Expression::Custom(custom_resource) => {
+                let scalar: Self = custom_resource.custom().try_into()?;
+                let number = schema.to_canonical(scalar.number, &scalar.unit)?;
+                let mut canonical = Self::new_canonical(number, schema.clone())?;
+                canonical.original = scalar.original;
+                Ok(canonical)
+            }
+
             _ => Err(format!("scalar not a string or custom: {}", expression.type_name())),
         }
     }
@@ -58,27 +75,86 @@ impl Scalar {
             return Err(NOTATION_ERROR.into());
         }
 
-        let mut number = Number::from_str(number)?;
+        let original = format!("{} {}", number, unit);
+
+        let number = Number::from_str(number)?;
+        let number = schema.to_canonical(number, unit)?;
 
-        let unit_factor = schema.unit_factor(unit)?;
+        let mut scalar = Self::new_canonical(number, schema.clone())?;
+        scalar.original = Some(original);
+        Ok(scalar)
+    }
+
+    /// Constructor.
+    ///
+    /// The number is in the canonical unit. If the schema is integer it must be a whole number.
+    pub fn new_canonical(number: Number, schema: ScalarSchema) -> Result<Self, String> {
         let unit = schema.canonical_unit()?;
+        let number = number.must_be_a_number()?;
 
-        if !unit_factor.is_one() {
-            number = number.multiply(unit_factor)?;
-        }
+        let number = if schema.is_integer() {
+            if let Number::Float(float) = number
+                && (float.fract() != 0.)
+            {
+                return Err(format!("scalar not a whole number of {}: {}", unit, number));
+            }
 
-        if schema.is_integer() {
-            Ok(Self::new(Number::Integer(number.try_into()?), unit, schema.clone()))
+            Number::Integer(number.try_into()?)
         } else {
-            Ok(Self::new(Number::Float(number.try_into()?), unit, schema.clone()))
+            Number::Float(number.try_into()?)
+        };
+
+        Ok(Self::new(number, unit, schema))
+    }
+
+    /// Convert to the canonical unit.
+    pub fn canonicalize(self) -> Result<Self, String> {
+        let number = self.schema.to_canonical(self.number, &self.unit)?;
+        let mut canonical = Self::new_canonical(number, self.schema)?;
+        canonical.original = self.original;
+        Ok(canonical)
+    }
+
+    /// True if both scalars share the same canonical unit.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        match (self.schema.canonical_unit(), other.schema.canonical_unit()) {
+            (Ok(canonical_unit), Ok(other_canonical_unit)) => canonical_unit == other_canonical_unit,
+            _ => false,
         }
     }
 }
 
 impl Comparator for Scalar {
     fn comparator(&self) -> Expression {
-        // TODO: canonicalize
-        self.number.into()
+        match self.schema.to_canonical(self.number, &self.unit) {
+            Ok(number) => number.into_expression(),
+            Err(_) => self.number.into_expression(),
+        }
+    }
+}
+
+impl ScalarOperand for Scalar {
+    type Custom = Expression;
+
+    fn from_custom(custom: Self::Custom) -> Result<Self, String> {
+        let scalar: Self = custom.try_into()?;
+        scalar.canonicalize()
+    }
+
+    fn into_custom(self) -> Self::Custom {
+        self.into()
+    }
+
+    fn number(&self) -> Number {
+        self.number
+    }
+
+    fn is_compatible(&self, other: &Self) -> bool {
+        Scalar::is_compatible(self, other)
+    }
+
+    fn with_number(self, number: Number) -> Result<Self, String> {
+        Self::new_canonical(number, self.schema)
     }
 }
 
@@ -122,7 +198,7 @@ impl TryFrom<&Custom> for Scalar {
         let Some(number) = map.get(&"number".into()) else {
             return Err("scalar missing \"number\" key".into());
         };
-        let number: Number = number.try_into().map_err(|error| format!("scalar \"number\" key value: {}", error))?;
+        let number = number.to_number().map_err(|error| format!("scalar \"number\" key value: {}", error))?;
 
         let Some(unit) = map.get(&"unit".into()) else {
             return Err("scalar missing \"unit\" key".into());
@@ -132,18 +208,31 @@ impl TryFrom<&Custom> for Scalar {
         };
         let _ = schema.unit_factor(unit)?;
 
-        Ok(Self::new(number, unit.clone(), schema))
+        let original = match map.get(&"original".into()) {
+            Some(Expression::Text(original)) => Some(original.clone()),
+            Some(_) => return Err("scalar \"original\" key not a string".into()),
+            None => None,
+        };
+
+        let mut scalar = Self::new(number, unit.clone(), schema);
+        scalar.original = original;
+        Ok(scalar)
     }
 }
 
 impl Into<Expression> for Scalar {
     fn into(self) -> Expression {
-        let number: Expression = self.number.into();
-        let map = BTreeMap::from([
+        let number = self.number.into_expression();
+        let mut map = BTreeMap::from([
             ("schema".into(), self.schema.into()),
             ("number".into(), number),
             ("unit".into(), self.unit.into()),
         ]);
+
+        if let Some(original) = self.original {
+            map.insert("original".into(), original.into());
+        }
+
         Custom::new("scalar".into(), map.into()).into()
     }
 }
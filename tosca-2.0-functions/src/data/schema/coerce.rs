@@ -1,3 +1,5 @@
+use super::{error::*, schema::*};
+
 use floria_plugin_sdk::data::*;
 
 //
@@ -7,10 +9,17 @@ use floria_plugin_sdk::data::*;
 /// Coerce.
 pub trait Coerce {
     /// Coerce into the schema.
-    fn coerce(&self, expression: Expression) -> Result<Expression, String>;
+    ///
+    /// The schema is used to follow references. The call site is used to evaluate validators.
+    fn coerce(&self, expression: Expression, schema: &Schema, call_site: &CallSite) -> Result<Expression, CoerceError>;
 
     /// Coerce into the schema.
     ///
     /// Returns [None] if the expression was not modified.
-    fn coerce_option(&self, expression: Option<Expression>) -> Result<Option<Expression>, String>;
+    fn coerce_option(
+        &self,
+        expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError>;
 }
@@ -0,0 +1,139 @@
+use std::fmt;
+
+//
+// CoerceError
+//
+
+/// Coerce error.
+///
+/// Carries the path to the value that failed, e.g. `ports[2].protocol`.
+#[derive(Clone, Debug, Default)]
+pub struct CoerceError {
+    /// Path.
+    pub path: Vec<PathSegment>,
+
+    /// Message.
+    pub message: String,
+}
+
+impl CoerceError {
+    /// Constructor.
+    pub fn new(message: String) -> Self {
+        Self { path: Default::default(), message }
+    }
+
+    /// Prefix the path with a struct field.
+    pub fn in_field(mut self, name: &str) -> Self {
+        self.path.insert(0, PathSegment::Field(name.into()));
+        self
+    }
+
+    /// Prefix the path with a list index.
+    pub fn in_index(mut self, index: usize) -> Self {
+        self.path.insert(0, PathSegment::Index(index));
+        self
+    }
+
+    /// Prefix the path with a map key.
+    pub fn in_key(mut self, key: String) -> Self {
+        self.path.insert(0, PathSegment::Key(key));
+        self
+    }
+
+    /// Path as a string, optionally under a root path.
+    pub fn path_string(&self, root: Option<&str>) -> String {
+        let mut path = String::from(root.unwrap_or_default());
+
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+
+                PathSegment::Index(index) => path.push_str(&format!("[{}]", index)),
+                PathSegment::Key(key) => path.push_str(&format!("[{:?}]", key)),
+            }
+        }
+
+        path
+    }
+
+    /// Into a message, optionally under a root path.
+    pub fn into_message(self, root: Option<&str>) -> String {
+        let path = self.path_string(root);
+        if path.is_empty() { self.message } else { format!("{}: {}", path, self.message) }
+    }
+}
+
+impl From<String> for CoerceError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for CoerceError {
+    fn from(message: &str) -> Self {
+        Self::new(message.into())
+    }
+}
+
+impl fmt::Display for CoerceError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.clone().into_message(None), formatter)
+    }
+}
+
+//
+// PathSegment
+//
+
+/// Value path segment.
+#[derive(Clone, Debug)]
+pub enum PathSegment {
+    /// Struct field.
+    Field(String),
+
+    /// List index.
+    Index(usize),
+
+    /// Map key.
+    Key(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path() {
+        let error = CoerceError::from("not an integer").in_field("protocol").in_index(2).in_field("ports");
+        assert_eq!(error.path_string(None), "ports[2].protocol");
+        assert_eq!(error.path_string(Some("properties")), "properties.ports[2].protocol");
+
+        let error = CoerceError::from("not an integer").in_key("http".into()).in_field("ports");
+        assert_eq!(error.path_string(None), "ports[\"http\"]");
+
+        // Starting with an index
+        let error = CoerceError::from("not an integer").in_field("port").in_index(0);
+        assert_eq!(error.path_string(None), "[0].port");
+        assert_eq!(error.path_string(Some("properties.endpoints")), "properties.endpoints[0].port");
+    }
+
+    #[test]
+    fn message() {
+        let error = CoerceError::from("missing required struct field").in_field("host");
+        assert_eq!(error.to_string(), "host: missing required struct field");
+        assert_eq!(
+            error.into_message(Some("properties.server")),
+            "properties.server.host: missing required struct field"
+        );
+
+        // Without a path
+        let error = CoerceError::from("struct not a map: integer");
+        assert_eq!(error.clone().into_message(None), "struct not a map: integer");
+        assert_eq!(error.into_message(Some("properties.server")), "properties.server: struct not a map: integer");
+    }
+}
@@ -0,0 +1,100 @@
+use super::{coerce::*, error::*, schema::*, validate::*, value::*};
+
+use floria_plugin_sdk::data::*;
+
+//
+// ListSchema
+//
+
+/// List schema.
+#[derive(Clone, Debug, Default)]
+pub struct ListSchema {
+    /// Entry.
+    pub entry: Option<SchemaReference>,
+
+    /// Default.
+    pub default: Option<Expression>,
+
+    /// Validator.
+    pub validator: Option<Expression>,
+}
+
+impl ListSchema {
+    /// Constructor.
+    pub fn new(entry: Option<SchemaReference>, default: Option<Expression>, validator: Option<Expression>) -> Self {
+        Self { entry, default, validator }
+    }
+}
+
+impl Coerce for ListSchema {
+    fn coerce(&self, expression: Expression, schema: &Schema, call_site: &CallSite) -> Result<Expression, CoerceError> {
+        let Expression::List(list_resource) = expression else {
+            return Err(format!("list not a list: {}", expression.type_name()).into());
+        };
+
+        let expression = match self.entry {
+            Some(entry) => {
+                let entry_schema = schema.get(entry)?;
+
+                let list = list_resource.list();
+                let mut coerced_list = Vec::with_capacity(list.inner.len());
+                for (index, item) in list.inner.iter().enumerate() {
+                    let item =
+                        entry_schema.coerce(item.clone(), schema, call_site).map_err(|error| error.in_index(index))?;
+                    coerced_list.push(item);
+                }
+
+                coerced_list.into()
+            }
+
+            None => Expression::List(list_resource),
+        };
+
+        validate(expression, self.validator.as_ref(), call_site)
+    }
+
+    fn coerce_option(
+        &self,
+        mut expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
+        if expression.is_none() && self.default.is_some() {
+            expression = self.default.clone();
+        }
+
+        Ok(match expression {
+            Some(expression) => Some(self.coerce(expression, schema, call_site)?),
+            None => expression,
+        })
+    }
+}
+
+impl TryFrom<Expression> for ListSchema {
+    type Error = String;
+
+    fn try_from(expression: Expression) -> Result<Self, Self::Error> {
+        match expression {
+            Expression::Map(map_resource) => {
+                let map = map_resource.map();
+
+                let entry = get_reference(map, "entry", "list")?;
+                let default = map.into_get("default").cloned();
+                let validator = map.into_get("validator").cloned();
+
+                Ok(Self::new(entry, default, validator))
+            }
+
+            _ => Err(format!("list schema not a map: {}", expression.type_name())),
+        }
+    }
+}
+
+/// Get an optional schema reference.
+pub fn get_reference(map: &Map, name: &'static str, kind: &str) -> Result<Option<SchemaReference>, String> {
+    match map.into_get(name) {
+        Some(Expression::UnsignedInteger(reference)) => Ok(Some(*reference)),
+        Some(value) => Err(format!("{} schema {:?} key not an unsigned integer: {}", kind, name, value.type_name())),
+        None => Ok(None),
+    }
+}
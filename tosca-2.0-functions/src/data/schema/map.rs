@@ -0,0 +1,113 @@
+use super::{coerce::*, error::*, list::*, schema::*, validate::*, value::*};
+
+use {floria_plugin_sdk::data::*, std::collections::*};
+
+//
+// MapSchema
+//
+
+/// Map schema.
+#[derive(Clone, Debug, Default)]
+pub struct MapSchema {
+    /// Key.
+    pub key: Option<SchemaReference>,
+
+    /// Entry.
+    pub entry: Option<SchemaReference>,
+
+    /// Default.
+    pub default: Option<Expression>,
+
+    /// Validator.
+    pub validator: Option<Expression>,
+}
+
+impl MapSchema {
+    /// Constructor.
+    pub fn new(
+        key: Option<SchemaReference>,
+        entry: Option<SchemaReference>,
+        default: Option<Expression>,
+        validator: Option<Expression>,
+    ) -> Self {
+        Self { key, entry, default, validator }
+    }
+}
+
+impl Coerce for MapSchema {
+    fn coerce(&self, expression: Expression, schema: &Schema, call_site: &CallSite) -> Result<Expression, CoerceError> {
+        let Expression::Map(map_resource) = expression else {
+            return Err(format!("map not a map: {}", expression.type_name()).into());
+        };
+
+        if self.key.is_none() && self.entry.is_none() {
+            return validate(Expression::Map(map_resource), self.validator.as_ref(), call_site);
+        }
+
+        let key_schema = self.key.map(|key| schema.get(key)).transpose()?;
+        let entry_schema = self.entry.map(|entry| schema.get(entry)).transpose()?;
+
+        let mut coerced_map = BTreeMap::default();
+        for (key, value) in &map_resource.map().inner {
+            let path_key = match key {
+                Expression::Text(text) => text.clone(),
+                _ => key.to_string(),
+            };
+
+            let key = match key_schema {
+                Some(key_schema) => {
+                    key_schema.coerce(key.clone(), schema, call_site).map_err(|error| error.in_key(path_key.clone()))?
+                }
+                None => key.clone(),
+            };
+
+            let value = match entry_schema {
+                Some(entry_schema) => {
+                    entry_schema.coerce(value.clone(), schema, call_site).map_err(|error| error.in_key(path_key))?
+                }
+                None => value.clone(),
+            };
+
+            coerced_map.insert(key, value);
+        }
+
+        validate(coerced_map.into(), self.validator.as_ref(), call_site)
+    }
+
+    fn coerce_option(
+        &self,
+        mut expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
+        if expression.is_none() && self.default.is_some() {
+            expression = self.default.clone();
+        }
+
+        Ok(match expression {
+            Some(expression) => Some(self.coerce(expression, schema, call_site)?),
+            None => expression,
+        })
+    }
+}
+
+impl TryFrom<Expression> for MapSchema {
+    type Error = String;
+
+    fn try_from(expression: Expression) -> Result<Self, Self::Error> {
+        match expression {
+            Expression::Map(map_resource) => {
+                let map = map_resource.map();
+
+                let key = get_reference(map, "key", "map")?;
+                let entry = get_reference(map, "entry", "map")?;
+                let default = map.into_get("default").cloned();
+                let validator = map.into_get("validator").cloned();
+
+                Ok(Self::new(key, entry, default, validator))
+            }
+
+            _ => Err(format!("map schema not a map: {}", expression.type_name())),
+        }
+    }
+}
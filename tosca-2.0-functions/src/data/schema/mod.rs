@@ -1,9 +1,15 @@
 mod coerce;
+mod error;
+mod list;
+mod map;
 mod primitive;
 mod scalar;
 mod schema;
 mod r#struct;
+mod validate;
 mod value;
 
 #[allow(unused_imports)]
-pub use {coerce::*, primitive::*, scalar::*, schema::*, r#struct::*, value::*};
+pub use {
+    coerce::*, error::*, list::*, map::*, primitive::*, scalar::*, schema::*, r#struct::*, validate::*, value::*,
+};
@@ -1,4 +1,4 @@
-use super::{super::expression::*, coerce::*};
+use super::{super::expression::*, coerce::*, error::*, schema::*, validate::*};
 
 use floria_plugin_sdk::data::*;
 
@@ -27,29 +27,36 @@ impl PrimitiveSchema {
 }
 
 impl Coerce for PrimitiveSchema {
-    fn coerce(&self, expression: Expression) -> Result<Expression, String> {
-        if let Some(_validator) = &self.validator {
-            // TODO
-        }
-
-        expression.must_coerce(&self.kind)
+    fn coerce(
+        &self,
+        expression: Expression,
+        _schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Expression, CoerceError> {
+        let expression = expression.must_coerce(&self.kind)?;
+        validate(expression, self.validator.as_ref(), call_site)
     }
 
-    fn coerce_option(&self, mut expression: Option<Expression>) -> Result<Option<Expression>, String> {
-        if expression.is_none() && self.default.is_some() {
-            expression = self.default.clone();
-        }
-
+    fn coerce_option(
+        &self,
+        expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
         Ok(match expression {
             Some(expression) => {
-                if let Some(_validator) = &self.validator {
-                    // TODO
+                if self.validator.is_some() {
+                    Some(self.coerce(expression, schema, call_site)?)
+                } else {
+                    expression.coerce_option(&self.kind)?
                 }
-
-                expression.coerce_option(&self.kind)?
             }
 
-            None => expression,
+            // The default is a modification
+            None => match &self.default {
+                Some(default) => Some(self.coerce(default.clone(), schema, call_site)?),
+                None => None,
+            },
         })
     }
 }
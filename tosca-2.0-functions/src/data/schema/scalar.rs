@@ -1,9 +1,12 @@
 use super::{
-    super::{number::*, scalar::*},
+    super::{expression::*, scalar::*, value::*},
     coerce::*,
+    error::*,
+    schema::*,
+    validate::*,
 };
 
-use {floria_plugin_sdk::data::*, std::collections::*};
+use {floria_plugin_sdk::data::*, puccini_tosca_2_0_core::*, std::collections::*};
 
 //
 // ScalarSchema
@@ -78,46 +81,62 @@ impl ScalarSchema {
     }
 
     /// Find factor for unit.
+    ///
+    /// Units can be used with or without a prefix.
     pub fn unit_factor(&self, unit: &str) -> Result<Number, String> {
-        if !self.prefixes.is_empty() {
-            for (prefix, prefix_factor) in &self.prefixes {
-                for (unit_, unit_factor) in &self.units {
-                    let unit_ = format!("{}{}", prefix, unit_);
-                    if unit == unit_ {
-                        let factor = prefix_factor.multiply(*unit_factor)?;
-                        return Ok(factor.into());
-                    }
-                }
-            }
-        } else {
-            for (unit_, unit_factor) in &self.units {
-                if unit == unit_ {
-                    return Ok(*unit_factor);
-                }
+        if let Some(unit_factor) = self.units.get(unit) {
+            return Ok(*unit_factor);
+        }
+
+        for (prefix, prefix_factor) in &self.prefixes {
+            if let Some(unit_) = unit.strip_prefix(prefix.as_str())
+                && let Some(unit_factor) = self.units.get(unit_)
+            {
+                return prefix_factor.multiply(*unit_factor);
             }
         }
 
         Err(format!("unsupported unit: {}", unit))
     }
-}
 
-impl Coerce for ScalarSchema {
-    fn coerce(&self, expression: Expression) -> Result<Expression, String> {
-        if let Some(_validator) = &self.validator {
-            // TODO
+    /// Convert a number in a unit to the canonical unit.
+    pub fn to_canonical(&self, number: Number, unit: &str) -> Result<Number, String> {
+        let canonical_unit = self.canonical_unit()?;
+        if unit == canonical_unit {
+            return Ok(number);
         }
 
+        let unit_factor = self.unit_factor(unit)?;
+        let number = if unit_factor.is_one() { number } else { number.multiply(unit_factor)? };
+
+        let canonical_factor = self.unit_factor(&canonical_unit)?;
+        if canonical_factor.is_one() { Ok(number) } else { number.divide(canonical_factor) }
+    }
+}
+
+impl Coerce for ScalarSchema {
+    fn coerce(
+        &self,
+        expression: Expression,
+        _schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Expression, CoerceError> {
         let scalar = Scalar::new_from_expression(expression, self)?;
-        Ok(scalar.into())
+        validate(scalar.into(), self.validator.as_ref(), call_site)
     }
 
-    fn coerce_option(&self, mut expression: Option<Expression>) -> Result<Option<Expression>, String> {
+    fn coerce_option(
+        &self,
+        mut expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
         if expression.is_none() && self.default.is_some() {
             expression = self.default.clone();
         }
 
         Ok(match expression {
-            Some(expression) => Some(self.coerce(expression)?),
+            Some(expression) => Some(self.coerce(expression, schema, call_site)?),
             None => expression,
         })
     }
@@ -148,14 +167,15 @@ impl TryFrom<Expression> for ScalarSchema {
 
 impl Into<Expression> for ScalarSchema {
     fn into(self) -> Expression {
-        let mut map = BTreeMap::default();
+        let mut map = BTreeMap::from([("kind".into(), "scalar".into())]);
 
         if let Some(data_kind) = self.data_kind {
             map.insert("data_kind".into(), data_kind.into());
         }
 
         if !self.units.is_empty() {
-            let units: BTreeMap<_, _> = self.units.into_iter().map(|(key, value)| (key.into(), value.into())).collect();
+            let units: BTreeMap<_, _> =
+                self.units.into_iter().map(|(key, value)| (key.into(), value.into_expression())).collect();
             map.insert("units".into(), units.into());
         }
 
@@ -165,10 +185,18 @@ impl Into<Expression> for ScalarSchema {
 
         if !self.prefixes.is_empty() {
             let prefixes: BTreeMap<_, _> =
-                self.prefixes.into_iter().map(|(key, value)| (key.into(), value.into())).collect();
+                self.prefixes.into_iter().map(|(key, value)| (key.into(), value.into_expression())).collect();
             map.insert("prefixes".into(), prefixes.into());
         }
 
+        if let Some(default) = self.default {
+            map.insert("default".into(), default);
+        }
+
+        if let Some(validator) = self.validator {
+            map.insert("validator".into(), validator);
+        }
+
         map.into()
     }
 }
@@ -192,7 +220,7 @@ fn get_map(map: &Map, name: &'static str) -> Result<BTreeMap<String, Number>, St
                 };
 
                 let value =
-                    value.try_into().map_err(|error| format!("scalar schema {:?} key map value: {}", name, error))?;
+                    value.to_number().map_err(|error| format!("scalar schema {:?} key map value: {}", name, error))?;
 
                 result.insert(key.clone(), value);
             }
@@ -205,3 +233,133 @@ fn get_map(map: &Map, name: &'static str) -> Result<BTreeMap<String, Number>, St
         None => Ok(Default::default()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{super::value::ValueSchema, *};
+
+    fn bytes() -> ScalarSchema {
+        let units = BTreeMap::from([
+            ("B".into(), Number::Integer(1)),
+            ("kB".into(), Number::Integer(1000)),
+            ("KiB".into(), Number::Integer(1024)),
+            ("MiB".into(), Number::Integer(1048576)),
+        ]);
+        ScalarSchema::new(Some("integer".into()), units, None, Default::default(), None, None)
+    }
+
+    fn time() -> ScalarSchema {
+        let units = BTreeMap::from([("s".into(), Number::Integer(1)), ("min".into(), Number::Integer(60))]);
+        let prefixes = BTreeMap::from([("m".into(), Number::Float(0.001)), ("k".into(), Number::Integer(1000))]);
+        ScalarSchema::new(Some("float".into()), units, None, prefixes, None, None)
+    }
+
+    #[test]
+    fn canonical_unit() {
+        assert_eq!(bytes().canonical_unit(), Ok("B".into()));
+
+        let mut schema = bytes();
+        schema.units.insert("byte".into(), Number::Integer(1));
+        assert_eq!(schema.canonical_unit(), Err("multiple candidates for canonical unit".into()));
+
+        schema.canonical_unit = Some("byte".into());
+        assert_eq!(schema.canonical_unit(), Ok("byte".into()));
+
+        schema.canonical_unit = Some("bit".into());
+        assert_eq!(schema.canonical_unit(), Err("unsupported unit: bit".into()));
+    }
+
+    #[test]
+    fn coerce_into_canonical_unit() {
+        let schema = bytes();
+        assert_eq!(Scalar::new_from_str("2 KiB", &schema).map(|scalar| scalar.to_string()), Ok("2048 B".into()));
+        assert_eq!(Scalar::new_from_str("1.5 kB", &schema).map(|scalar| scalar.to_string()), Ok("1500 B".into()));
+        assert_eq!(Scalar::new_from_str("1 MiB", &schema).map(|scalar| scalar.to_string()), Ok("1048576 B".into()));
+    }
+
+    #[test]
+    fn coerce_with_prefix() {
+        let schema = time();
+        assert_eq!(Scalar::new_from_str("500 ms", &schema).map(|scalar| scalar.to_string()), Ok("0.5 s".into()));
+        assert_eq!(Scalar::new_from_str("2 min", &schema).map(|scalar| scalar.to_string()), Ok("120 s".into()));
+        assert_eq!(Scalar::new_from_str("1 ks", &schema).map(|scalar| scalar.to_string()), Ok("1000 s".into()));
+    }
+
+    #[test]
+    fn coercion_is_stable() {
+        let schema = bytes();
+        let scalar = Scalar::new_from_str("3 KiB", &schema).expect("scalar");
+        let scalar = scalar.canonicalize().expect("canonical");
+        assert_eq!(scalar.to_string(), "3072 B");
+    }
+
+    #[test]
+    fn invalid_scalars() {
+        let schema = bytes();
+        assert_eq!(
+            Scalar::new_from_str("1 XB", &schema).map(|scalar| scalar.to_string()),
+            Err("unsupported unit: XB".into())
+        );
+        assert_eq!(
+            Scalar::new_from_str("1", &schema).map(|scalar| scalar.to_string()),
+            Err("scalar not \"<number> <unit>\"".into())
+        );
+        assert_eq!(
+            Scalar::new_from_str("1 B B", &schema).map(|scalar| scalar.to_string()),
+            Err("scalar not \"<number> <unit>\"".into())
+        );
+        assert_eq!(
+            Scalar::new_from_str("one B", &schema).map(|scalar| scalar.to_string()),
+            Err("not a number: one".into())
+        );
+    }
+
+    #[test]
+    fn integer_scalars_are_exact() {
+        let schema = bytes();
+        assert_eq!(
+            Scalar::new_from_str("1.5 B", &schema).map(|scalar| scalar.to_string()),
+            Err("scalar not a whole number of B: 1.5".into())
+        );
+        assert_eq!(
+            Scalar::new_canonical(Number::Float(0.25), schema).map(|scalar| scalar.to_string()),
+            Err("scalar not a whole number of B: 0.25".into())
+        );
+    }
+
+    #[test]
+    fn original_representation() {
+        let scalar = Scalar::new_from_str("2  KiB", &bytes()).expect("scalar");
+        assert_eq!(scalar.original.as_deref(), Some("2 KiB"));
+
+        // Survives a round trip through a custom expression
+        let scalar = Scalar::try_from(Into::<Expression>::into(scalar)).expect("scalar");
+        assert_eq!(scalar.original.as_deref(), Some("2 KiB"));
+        assert_eq!(scalar.to_string(), "2048 B");
+    }
+
+    #[test]
+    fn schema_round_trip() {
+        let mut schema = bytes();
+        schema.default = Some("1 KiB".into());
+        schema.validator = Some(true.into());
+
+        let expression: Expression = schema.into();
+        let Ok(ValueSchema::Scalar(schema)) = ValueSchema::try_from(expression) else {
+            panic!("not a scalar schema");
+        };
+        assert!(schema.is_integer());
+        assert_eq!(schema.units.len(), 4);
+        assert!(schema.default.is_some());
+        assert!(schema.validator.is_some());
+    }
+
+    #[test]
+    fn compatibility() {
+        let kilobytes = Scalar::new_from_str("1 kB", &bytes()).expect("scalar");
+        let mebibytes = Scalar::new_from_str("1 MiB", &bytes()).expect("scalar");
+        let seconds = Scalar::new_from_str("1 s", &time()).expect("scalar");
+        assert!(kilobytes.is_compatible(&mebibytes));
+        assert!(!kilobytes.is_compatible(&seconds));
+    }
+}
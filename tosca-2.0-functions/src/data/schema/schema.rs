@@ -1,4 +1,4 @@
-use super::{coerce::*, value::*};
+use super::{coerce::*, error::*, value::*};
 
 use floria_plugin_sdk::data::*;
 
@@ -30,15 +30,21 @@ impl Schema {
             None => Err(format!("schema value not found: {}", reference)),
         }
     }
-}
 
-impl Coerce for Schema {
-    fn coerce(&self, expression: Expression) -> Result<Expression, String> {
-        self.root()?.coerce(expression)
+    /// Coerce into the root schema.
+    pub fn coerce(&self, expression: Expression, call_site: &CallSite) -> Result<Expression, CoerceError> {
+        self.root()?.coerce(expression, self, call_site)
     }
 
-    fn coerce_option(&self, expression: Option<Expression>) -> Result<Option<Expression>, String> {
-        self.root()?.coerce_option(expression)
+    /// Coerce into the root schema.
+    ///
+    /// Returns [None] if the expression was not modified.
+    pub fn coerce_option(
+        &self,
+        expression: Option<Expression>,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
+        self.root()?.coerce_option(expression, self, call_site)
     }
 }
 
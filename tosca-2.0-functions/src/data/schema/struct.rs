@@ -1,4 +1,4 @@
-use super::{coerce::*, value::*};
+use super::{super::expression::*, coerce::*, error::*, schema::*, validate::*, value::*};
 
 use {floria_plugin_sdk::data::*, std::collections::*};
 
@@ -12,6 +12,15 @@ pub struct StructSchema {
     /// Fields.
     pub fields: BTreeMap<String, ValueSchema>,
 
+    /// Required fields.
+    pub required: BTreeSet<String>,
+
+    /// Field defaults.
+    pub defaults: BTreeMap<String, Expression>,
+
+    /// Field validators.
+    pub validators: BTreeMap<String, Expression>,
+
     /// Default.
     pub default: Option<Expression>,
 
@@ -23,19 +32,31 @@ impl StructSchema {
     /// Constructor.
     pub fn new(
         fields: BTreeMap<String, ValueSchema>,
+        required: BTreeSet<String>,
+        defaults: BTreeMap<String, Expression>,
+        validators: BTreeMap<String, Expression>,
         default: Option<Expression>,
         validator: Option<Expression>,
     ) -> Self {
-        Self { fields, default, validator }
+        Self { fields, required, defaults, validators, default, validator }
+    }
+
+    /// Coerce a field and validate it.
+    pub fn coerce_field(
+        &self,
+        name: &str,
+        field_schema: &ValueSchema,
+        expression: Expression,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Expression, CoerceError> {
+        let expression = field_schema.coerce(expression, schema, call_site)?;
+        validate(expression, self.validators.get(name), call_site)
     }
 }
 
 impl Coerce for StructSchema {
-    fn coerce(&self, expression: Expression) -> Result<Expression, String> {
-        if let Some(_validator) = &self.validator {
-            // TODO
-        }
-
+    fn coerce(&self, expression: Expression, schema: &Schema, call_site: &CallSite) -> Result<Expression, CoerceError> {
         match expression {
             Expression::Map(map_resource) => {
                 let map = map_resource.map();
@@ -43,32 +64,77 @@ impl Coerce for StructSchema {
                 let mut coerced_map = BTreeMap::default();
                 for (key, value) in &map.inner {
                     let Expression::Text(name) = key else {
-                        return Err(format!("struct field name not a string: {}", key.type_name()));
+                        return Err(format!("struct field name not a string: {}", key.type_name()).into());
                     };
 
                     match self.fields.get(name) {
-                        Some(schema) => {
-                            coerced_map.insert(key.clone(), schema.coerce(value.clone())?);
+                        Some(field_schema) => {
+                            let value = self
+                                .coerce_field(name, field_schema, value.clone(), schema, call_site)
+                                .map_err(|error| error.in_field(name))?;
+                            coerced_map.insert(key.clone(), value);
+                        }
+
+                        None => return Err(CoerceError::from("unsupported struct field").in_field(name)),
+                    }
+                }
+
+                // Missing fields
+                for (name, field_schema) in &self.fields {
+                    let key = Expression::from(name.clone());
+                    if coerced_map.contains_key(&key) {
+                        continue;
+                    }
+
+                    let default = match self.defaults.get(name) {
+                        Some(default) => default
+                            .clone()
+                            .evaluate(call_site)
+                            .map_err(|error| CoerceError::from(error).in_field(name))?,
+                        None => None,
+                    };
+
+                    let value = match default {
+                        Some(default) => Some(self.coerce_field(name, field_schema, default, schema, call_site)),
+                        None => field_schema
+                            .coerce_option(None, schema, call_site)
+                            .transpose()
+                            .map(|value| value.and_then(|value| validate(value, self.validators.get(name), call_site))),
+                    };
+
+                    match value {
+                        Some(value) => {
+                            let value = value.map_err(|error| error.in_field(name))?;
+                            coerced_map.insert(key, value);
                         }
 
-                        None => return Err(format!("unsupported struct field: {:?}", name)),
+                        None => {
+                            if self.required.contains(name) {
+                                return Err(CoerceError::from("missing required struct field").in_field(name));
+                            }
+                        }
                     }
                 }
 
-                Ok(coerced_map.into())
+                validate(coerced_map.into(), self.validator.as_ref(), call_site)
             }
 
-            _ => Err(format!("struct not a map: {}", expression.type_name())),
+            _ => Err(format!("struct not a map: {}", expression.type_name()).into()),
         }
     }
 
-    fn coerce_option(&self, mut expression: Option<Expression>) -> Result<Option<Expression>, String> {
+    fn coerce_option(
+        &self,
+        mut expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
         if expression.is_none() && self.default.is_some() {
             expression = self.default.clone();
         }
 
         Ok(match expression {
-            Some(expression) => Some(self.coerce(expression)?),
+            Some(expression) => Some(self.coerce(expression, schema, call_site)?),
             None => expression,
         })
     }
@@ -83,10 +149,13 @@ impl TryFrom<Expression> for StructSchema {
                 let map = map_resource.map();
 
                 let fields = get_map(map, "fields")?;
+                let required = get_set(map, "required")?;
+                let defaults = get_expression_map(map, "defaults")?;
+                let validators = get_expression_map(map, "validators")?;
                 let default = map.into_get("default").cloned();
                 let validator = map.into_get("validator").cloned();
 
-                Ok(Self::new(fields, default, validator))
+                Ok(Self::new(fields, required, defaults, validators, default, validator))
             }
 
             _ => Err(format!("struct schema not a map: {}", expression.type_name())),
@@ -120,3 +189,47 @@ fn get_map(map: &Map, name: &'static str) -> Result<BTreeMap<String, ValueSchema
         None => Ok(Default::default()),
     }
 }
+
+fn get_set(map: &Map, name: &'static str) -> Result<BTreeSet<String>, String> {
+    match map.into_get(name) {
+        Some(Expression::List(list_resource)) => {
+            let mut set = BTreeSet::default();
+
+            for item in &list_resource.list().inner {
+                let Expression::Text(item) = item else {
+                    return Err(format!("struct schema {:?} key has non-string item: {}", name, item.type_name()));
+                };
+
+                set.insert(item.clone());
+            }
+
+            Ok(set)
+        }
+
+        Some(value) => return Err(format!("struct schema {:?} key not a list: {}", name, value.type_name())),
+
+        None => Ok(Default::default()),
+    }
+}
+
+fn get_expression_map(map: &Map, name: &'static str) -> Result<BTreeMap<String, Expression>, String> {
+    match map.into_get(name) {
+        Some(Expression::Map(map_resource)) => {
+            let mut map = BTreeMap::default();
+
+            for (key, value) in &map_resource.map().inner {
+                let Expression::Text(key) = key else {
+                    return Err(format!("struct schema {:?} key has non-string map key: {}", name, key.type_name()));
+                };
+
+                map.insert(key.clone(), value.clone());
+            }
+
+            Ok(map)
+        }
+
+        Some(value) => return Err(format!("struct schema {:?} key not a map: {}", name, value.type_name())),
+
+        None => Ok(Default::default()),
+    }
+}
@@ -0,0 +1,42 @@
+use super::{
+    super::{super::functions::*, expression::*},
+    error::*,
+};
+
+use floria_plugin_sdk::data::*;
+
+/// Validate a coerced value.
+///
+/// The validator is evaluated with the value bound as [`$value`](super::super::super::functions::value).
+/// Like [`$_apply`](super::super::super::functions::apply), a validator may return a new value,
+/// which will replace the coerced value. A boolean result is instead a verdict: true passes and
+/// false fails with the `$_assert` reason.
+pub fn validate(
+    expression: Expression,
+    validator: Option<&Expression>,
+    call_site: &CallSite,
+) -> Result<Expression, CoerceError> {
+    let Some(validator) = validator else {
+        return Ok(expression);
+    };
+
+    let outer_override = get_call_site_override()?;
+    set_call_site_override(Some(expression.clone()))?;
+    set_assert_reason(None)?;
+
+    let result = validator.clone().evaluate(call_site);
+
+    set_call_site_override(outer_override)?;
+
+    match result? {
+        // A boolean result is a verdict, not a replacement value (even when the value is a boolean)
+        Some(Expression::Boolean(false)) => match get_assert_reason()? {
+            Some(reason) => Err(format!("invalid because {} is false", reason).into()),
+            None => Err("invalid because an expression is false".into()),
+        },
+
+        Some(Expression::Boolean(true)) | None => Ok(expression),
+
+        Some(expression) => Ok(expression),
+    }
+}
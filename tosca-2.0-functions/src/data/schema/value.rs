@@ -1,4 +1,4 @@
-use super::{coerce::*, primitive::*, scalar::*, r#struct::*};
+use super::{coerce::*, error::*, list::*, map::*, primitive::*, scalar::*, schema::*, r#struct::*};
 
 use floria_plugin_sdk::data::*;
 
@@ -12,30 +12,42 @@ pub type SchemaReference = u64;
 /// Value schema.
 #[derive(Clone, Debug)]
 pub enum ValueSchema {
+    Any,
     Reference(SchemaReference),
     Primitive(PrimitiveSchema),
     Scalar(ScalarSchema),
+    List(ListSchema),
+    Map(MapSchema),
     Struct(StructSchema),
 }
 
 impl Coerce for ValueSchema {
-    fn coerce(&self, expression: Expression) -> Result<Expression, String> {
+    fn coerce(&self, expression: Expression, schema: &Schema, call_site: &CallSite) -> Result<Expression, CoerceError> {
         match self {
-            Self::Primitive(primitive) => primitive.coerce(expression),
-            Self::Scalar(scalar) => scalar.coerce(expression),
-            Self::Struct(struct_) => struct_.coerce(expression),
-
-            _ => Ok(expression),
+            Self::Any => Ok(expression),
+            Self::Reference(reference) => schema.get(*reference)?.coerce(expression, schema, call_site),
+            Self::Primitive(primitive) => primitive.coerce(expression, schema, call_site),
+            Self::Scalar(scalar) => scalar.coerce(expression, schema, call_site),
+            Self::List(list) => list.coerce(expression, schema, call_site),
+            Self::Map(map) => map.coerce(expression, schema, call_site),
+            Self::Struct(struct_) => struct_.coerce(expression, schema, call_site),
         }
     }
 
-    fn coerce_option(&self, expression: Option<Expression>) -> Result<Option<Expression>, String> {
+    fn coerce_option(
+        &self,
+        expression: Option<Expression>,
+        schema: &Schema,
+        call_site: &CallSite,
+    ) -> Result<Option<Expression>, CoerceError> {
         match self {
-            Self::Primitive(primitive) => primitive.coerce_option(expression),
-            Self::Scalar(scalar) => scalar.coerce_option(expression),
-            Self::Struct(struct_) => struct_.coerce_option(expression),
-
-            _ => Ok(expression),
+            Self::Any => Ok(expression),
+            Self::Reference(reference) => schema.get(*reference)?.coerce_option(expression, schema, call_site),
+            Self::Primitive(primitive) => primitive.coerce_option(expression, schema, call_site),
+            Self::Scalar(scalar) => scalar.coerce_option(expression, schema, call_site),
+            Self::List(list) => list.coerce_option(expression, schema, call_site),
+            Self::Map(map) => map.coerce_option(expression, schema, call_site),
+            Self::Struct(struct_) => struct_.coerce_option(expression, schema, call_site),
         }
     }
 }
@@ -61,6 +73,16 @@ impl TryFrom<Expression> for ValueSchema {
                     return Ok(Self::Scalar(scalar));
                 }
 
+                "list" => {
+                    let list = ListSchema::try_from(expression)?;
+                    return Ok(Self::List(list));
+                }
+
+                "map" => {
+                    let map = MapSchema::try_from(expression)?;
+                    return Ok(Self::Map(map));
+                }
+
                 "struct" => {
                     let r#struct = StructSchema::try_from(expression)?;
                     return Ok(Self::Struct(r#struct));
@@ -74,10 +96,11 @@ impl TryFrom<Expression> for ValueSchema {
         }
 
         match expression {
+            Expression::Null => Ok(Self::Any),
             Expression::UnsignedInteger(unsigned_integer) => Ok(Self::Reference(unsigned_integer)),
             Expression::Text(text) => Ok(Self::Primitive(PrimitiveSchema::new(text, None, None))),
 
-            _ => Err(format!("value schema not a map, string, or unsigned integer: {}", expression.type_name())),
+            _ => Err(format!("value schema not a map, string, unsigned integer, or null: {}", expression.type_name())),
         }
     }
 }
@@ -1,6 +1,9 @@
-use super::tosca_instance_selector::*;
+use super::{expression::*, scalar::*};
 
-use floria_plugin_sdk::{data::*, traverse};
+use {
+    floria_plugin_sdk::{data::*, traverse},
+    puccini_tosca_2_0_core::*,
+};
 
 //
 // ToscaEntity
@@ -25,22 +28,34 @@ pub trait ToscaEntity {
     /// Is TOSCA requirement.
     fn is_tosca_requirement(&self, requirement_name: &str) -> Result<bool, String>;
 
+    /// Is TOSCA relationship created from a relationship template (according to its
+    /// "tosca:relationship_template" metadata).
+    fn is_tosca_relationship(&self, relationship_template_name: &str) -> Result<bool, String>;
+
+    /// Is TOSCA artifact.
+    fn is_tosca_artifact(&self, artifact_name: &str) -> Result<bool, String>;
+
+    /// Artifact.
+    fn get_tosca_artifact(&self, artifact_name: &str) -> Result<Option<Entity>, String>;
+
+    /// Artifact location.
+    fn get_tosca_location(&self) -> Result<String, String>;
+
     /// Capability.
     fn get_tosca_capability(&self, capability_name: &str) -> Result<Option<Entity>, String>;
 
-    /// Outgoing relationship.
-    fn get_tosca_outgoing_relationship(
+    /// Outgoing relationships.
+    fn get_tosca_outgoing_relationships(
         &self,
         requirement_name: &str,
         selector: ToscaInstanceSelector,
-    ) -> Result<Option<Entity>, String>;
+    ) -> Result<Vec<Entity>, String>;
 
-    /// Incoming relationship.
-    fn get_tosca_incoming_relationship(
-        &self,
-        requirement_name: &str,
-        selector: ToscaInstanceSelector,
-    ) -> Result<Option<Entity>, String>;
+    /// Incoming relationships.
+    ///
+    /// Note that the TOSCA path grammar does not name the requirement, so all incoming
+    /// relationships are candidates. See: https://github.com/oasis-tcs/tosca-specs/issues/315
+    fn get_tosca_incoming_relationships(&self, selector: ToscaInstanceSelector) -> Result<Vec<Entity>, String>;
 
     /// Source node.
     fn get_tosca_source_node(&self) -> Result<Entity, String>;
@@ -56,7 +71,7 @@ pub trait ToscaEntity {
             .ok_or_else(|| format!("TOSCA: capability {} missing `containing node`", target_vertex.id))?;
 
         match vertex.get_tosca_entity_kind()?.as_str() {
-            "NodeTemplate" => Ok(vertex),
+            NODE_TEMPLATE_NAME => Ok(vertex),
 
             kind => Err(format!(
                 "TOSCA: container of relationship target capability {} not a TOSCA node: {}",
@@ -65,11 +80,20 @@ pub trait ToscaEntity {
         }
     }
 
+    /// Relationship allocation for a capability property.
+    fn get_tosca_allocation(&self, property_name: &str) -> Result<Option<Expression>, String>;
+
+    /// Capability property value that can be allocated.
+    fn get_tosca_capacity(&self, property_name: &str) -> Result<Expression, String>;
+
+    /// Capability property capacity minus the allocations of all incoming relationships.
+    fn get_tosca_available_allocation(&self, property_name: &str) -> Result<Operand<Scalar>, String>;
+
     /// Service.
     fn find_tosca_service(&self) -> Result<Entity, String> {
         let node = Entity::get(&self.find_tosca_service_id()?)?;
         match node.get_tosca_entity_kind()?.as_str() {
-            "ServiceTemplate" => Ok(node),
+            SERVICE_TEMPLATE_NAME => Ok(node),
 
             kind => return Err(format!("TOSCA: entity {} not a TOSCA service: {}", node.id, kind)),
         }
@@ -78,21 +102,47 @@ pub trait ToscaEntity {
     /// Service template ID.
     fn find_tosca_service_id(&self) -> Result<Id, String>;
 
-    /// Node.
-    fn find_tosca_node(
+    /// Nodes.
+    fn find_tosca_nodes(
         &self,
         node_template_name: &str,
-        _selector: ToscaInstanceSelector,
-    ) -> Result<Option<Entity>, String> {
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
         let service = self.find_tosca_service()?;
+
+        let mut nodes = Vec::default();
         for contained_vertex_id in service.get_contained_vertex_ids()? {
             let vertex = Entity::get(&contained_vertex_id)?;
             if vertex.is_tosca_node(node_template_name)? {
-                return Ok(Some(vertex));
+                nodes.push(vertex);
             }
         }
 
-        Ok(None)
+        Ok(selector.select(nodes))
+    }
+
+    /// Relationships created from a relationship template.
+    fn find_tosca_relationships(
+        &self,
+        relationship_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
+        let service = self.find_tosca_service()?;
+
+        let mut relationships = Vec::default();
+        for contained_vertex_id in service.get_contained_vertex_ids()? {
+            let vertex = Entity::get(&contained_vertex_id)?;
+            if vertex.get_tosca_entity_kind()? == NODE_TEMPLATE_NAME {
+                for outgoing_edge_id in vertex.get_outgoing_edge_ids()? {
+                    let edge = Entity::get(&outgoing_edge_id)?;
+                    if edge.is_tosca_relationship(relationship_template_name)? {
+                        relationships.push(edge);
+                    }
+                }
+            }
+        }
+
+        Ok(selector.select(relationships))
     }
 }
 
@@ -109,7 +159,7 @@ impl ToscaEntity for Entity {
     }
 
     fn is_tosca_node(&self, node_template_name: &str) -> Result<bool, String> {
-        if self.get_tosca_entity_kind()?.as_str() == "NodeTemplate" {
+        if self.get_tosca_entity_kind()?.as_str() == NODE_TEMPLATE_NAME {
             let node_template_id = self
                 .get_origin_template_id()?
                 .ok_or_else(|| format!("TOSCA: node {} missing \"origin_template_id\"", self.id))?;
@@ -121,7 +171,7 @@ impl ToscaEntity for Entity {
     }
 
     fn is_tosca_capability(&self, capability_name: &str) -> Result<bool, String> {
-        if self.get_tosca_entity_kind()? == "CapabilityAssignment" {
+        if self.get_tosca_entity_kind()? == CAPABILITY_NAME {
             let node_template_id = self
                 .get_origin_template_id()?
                 .ok_or_else(|| format!("TOSCA: capability {} missing \"origin_template_id\"", self.id))?;
@@ -135,7 +185,7 @@ impl ToscaEntity for Entity {
     }
 
     fn is_tosca_requirement(&self, requirement_name: &str) -> Result<bool, String> {
-        if self.get_tosca_entity_kind()? == "RequirementAssignment" {
+        if self.get_tosca_entity_kind()? == REQUIREMENT_NAME {
             let relationship_template_id = self
                 .get_origin_template_id()?
                 .ok_or_else(|| format!("TOSCA: relationship {} missing `origin_template_id`", self.id))?;
@@ -153,9 +203,73 @@ impl ToscaEntity for Entity {
         Ok(false)
     }
 
+    fn is_tosca_relationship(&self, relationship_template_name: &str) -> Result<bool, String> {
+        if self.get_tosca_entity_kind()? == REQUIREMENT_NAME {
+            return match traverse!(self.expression, "metadata", "tosca:relationship_template") {
+                Some(Expression::Text(name)) => Ok(name == relationship_template_name),
+
+                Some(_) => Err(format!(
+                    "TOSCA: relationship {} has malformed \"tosca:relationship_template\" metadata, not a string",
+                    self.id
+                )),
+
+                None => Ok(false),
+            };
+        }
+
+        Ok(false)
+    }
+
+    fn is_tosca_artifact(&self, artifact_name: &str) -> Result<bool, String> {
+        if self.get_tosca_entity_kind()? == ARTIFACT_NAME {
+            let artifact_template_id = self
+                .get_origin_template_id()?
+                .ok_or_else(|| format!("TOSCA: artifact {} missing \"origin_template_id\"", self.id))?;
+
+            if artifact_template_id.id == artifact_name {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn get_tosca_artifact(&self, artifact_name: &str) -> Result<Option<Entity>, String> {
+        match self.get_tosca_entity_kind()?.as_str() {
+            NODE_TEMPLATE_NAME => {
+                for contained_vertex_id in self.get_contained_vertex_ids()? {
+                    let vertex = Entity::get(&contained_vertex_id)?;
+                    if vertex.is_tosca_artifact(artifact_name)? {
+                        return Ok(Some(vertex));
+                    }
+                }
+            }
+
+            kind => return Err(format!("TOSCA: entity {} not a TOSCA node: {}", self.id, kind)),
+        }
+
+        Ok(None)
+    }
+
+    fn get_tosca_location(&self) -> Result<String, String> {
+        match self.get_tosca_entity_kind()?.as_str() {
+            ARTIFACT_NAME => match traverse!(self.expression, "metadata", "tosca:location") {
+                Some(Expression::Text(location)) => Ok(location.clone()),
+
+                Some(_) => {
+                    Err(format!("TOSCA: artifact {} has malformed \"tosca:location\" metadata, not a string", self.id))
+                }
+
+                None => Err(format!("TOSCA: artifact {} has no location", self.id)),
+            },
+
+            kind => return Err(format!("TOSCA: entity {} not a TOSCA artifact: {}", self.id, kind)),
+        }
+    }
+
     fn get_tosca_capability(&self, capability_name: &str) -> Result<Option<Entity>, String> {
         match self.get_tosca_entity_kind()?.as_str() {
-            "NodeTemplate" => {
+            NODE_TEMPLATE_NAME => {
                 for contained_vertex_id in self.get_contained_vertex_ids()? {
                     let vertex = Entity::get(&contained_vertex_id)?;
                     if vertex.is_tosca_capability(capability_name)? {
@@ -170,17 +284,19 @@ impl ToscaEntity for Entity {
         Ok(None)
     }
 
-    fn get_tosca_outgoing_relationship(
+    fn get_tosca_outgoing_relationships(
         &self,
         requirement_name: &str,
-        _selector: ToscaInstanceSelector,
-    ) -> Result<Option<Entity>, String> {
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
+        let mut relationships = Vec::default();
+
         match self.get_tosca_entity_kind()?.as_str() {
-            "NodeTemplate" => {
+            NODE_TEMPLATE_NAME => {
                 for outgoing_edge_id in self.get_outgoing_edge_ids()? {
                     let edge = Entity::get(&outgoing_edge_id)?;
                     if edge.is_tosca_requirement(requirement_name)? {
-                        return Ok(Some(edge));
+                        relationships.push(edge);
                     }
                 }
             }
@@ -188,20 +304,18 @@ impl ToscaEntity for Entity {
             kind => return Err(format!("TOSCA: entity {} not a TOSCA node: {}", self.id, kind)),
         }
 
-        Ok(None)
+        Ok(selector.select(relationships))
     }
 
-    fn get_tosca_incoming_relationship(
-        &self,
-        requirement_name: &str,
-        _selector: ToscaInstanceSelector,
-    ) -> Result<Option<Entity>, String> {
+    fn get_tosca_incoming_relationships(&self, selector: ToscaInstanceSelector) -> Result<Vec<Entity>, String> {
+        let mut relationships = Vec::default();
+
         match self.get_tosca_entity_kind()?.as_str() {
-            "CapabilityAssignment" => {
+            CAPABILITY_NAME => {
                 for incoming_edge_id in self.get_incoming_edge_ids()? {
                     let relationship = Entity::get(&incoming_edge_id)?;
-                    if relationship.is_tosca_requirement(requirement_name)? {
-                        return Ok(Some(relationship));
+                    if relationship.get_tosca_entity_kind()? == REQUIREMENT_NAME {
+                        relationships.push(relationship);
                     }
                 }
             }
@@ -209,15 +323,15 @@ impl ToscaEntity for Entity {
             kind => return Err(format!("TOSCA: entity {} not a capability: {}", self.id, kind)),
         }
 
-        Ok(None)
+        Ok(selector.select(relationships))
     }
 
     fn get_tosca_source_node(&self) -> Result<Entity, String> {
         match self.get_tosca_entity_kind()?.as_str() {
-            "RequirementAssignment" => {
+            REQUIREMENT_NAME => {
                 let vertex = self.get_source_vertex()?;
                 match vertex.get_tosca_entity_kind()?.as_str() {
-                    "NodeTemplate" => Ok(vertex),
+                    NODE_TEMPLATE_NAME => Ok(vertex),
 
                     kind => {
                         Err(format!("TOSCA: relationship {} source {} not a TOSCA node: {}", self.id, vertex.id, kind))
@@ -231,10 +345,10 @@ impl ToscaEntity for Entity {
 
     fn get_tosca_target_capability(&self) -> Result<Entity, String> {
         match self.get_tosca_entity_kind()?.as_str() {
-            "RequirementAssignment" => {
+            REQUIREMENT_NAME => {
                 let vertex = self.get_target_vertex()?;
                 match vertex.get_tosca_entity_kind()?.as_str() {
-                    "CapabilityAssignment" => Ok(vertex),
+                    CAPABILITY_NAME => Ok(vertex),
 
                     kind => Err(format!(
                         "TOSCA: relationship {} target {} not a TOSCA capability: {}",
@@ -247,19 +361,80 @@ impl ToscaEntity for Entity {
         }
     }
 
+    fn get_tosca_allocation(&self, property_name: &str) -> Result<Option<Expression>, String> {
+        match self.get_tosca_entity_kind()?.as_str() {
+            REQUIREMENT_NAME => match traverse!(self.expression, "metadata", "tosca:allocation") {
+                Some(Expression::Map(map_resource)) => {
+                    let allocation = map_resource.map();
+                    Ok(allocation.into_get(property_name).cloned())
+                }
+
+                Some(_) => Err(format!(
+                    "TOSCA: relationship {} has malformed \"tosca:allocation\" metadata, not a map",
+                    self.id
+                )),
+
+                None => Ok(None),
+            },
+
+            kind => return Err(format!("TOSCA: entity {} not a TOSCA relationship: {}", self.id, kind)),
+        }
+    }
+
+    fn get_tosca_capacity(&self, property_name: &str) -> Result<Expression, String> {
+        match self.get_tosca_entity_kind()?.as_str() {
+            CAPABILITY_NAME => {
+                let property = self
+                    .get_property(property_name)?
+                    .ok_or_else(|| format!("TOSCA: capability {} missing property: {}", self.id, property_name))?;
+
+                property.value().cloned().ok_or_else(|| {
+                    format!("TOSCA: capability {} has no value for property: {}", self.id, property_name)
+                })
+            }
+
+            kind => return Err(format!("TOSCA: entity {} not a TOSCA capability: {}", self.id, kind)),
+        }
+    }
+
+    fn get_tosca_available_allocation(&self, property_name: &str) -> Result<Operand<Scalar>, String> {
+        let capacity = self.get_tosca_capacity(property_name)?;
+
+        let mut allocations = Vec::default();
+        for incoming_edge_id in self.get_incoming_edge_ids()? {
+            let relationship = Entity::get(&incoming_edge_id)?;
+            if let Some(allocation) = relationship.get_tosca_allocation(property_name)? {
+                let allocation =
+                    Operand::new(allocation.coerce_if_custom(&capacity)?.into_value()?).map_err(|error| {
+                        format!("TOSCA: relationship {} allocation {} {}", relationship.id, property_name, error)
+                    })?;
+
+                allocations.push(allocation);
+            }
+        }
+
+        let capacity = Operand::new(capacity.into_value()?)
+            .map_err(|error| format!("TOSCA: capability {} property {} {}", self.id, property_name, error))?;
+
+        subtract_allocations(capacity, allocations)
+    }
+
     fn find_tosca_service_id(&self) -> Result<Id, String> {
         match self.get_tosca_entity_kind()?.as_str() {
-            "NodeTemplate" => self
+            SERVICE_TEMPLATE_NAME => Ok(self.id.clone()),
+
+            NODE_TEMPLATE_NAME | POLICY_TEMPLATE_NAME => self
                 .get_containing_vertex_id()?
-                .ok_or_else(|| format!("TOSCA: node {} missing containing node", self.id)),
-            "CapabilityAssignment" => {
+                .ok_or_else(|| format!("TOSCA: entity {} missing containing node", self.id)),
+
+            CAPABILITY_NAME | ARTIFACT_NAME => {
                 let node = self
                     .get_containing_vertex()?
-                    .ok_or_else(|| format!("TOSCA: capability {} missing containing node", self.id))?;
+                    .ok_or_else(|| format!("TOSCA: entity {} missing containing node", self.id))?;
                 node.find_tosca_service_id()
             }
 
-            "RequirementAssignment" => self.get_source_vertex()?.find_tosca_service_id(),
+            REQUIREMENT_NAME => self.get_source_vertex()?.find_tosca_service_id(),
 
             kind => return Err(format!("TOSCA: entity {} incompatible: {}", self.id, kind)),
         }
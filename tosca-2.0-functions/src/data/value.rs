@@ -0,0 +1,58 @@
+use super::scalar::*;
+
+use {floria_plugin_sdk::data::*, puccini_tosca_2_0_core::*, std::collections::*};
+
+//
+// IntoExpression
+//
+
+/// Into an expression.
+///
+/// For types of the core crate, for which we cannot implement [Into].
+pub trait IntoExpression {
+    /// Into an expression.
+    fn into_expression(self) -> Expression;
+}
+
+impl IntoExpression for Value<Expression> {
+    fn into_expression(self) -> Expression {
+        match self {
+            Self::Null => Expression::Null,
+            Self::Integer(integer) => integer.into(),
+            Self::UnsignedInteger(unsigned_integer) => unsigned_integer.into(),
+            Self::Float(float) => float.into(),
+            Self::Boolean(boolean) => boolean.into(),
+            Self::Text(text) => text.into(),
+            Self::Bytes(bytes) => Expression::Blob(bytes),
+
+            Self::List(list) => {
+                let list: Vec<_> = list.into_iter().map(|item| item.into_expression()).collect();
+                list.into()
+            }
+
+            Self::Map(map) => {
+                let map: BTreeMap<_, _> =
+                    map.into_iter().map(|(key, value)| (key.into_expression(), value.into_expression())).collect();
+                map.into()
+            }
+
+            Self::Custom(custom) => custom,
+        }
+    }
+}
+
+impl IntoExpression for Number {
+    fn into_expression(self) -> Expression {
+        match self {
+            Self::Integer(integer) => integer.into(),
+            Self::UnsignedInteger(unsigned_integer) => unsigned_integer.into(),
+            Self::Float(float) => float.into(),
+        }
+    }
+}
+
+impl IntoExpression for Operand<Scalar> {
+    fn into_expression(self) -> Expression {
+        self.into_value().into_expression()
+    }
+}
@@ -1,11 +0,0 @@
-mod ceil;
-mod difference;
-mod floor;
-mod product;
-mod quotient;
-mod remainder;
-mod round;
-mod sum;
-
-#[allow(unused_imports)]
-pub use {ceil::*, difference::*, floor::*, product::*, quotient::*, remainder::*, round::*, sum::*};
@@ -1,10 +1,5 @@
-mod equal;
-mod greater_or_equal;
-mod greater_than;
-mod less_or_equal;
-mod less_than;
 mod matches;
 mod valid_values;
 
 #[allow(unused_imports)]
-pub use {equal::*, greater_or_equal::*, greater_than::*, less_or_equal::*, less_than::*, matches::*, valid_values::*};
+pub use {matches::*, valid_values::*};
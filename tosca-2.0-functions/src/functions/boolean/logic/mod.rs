@@ -1,7 +0,0 @@
-mod and;
-mod not;
-mod or;
-mod xor;
-
-#[allow(unused_imports)]
-pub use {and::*, not::*, or::*, xor::*};
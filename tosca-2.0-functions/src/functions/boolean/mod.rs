@@ -1,6 +1,5 @@
 mod collection;
 mod comparison;
-mod logic;
 
 #[allow(unused_imports)]
-pub use {collection::*, comparison::*, logic::*};
+pub use {collection::*, comparison::*};
@@ -1,7 +0,0 @@
-mod concat;
-mod join;
-mod length;
-mod token;
-
-#[allow(unused_imports)]
-pub use {concat::*, join::*, length::*, token::*};
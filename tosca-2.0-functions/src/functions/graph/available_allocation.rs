@@ -1,3 +1,5 @@
+use super::{super::super::data::*, tosca_path::*};
+
 use floria_plugin_sdk::data::*;
 
 /// The $available_allocation function is used to retrieve the available allocation for capablity
@@ -5,6 +7,21 @@ use floria_plugin_sdk::data::*;
 /// to use this function within the condition clause in a node_filter of a node with a select
 /// directive; this allows to select only nodes that have a certain available capacity that for
 /// example can accomodate the expected allocations when used as a target for a relationship.
-pub fn available_allocation(_arguments: Vec<Expression>, _call_site: CallSite) -> Result<Option<Expression>, String> {
-    Ok(None)
+pub fn available_allocation(arguments: Vec<Expression>, call_site: CallSite) -> Result<Option<Expression>, String> {
+    let site = call_site.entity()?;
+
+    let mut parser = EntityGraph::parser(&arguments);
+    let capability = parser.next_site(site)?;
+
+    let argument = parser.next_argument().ok_or_else(|| "invalid TOSCA path: missing property name")?;
+    let Expression::Text(property_name) = argument else {
+        return Err(format!("invalid TOSCA path: property name not a string: {}", argument.type_name()));
+    };
+
+    if let Some(argument) = parser.next_argument() {
+        return Err(format!("invalid TOSCA path: unexpected argument after property name: {}", argument));
+    }
+
+    let available = capability.get_tosca_available_allocation(property_name)?;
+    Ok(Some(available.into_expression()))
 }
@@ -1,7 +1,113 @@
-use floria_plugin_sdk::data::*;
+use super::{super::super::data::*, tosca_path::*};
+
+use {
+    floria_plugin_sdk::{data::*, traverse},
+    puccini_tosca_2_0_core::*,
+    std::{fs, io::Write, path::*},
+};
+
+const LOCAL_FILE: &str = "LOCAL_FILE";
 
 /// The $get_artifact function is used to retrieve the location of artifacts defined by modelable
 /// entities in a service template.
-pub fn get_artifact(_arguments: Vec<Expression>, _call_site: CallSite) -> Result<Option<Expression>, String> {
-    Ok(None)
+///
+/// Arguments:
+///
+/// * The TOSCA Path to the node containing the artifact.
+/// * The name of the artifact definition.
+/// * Optional location: either a path or "LOCAL_FILE". If LOCAL_FILE, the artifact is copied to
+///   the local deployment area and the path is returned. It will be removed at the end of the
+///   operation. If a path, the artifact is copied there and it will be left in place.
+/// * Optional remove flag, overriding the default removal behavior for the location.
+pub fn get_artifact(arguments: Vec<Expression>, call_site: CallSite) -> Result<Option<Expression>, String> {
+    let site = call_site.entity()?;
+
+    let mut parser = EntityGraph::parser(&arguments);
+    let node = parser.next_site(site)?;
+
+    let argument = parser.next_argument().ok_or_else(|| "missing artifact name")?;
+    let Expression::Text(artifact_name) = argument else {
+        return Err(format!("artifact name not a string: {}", argument.type_name()));
+    };
+
+    let artifact =
+        node.get_tosca_artifact(artifact_name)?.ok_or_else(|| format!("artifact not found: {}", artifact_name))?;
+    let location = artifact.get_tosca_location()?;
+
+    let Some(argument) = parser.next_argument() else {
+        return Ok(Some(location.into()));
+    };
+
+    let Expression::Text(target) = argument else {
+        return Err(format!("location not a string: {}", argument.type_name()));
+    };
+
+    let remove = match parser.next_argument() {
+        Some(Expression::Boolean(remove)) => *remove,
+        Some(argument) => return Err(format!("remove not a boolean: {}", argument.type_name())),
+        None => target == LOCAL_FILE,
+    };
+
+    if let Some(argument) = parser.next_argument() {
+        return Err(format!("unexpected argument after remove: {}", argument));
+    }
+
+    let area = artifacts_directory(&node)?;
+    let target = if target == LOCAL_FILE {
+        let file_name = Path::new(&location)
+            .file_name()
+            .ok_or_else(|| format!("artifact location has no file name: {}", location))?;
+        area.join(artifact.id.to_string().replace(['/', ':'], "_")).join(file_name)
+    } else {
+        // Relative paths are in the deployment area
+        area.join(target)
+    };
+
+    stage(&location, &target)?;
+
+    if remove {
+        schedule_removal(&area, &target)?;
+    }
+
+    Ok(Some(target.display().to_string().into()))
+}
+
+/// Local artifact deployment area.
+///
+/// Set by the host in the service metadata, otherwise the default.
+pub fn artifacts_directory(entity: &Entity) -> Result<PathBuf, String> {
+    let service = entity.find_tosca_service()?;
+    Ok(match traverse!(service.expression, "metadata", ARTIFACTS_METADATA_KEY) {
+        Some(Expression::Text(directory)) => directory.into(),
+        _ => default_artifacts_path(),
+    })
+}
+
+// Copy artifact file
+fn stage(location: &str, target: &Path) -> Result<(), String> {
+    let source = location.strip_prefix("file://").unwrap_or(location);
+    if source.contains("://") {
+        return Err(format!("cannot stage remote artifact: {}", location));
+    }
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|error| format!("{}: {}", parent.display(), error))?;
+    }
+
+    fs::copy(source, target).map_err(|error| format!("staging artifact {}: {}", location, error))?;
+
+    Ok(())
+}
+
+// Append to teardown file
+fn schedule_removal(area: &Path, target: &Path) -> Result<(), String> {
+    let teardown_path = area.join(ARTIFACTS_TEARDOWN_FILE_NAME);
+
+    let mut teardown = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&teardown_path)
+        .map_err(|error| format!("{}: {}", teardown_path.display(), error))?;
+
+    writeln!(teardown, "{}", target.display()).map_err(|error| format!("{}: {}", teardown_path.display(), error))
 }
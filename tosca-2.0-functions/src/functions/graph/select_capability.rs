@@ -2,6 +2,7 @@ use super::super::super::data::*;
 
 use {
     floria_plugin_sdk::{data::*, log},
+    puccini_tosca_2_0_core::*,
     std::fmt,
 };
 
@@ -14,18 +15,23 @@ pub fn select_capability(arguments: Vec<Expression>, call_site: CallSite) -> Res
     let site = call_site.entity()?;
     let service = site.find_tosca_service()?;
 
+    let mut over_capacity = None;
+
     let mut index = 0;
     for vertex_id in service.get_contained_vertex_ids()? {
         let node = Entity::get(&vertex_id)?;
-        if node.get_tosca_entity_kind()? == "NodeTemplate" {
+        if node.get_tosca_entity_kind()? == NODE_TEMPLATE_NAME {
             let (node_type_matches, node_template_matches, index_matches) = constraints.match_node(&node, index)?;
 
             if node_type_matches && node_template_matches && index_matches {
                 for vertex_id in node.get_contained_vertex_ids()? {
                     let vertex = Entity::get(&vertex_id)?;
-                    if vertex.get_tosca_entity_kind()? == "CapabilityAssignment" {
+                    if vertex.get_tosca_entity_kind()? == CAPABILITY_NAME {
                         if constraints.match_capability(&vertex)? {
-                            return Ok(Some(vertex.id.to_string().into()));
+                            match constraints.match_allocation(&vertex)? {
+                                None => return Ok(Some(vertex.id.to_string().into())),
+                                Some(reason) => over_capacity = Some(reason),
+                            }
                         }
                     }
                 }
@@ -37,7 +43,10 @@ pub fn select_capability(arguments: Vec<Expression>, call_site: CallSite) -> Res
         }
     }
 
-    Err(format!("capability not found: {}", constraints))
+    Err(match over_capacity {
+        Some(reason) => format!("capability over capacity: {}: {}", constraints, reason),
+        None => format!("capability not found: {}", constraints),
+    })
 }
 
 //
@@ -50,7 +59,9 @@ struct CapabilityConstraints<'own> {
     capability_name: Option<&'own str>,
 
     node_type_id: Option<Id>,
-    node_template: Option<(&'own str, usize)>,
+    node_template: Option<(&'own str, Option<usize>)>,
+
+    allocation: Vec<(String, Expression)>,
 }
 
 impl<'own> CapabilityConstraints<'own> {
@@ -90,10 +101,7 @@ impl<'own> CapabilityConstraints<'own> {
                     if let Some(node_type_name) = argument.into_get("node_type_name") {
                         match node_type_name {
                             Expression::Text(node_type_name) => {
-                                let mut id = Id::parse(Kind::Class, node_type_name);
-                                id.directory.insert(0, "node".into());
-                                id.directory.insert(0, "tosca".into());
-                                find_node.node_type_id = Some(id)
+                                find_node.node_type_id = Some(Id::parse(Kind::Class, node_type_name))
                             }
 
                             _ => {
@@ -105,20 +113,18 @@ impl<'own> CapabilityConstraints<'own> {
                     if let Some(node_template_name) = argument.into_get("node_template_name") {
                         match node_template_name {
                             Expression::Text(node_template_name) => {
-                                let node_template_index = argument
-                                    .inner
-                                    .get(&"node_template_index".into())
-                                    .ok_or_else(|| "missing node_template_index")?;
-
-                                let node_template_index = match node_template_index {
-                                    Expression::Integer(integer) => *integer as usize,
-                                    Expression::UnsignedInteger(unsigned_integer) => *unsigned_integer as usize,
-                                    _ => {
+                                let node_template_index = match argument.inner.get(&"node_template_index".into()) {
+                                    Some(Expression::Integer(integer)) => Some(*integer as usize),
+                                    Some(Expression::UnsignedInteger(unsigned_integer)) => {
+                                        Some(*unsigned_integer as usize)
+                                    }
+                                    Some(node_template_index) => {
                                         return Err(format!(
                                             "node_template_index not an integer: {}",
                                             node_template_index.type_name()
                                         ));
                                     }
+                                    None => None,
                                 };
 
                                 find_node.node_template = Some((node_template_name, node_template_index));
@@ -132,6 +138,31 @@ impl<'own> CapabilityConstraints<'own> {
                             }
                         }
                     }
+
+                    if let Some(allocation) = argument.into_get("allocation") {
+                        match allocation {
+                            Expression::Map(allocation) => {
+                                for (property_name, value) in &allocation.map().inner {
+                                    match property_name {
+                                        Expression::Text(property_name) => {
+                                            find_node.allocation.push((property_name.clone(), value.clone()))
+                                        }
+
+                                        _ => {
+                                            return Err(format!(
+                                                "allocation key not a string: {}",
+                                                property_name.type_name()
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+
+                            _ => {
+                                return Err(format!("allocation not a map: {}", allocation.type_name()));
+                            }
+                        }
+                    }
                 }
 
                 _ => {
@@ -159,6 +190,22 @@ impl<'own> CapabilityConstraints<'own> {
         Ok(capability_matches)
     }
 
+    // Returns the reason if the allocation is over the available capacity
+    fn match_allocation(&self, entity: &Entity) -> Result<Option<String>, String> {
+        for (property_name, allocation) in &self.allocation {
+            let capacity = entity.get_tosca_capacity(property_name)?;
+            let allocation = Operand::new(allocation.clone().coerce_if_custom(&capacity)?.into_value()?)
+                .map_err(|error| format!("allocation {} {}", property_name, error))?;
+
+            let available = entity.get_tosca_available_allocation(property_name)?;
+            if allocate(&available, &allocation)?.is_none() {
+                return Ok(Some(format!("{} allocation {} > available {}", property_name, allocation, available)));
+            }
+        }
+
+        Ok(None)
+    }
+
     fn match_node(&self, entity: &Entity, index: usize) -> Result<(bool, bool, bool), String> {
         let node_type_matches = match &self.node_type_id {
             Some(node_type_id) => entity.is_in_class(node_type_id)?,
@@ -169,7 +216,7 @@ impl<'own> CapabilityConstraints<'own> {
             match self.node_template {
                 Some((node_template_name, index_)) => {
                     if entity.is_tosca_node(node_template_name)? {
-                        (true, index == index_)
+                        (true, index_.is_none_or(|index_| index == index_))
                     } else {
                         (false, false)
                     }
@@ -214,7 +261,24 @@ impl<'own> fmt::Display for CapabilityConstraints<'own> {
             if separate {
                 write!(formatter, ", ")?;
             }
-            write!(formatter, "node_template: {} {}", node_template, index)?;
+            separate = true;
+            write!(formatter, "node_template: {}", node_template)?;
+            if let Some(index) = index {
+                write!(formatter, " {}", index)?;
+            }
+        }
+
+        if !self.allocation.is_empty() {
+            if separate {
+                write!(formatter, ", ")?;
+            }
+            write!(formatter, "allocation: ")?;
+            for (index, (property_name, allocation)) in self.allocation.iter().enumerate() {
+                if index != 0 {
+                    write!(formatter, " ")?;
+                }
+                write!(formatter, "{}={}", property_name, allocation)?;
+            }
         }
 
         Ok(())
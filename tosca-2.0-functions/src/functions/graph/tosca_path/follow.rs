@@ -1,23 +1,23 @@
 #![allow(unused)]
 
-use super::parser::*;
+use super::graph::*;
 
 use floria_plugin_sdk::data::*;
 
 /// Follow TOSCA path.
-pub fn follow_tosca_path(path_site: Entity, arguments: &Vec<Expression>) -> Result<Entity, String> {
-    let mut parser = ToscaPathParser::new(arguments);
-    parser.next_site(path_site)
+pub fn follow_tosca_path(path_site: Entity, arguments: &[Expression]) -> Result<Entity, String> {
+    EntityGraph::parser(arguments).next_site(path_site)
 }
 
 /// Follow TOSCA path to property value.
+///
+/// If the path uses an `ALL` selector, the result is a list of the values.
 pub fn follow_tosca_path_to_property_value(
     path_site: Entity,
-    arguments: &Vec<Expression>,
+    arguments: &[Expression],
     read_only: bool,
 ) -> Result<Expression, String> {
-    let mut parser = ToscaPathParser::new(arguments);
-    let site = parser.next_site(path_site)?;
-    let property = parser.next_property(&site, read_only)?;
-    parser.next_expression(&property)
+    let mut parser = EntityGraph::parser(arguments);
+    let sites = parser.next_sites(path_site)?;
+    parser.next_property_values(&sites, read_only)
 }
@@ -0,0 +1,106 @@
+use super::super::super::super::data::*;
+
+use {floria_plugin_sdk::data::*, puccini_tosca_2_0_core::*};
+
+//
+// EntityGraph
+//
+
+/// [ToscaPathGraph] over Floria entities.
+pub struct EntityGraph;
+
+impl EntityGraph {
+    /// TOSCA Path parser.
+    pub fn parser<'own>(arguments: &'own [Expression]) -> ToscaPathParser<'own, Self> {
+        ToscaPathParser::new(&EntityGraph, arguments)
+    }
+}
+
+impl ToscaPathGraph for EntityGraph {
+    type Site = Entity;
+    type Value = Expression;
+
+    fn site_kind(&self, site: &Entity) -> Result<ToscaPathSiteKind, String> {
+        Ok(match site.id.kind {
+            Kind::Vertex => ToscaPathSiteKind::Node,
+            Kind::Edge => ToscaPathSiteKind::Relationship,
+            kind => ToscaPathSiteKind::Other(kind.to_string()),
+        })
+    }
+
+    fn find_nodes(
+        &self,
+        site: &Entity,
+        node_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
+        site.find_tosca_nodes(node_template_name, selector)
+    }
+
+    fn find_relationships(
+        &self,
+        site: &Entity,
+        relationship_template_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
+        site.find_tosca_relationships(relationship_template_name, selector)
+    }
+
+    fn outgoing_relationships(
+        &self,
+        site: &Entity,
+        requirement_name: &str,
+        selector: ToscaInstanceSelector,
+    ) -> Result<Vec<Entity>, String> {
+        site.get_tosca_outgoing_relationships(requirement_name, selector)
+    }
+
+    fn capability(&self, site: &Entity, capability_name: &str) -> Result<Option<Entity>, String> {
+        site.get_tosca_capability(capability_name)
+    }
+
+    fn incoming_relationships(&self, site: &Entity, selector: ToscaInstanceSelector) -> Result<Vec<Entity>, String> {
+        site.get_tosca_incoming_relationships(selector)
+    }
+
+    fn source_node(&self, site: &Entity) -> Result<Entity, String> {
+        site.get_tosca_source_node()
+    }
+
+    fn target_node(&self, site: &Entity) -> Result<Entity, String> {
+        site.get_tosca_target_node()
+    }
+
+    fn target_capability(&self, site: &Entity) -> Result<Entity, String> {
+        site.get_tosca_target_capability()
+    }
+
+    fn property(&self, site: &Entity, property_name: &str) -> Result<Option<ToscaPathProperty<Expression>>, String> {
+        Ok(match site.get_property(property_name)? {
+            Some(property) => {
+                Some(ToscaPathProperty { value: property.value().cloned(), read_only: property.is_read_only()? })
+            }
+
+            None => None,
+        })
+    }
+
+    fn get(&self, value: &Expression, key: &Expression) -> Option<Expression> {
+        value.get(key).cloned()
+    }
+
+    fn as_text<'value>(&self, value: &'value Expression) -> Option<&'value str> {
+        match value {
+            Expression::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn as_number(&self, value: &Expression) -> Option<Number> {
+        value.to_number().ok()
+    }
+
+    fn type_name(&self, value: &Expression) -> String {
+        value.type_name().into()
+    }
+}
@@ -1,5 +1,5 @@
 mod follow;
-mod parser;
+mod graph;
 
 #[allow(unused_imports)]
-pub use {follow::*, parser::*};
+pub use {follow::*, graph::*};
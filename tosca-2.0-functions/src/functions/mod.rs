@@ -1,9 +1,6 @@
-mod arithmetic;
 mod boolean;
-mod collection;
 mod graph;
 mod puccini;
-mod set;
 
 #[allow(unused_imports)]
-pub use {arithmetic::*, boolean::*, collection::*, graph::*, puccini::*, set::*};
+pub use {boolean::*, graph::*, puccini::*};
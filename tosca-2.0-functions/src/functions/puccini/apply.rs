@@ -11,10 +11,16 @@ use floria_plugin_sdk::data::*;
 /// Value-less expressions will not affect the call site value, however they can still return an
 /// error, for example if the value is invalid.
 pub fn apply(arguments: Vec<Expression>, call_site: CallSite) -> Result<Option<Expression>, String> {
-    set_call_site_override(None)?;
+    // Nested applications (e.g. in schema validators) must not clobber the outer value
+    let outer_override = get_call_site_override()?;
+    let result = apply_all(arguments, &call_site);
+    set_call_site_override(outer_override)?;
+    result
+}
 
+fn apply_all(arguments: Vec<Expression>, call_site: &CallSite) -> Result<Option<Expression>, String> {
     for preparer in arguments {
-        if let Some(expression) = preparer.evaluate(&call_site)? {
+        if let Some(expression) = preparer.evaluate(call_site)? {
             set_call_site_override(Some(expression.clone()))?;
         }
     }
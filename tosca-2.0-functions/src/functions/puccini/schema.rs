@@ -1,18 +1,47 @@
 use super::super::super::data::*;
 
-use floria_plugin_sdk::data::*;
+use {
+    floria_plugin_sdk::data::*,
+    puccini_tosca_2_0_core::{LocatedError, SourceLocation},
+};
 
 /// Coerce to a schema.
 ///
 /// If the value is valid, meaning that it adheres to the schema, will return a canonicalized
 /// version of the value, or [None] if already canonical. Otherwise, will return an error.
+///
+/// The optional third argument is the root of the value path used in errors (e.g.
+/// `properties.ports`), and the optional fourth argument is the source location of the value.
+/// When there is a source location the errors are [LocatedError]s of kind `coerce`, so that the host
+/// can map them back to the TOSCA source.
 pub fn schema(mut arguments: Vec<Expression>, call_site: CallSite) -> Result<Option<Expression>, String> {
-    if arguments.len() != 2 {
-        return Err("must have two arguments".into());
+    let length = arguments.len();
+    if (length < 2) || (length > 4) {
+        return Err("must have two to four arguments".into());
     }
 
     let value = arguments.remove(0).evaluate(&call_site)?;
-    let schema: Schema = arguments.remove(0).must_evaluate(&call_site)?.try_into()?;
 
-    schema.coerce_option(value)
+    // Not evaluated, because validators must be evaluated only during coercion
+    let schema: Schema = arguments.remove(0).try_into()?;
+
+    let root = match arguments.first() {
+        Some(Expression::Text(root)) => Some(root.clone()),
+        Some(root) => return Err(format!("path not a string: {}", root.type_name())),
+        None => None,
+    };
+
+    let location: Option<SourceLocation> = match arguments.get(1) {
+        Some(Expression::Text(location)) => Some(location.parse()?),
+        Some(location) => return Err(format!("location not a string: {}", location.type_name())),
+        None => None,
+    };
+
+    schema.coerce_option(value, &call_site).map_err(|error| {
+        let message = error.into_message(root.as_deref());
+        match location {
+            Some(location) => LocatedError::new(location, "coerce".into(), message).to_string(),
+            None => message,
+        }
+    })
 }
@@ -1,5 +0,0 @@
-mod intersection;
-mod union;
-
-#[allow(unused_imports)]
-pub use {intersection::*, union::*};
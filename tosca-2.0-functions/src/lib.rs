@@ -9,6 +9,7 @@ TOSCA 2.0 built-in functions.
 mod data;
 mod dispatcher;
 mod functions;
+mod trace;
 
 #[allow(unused_imports)]
-pub use dispatcher::*;
+pub use {dispatcher::*, trace::*};
@@ -0,0 +1,95 @@
+use super::data::*;
+
+use {
+    floria_plugin_sdk::{data::*, traverse},
+    puccini_tosca_2_0_core::*,
+    std::{
+        fs,
+        io::Write,
+        sync::{atomic::*, *},
+    },
+};
+
+static INDEX: AtomicUsize = AtomicUsize::new(0);
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+/// Dispatch with tracing, if enabled.
+pub fn trace<DispatchT>(
+    name: String,
+    arguments: Vec<Expression>,
+    call_site: CallSite,
+    dispatch: DispatchT,
+) -> Result<Option<Expression>, String>
+where
+    DispatchT: FnOnce(String, Vec<Expression>, CallSite) -> Result<Option<Expression>, String>,
+{
+    let Some(path) = trace_path(&call_site) else {
+        return dispatch(name, arguments, call_site);
+    };
+
+    // Index is in call order, so that nested calls come after their parent
+    let index = INDEX.fetch_add(1, Ordering::SeqCst);
+    let depth = DEPTH.fetch_add(1, Ordering::SeqCst);
+
+    let entity = call_site.entity().map(|entity| entity.id.to_string()).unwrap_or_default();
+    let property = call_site.path.join(".");
+    let mut fields = vec![index.to_string(), depth.to_string(), name.clone(), entity, property];
+    let arguments_fields: Vec<_> = arguments.iter().map(|argument| argument.to_string()).collect();
+
+    let result = dispatch(name, arguments, call_site);
+    DEPTH.fetch_sub(1, Ordering::SeqCst);
+
+    match &result {
+        Ok(value) => {
+            fields.push("ok".into());
+            fields.push(value.as_ref().map(|value| value.to_string()).unwrap_or_default());
+        }
+
+        Err(error) => {
+            fields.push("error".into());
+            fields.push(error.clone());
+        }
+    }
+
+    fields.extend(arguments_fields);
+    let line: Vec<_> = fields.iter().map(|field| escape(field)).collect();
+
+    // Tracing must not affect the result, so we ignore errors
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(path) {
+        _ = writeln!(file, "{}", line.join("\t"));
+    }
+
+    result
+}
+
+// The path is in the service metadata; once found it is kept for the lifetime of the plugin
+fn trace_path(call_site: &CallSite) -> Option<String> {
+    static TRACE_PATH: OnceLock<Option<String>> = OnceLock::new();
+
+    if let Some(path) = TRACE_PATH.get() {
+        return path.clone();
+    }
+
+    // Tracing must not affect the result, so we ignore errors (and try again at the next call)
+    let service = call_site.entity().ok()?.find_tosca_service().ok()?;
+    let path = match traverse!(service.expression, "metadata", TRACE_FUNCTIONS_METADATA_KEY) {
+        Some(Expression::Text(path)) => Some(path.clone()),
+        _ => None,
+    };
+
+    TRACE_PATH.get_or_init(|| path).clone()
+}
+
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for character in field.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,427 @@
+use super::{call::*, expression::*};
+
+use compris::normal::*;
+
+//
+// ArgumentKind
+//
+
+/// Argument kind for [BuiltinSignature].
+///
+/// Only literal arguments can be checked at compile time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgumentKind {
+    /// Any.
+    Any,
+
+    /// Boolean.
+    Boolean,
+
+    /// String.
+    String,
+
+    /// Integer.
+    Integer,
+
+    /// Integer or float.
+    Number,
+
+    /// Integer, float, or scalar.
+    Numeric,
+
+    /// List.
+    List,
+
+    /// Map.
+    Map,
+
+    /// String or list.
+    StringOrList,
+
+    /// List or map.
+    ListOrMap,
+
+    /// String, list, or bytes.
+    StringListOrBytes,
+
+    /// String, list, map, or bytes.
+    StringListMapOrBytes,
+
+    /// First element of a TOSCA Path.
+    PathRoot,
+
+    /// Element of a TOSCA Path after the first.
+    PathSegment,
+}
+
+impl ArgumentKind {
+    /// As string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Boolean => "a boolean",
+            Self::String => "a string",
+            Self::Integer => "an integer",
+            Self::Number => "an integer or a float",
+            Self::Numeric => "an integer, a float, or a scalar",
+            Self::List => "a list",
+            Self::Map => "a map",
+            Self::StringOrList => "a string or a list",
+            Self::ListOrMap => "a list or a map",
+            Self::StringListOrBytes => "a string, a list, or bytes",
+            Self::StringListMapOrBytes => "a string, a list, a map, or bytes",
+            Self::PathRoot => "a string",
+            Self::PathSegment => "a string or an integer",
+        }
+    }
+
+    /// True if the literal is of this kind.
+    pub fn is_literal<AnnotatedT>(&self, literal: &Variant<AnnotatedT>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Boolean => matches!(literal, Variant::Boolean(_)),
+            Self::String | Self::PathRoot => matches!(literal, Variant::Text(_)),
+            Self::Integer => matches!(literal, Variant::Integer(_) | Variant::UnsignedInteger(_)),
+            Self::Number => matches!(literal, Variant::Integer(_) | Variant::UnsignedInteger(_) | Variant::Float(_)),
+            Self::Numeric => matches!(
+                literal,
+                Variant::Integer(_) | Variant::UnsignedInteger(_) | Variant::Float(_) | Variant::Text(_)
+            ),
+            Self::List => matches!(literal, Variant::List(_)),
+            Self::Map => matches!(literal, Variant::Map(_)),
+            Self::StringOrList => matches!(literal, Variant::Text(_) | Variant::List(_)),
+            Self::ListOrMap => matches!(literal, Variant::List(_) | Variant::Map(_)),
+            Self::StringListOrBytes => matches!(literal, Variant::Text(_) | Variant::List(_) | Variant::Blob(_)),
+            Self::StringListMapOrBytes => {
+                matches!(literal, Variant::Text(_) | Variant::List(_) | Variant::Map(_) | Variant::Blob(_))
+            }
+            Self::PathSegment => {
+                matches!(literal, Variant::Text(_) | Variant::Integer(_) | Variant::UnsignedInteger(_))
+            }
+        }
+    }
+}
+
+//
+// BuiltinSignature
+//
+
+/// Signature of a built-in function.
+///
+/// Should be kept in sync with the functions in the `tosca-2.0-functions` plugin.
+#[derive(Clone, Debug)]
+pub struct BuiltinSignature {
+    /// Function name.
+    pub name: &'static str,
+
+    /// Required arguments.
+    pub arguments: &'static [ArgumentKind],
+
+    /// Optional arguments.
+    pub optional_arguments: &'static [ArgumentKind],
+
+    /// True if the last argument may be repeated.
+    pub variadic: bool,
+
+    /// True if the arguments are a TOSCA Path.
+    pub tosca_path: bool,
+}
+
+impl BuiltinSignature {
+    /// Constructor.
+    pub const fn new(
+        name: &'static str,
+        arguments: &'static [ArgumentKind],
+        optional_arguments: &'static [ArgumentKind],
+        variadic: bool,
+    ) -> Self {
+        Self { name, arguments, optional_arguments, variadic, tosca_path: false }
+    }
+
+    /// Constructor.
+    pub const fn new_tosca_path(name: &'static str) -> Self {
+        Self {
+            name,
+            arguments: &[ArgumentKind::PathRoot, ArgumentKind::PathSegment],
+            optional_arguments: &[ArgumentKind::PathSegment],
+            variadic: true,
+            tosca_path: true,
+        }
+    }
+
+    /// Find a built-in signature.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        BUILTIN_SIGNATURES.iter().find(|signature| signature.name == name)
+    }
+
+    /// The argument kind at an index.
+    ///
+    /// Takes into account optional and variadic arguments.
+    pub fn argument_kind(&self, index: usize) -> Option<ArgumentKind> {
+        let length = self.arguments.len();
+        if index < length {
+            return Some(self.arguments[index]);
+        }
+
+        let optional_length = self.optional_arguments.len();
+        if index < length + optional_length {
+            return Some(self.optional_arguments[index - length]);
+        }
+
+        if self.variadic { self.optional_arguments.last().or_else(|| self.arguments.last()).copied() } else { None }
+    }
+
+    /// Validate a call.
+    ///
+    /// Returns the reason if invalid.
+    pub fn validate<AnnotatedT>(&self, call: &Call<AnnotatedT>) -> Result<(), String> {
+        let arity = call.arguments.len();
+        let minimum = self.arguments.len();
+        let maximum = minimum + self.optional_arguments.len();
+
+        if arity < minimum {
+            return Err(match minimum {
+                1 => "must have at least one argument".into(),
+                _ => format!("must have at least {} arguments", minimum),
+            });
+        }
+
+        if !self.variadic && (arity > maximum) {
+            return Err(match maximum {
+                0 => "must have no arguments".into(),
+                1 => "must have at most one argument".into(),
+                _ => format!("must have at most {} arguments", maximum),
+            });
+        }
+
+        for (index, argument) in call.arguments.iter().enumerate() {
+            if let Expression::Literal(literal) = argument
+                && let Some(argument_kind) = self.argument_kind(index)
+                && !argument_kind.is_literal(literal)
+            {
+                return Err(format!("argument {} not {}: {}", index, argument_kind.as_str(), literal));
+            }
+        }
+
+        if self.tosca_path {
+            validate_tosca_path(&call.arguments)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Built-in function signatures.
+pub const BUILTIN_SIGNATURES: &[BuiltinSignature] = &[
+    // Graph
+    BuiltinSignature::new("get_input", &[ArgumentKind::String], &[ArgumentKind::PathSegment], true),
+    BuiltinSignature::new_tosca_path("get_property"),
+    BuiltinSignature::new_tosca_path("get_attribute"),
+    // The TOSCA Path is followed by the artifact name, and the optional location and remove arguments
+    BuiltinSignature {
+        name: "get_artifact",
+        arguments: &[ArgumentKind::PathRoot, ArgumentKind::Any],
+        optional_arguments: &[ArgumentKind::Any],
+        variadic: true,
+        tosca_path: true,
+    },
+    BuiltinSignature::new("value", &[], &[], false),
+    BuiltinSignature::new("node_index", &[], &[], false),
+    BuiltinSignature::new("relationship_index", &[], &[], false),
+    BuiltinSignature::new_tosca_path("available_allocation"),
+    BuiltinSignature::new("select_capability", &[], &[ArgumentKind::Map], false),
+    // Boolean logic
+    BuiltinSignature::new("and", &[ArgumentKind::Boolean], &[ArgumentKind::Boolean], true),
+    BuiltinSignature::new("or", &[ArgumentKind::Boolean], &[ArgumentKind::Boolean], true),
+    BuiltinSignature::new("not", &[ArgumentKind::Boolean], &[], false),
+    BuiltinSignature::new("xor", &[ArgumentKind::Boolean, ArgumentKind::Boolean], &[], false),
+    // Boolean comparison
+    BuiltinSignature::new("equal", &[ArgumentKind::Any, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("greater_than", &[ArgumentKind::Any, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("greater_or_equal", &[ArgumentKind::Any, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("less_than", &[ArgumentKind::Any, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("less_or_equal", &[ArgumentKind::Any, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("valid_values", &[ArgumentKind::Any, ArgumentKind::List], &[], false),
+    BuiltinSignature::new("matches", &[ArgumentKind::String, ArgumentKind::String], &[], false),
+    // Boolean collection
+    BuiltinSignature::new("has_suffix", &[ArgumentKind::StringOrList, ArgumentKind::StringOrList], &[], false),
+    BuiltinSignature::new("has_prefix", &[ArgumentKind::StringOrList, ArgumentKind::StringOrList], &[], false),
+    BuiltinSignature::new("contains", &[ArgumentKind::StringOrList, ArgumentKind::StringOrList], &[], false),
+    BuiltinSignature::new("has_entry", &[ArgumentKind::ListOrMap, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("has_key", &[ArgumentKind::Map, ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("has_all_entries", &[ArgumentKind::ListOrMap, ArgumentKind::List], &[], false),
+    BuiltinSignature::new("has_all_keys", &[ArgumentKind::Map, ArgumentKind::List], &[], false),
+    BuiltinSignature::new("has_any_entry", &[ArgumentKind::ListOrMap, ArgumentKind::List], &[], false),
+    BuiltinSignature::new("has_any_key", &[ArgumentKind::Map, ArgumentKind::List], &[], false),
+    // Collection
+    BuiltinSignature::new("length", &[ArgumentKind::StringListMapOrBytes], &[], false),
+    BuiltinSignature::new("concat", &[ArgumentKind::StringListOrBytes], &[ArgumentKind::StringListOrBytes], true),
+    BuiltinSignature::new("join", &[ArgumentKind::List], &[ArgumentKind::String], false),
+    BuiltinSignature::new("token", &[ArgumentKind::String, ArgumentKind::String, ArgumentKind::Integer], &[], false),
+    // Set
+    BuiltinSignature::new("union", &[ArgumentKind::List], &[ArgumentKind::List], true),
+    BuiltinSignature::new("intersection", &[ArgumentKind::List], &[ArgumentKind::List], true),
+    // Arithmetic
+    BuiltinSignature::new("sum", &[ArgumentKind::Numeric], &[ArgumentKind::Numeric], true),
+    BuiltinSignature::new("difference", &[ArgumentKind::Numeric, ArgumentKind::Numeric], &[], false),
+    BuiltinSignature::new("product", &[ArgumentKind::Numeric, ArgumentKind::Numeric], &[ArgumentKind::Number], true),
+    BuiltinSignature::new("quotient", &[ArgumentKind::Numeric, ArgumentKind::Number], &[], false),
+    BuiltinSignature::new("remainder", &[ArgumentKind::Numeric, ArgumentKind::Integer], &[], false),
+    BuiltinSignature::new("round", &[ArgumentKind::Number], &[], false),
+    BuiltinSignature::new("floor", &[ArgumentKind::Number], &[], false),
+    BuiltinSignature::new("ceil", &[ArgumentKind::Number], &[], false),
+    // Puccini
+    BuiltinSignature::new("_evaluate", &[ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("_assert", &[ArgumentKind::Any], &[], false),
+    BuiltinSignature::new("_apply", &[], &[ArgumentKind::Any], true),
+    // The optional arguments are the root of the value path and the source location
+    BuiltinSignature::new(
+        "_schema",
+        &[ArgumentKind::Any, ArgumentKind::Any],
+        &[ArgumentKind::String, ArgumentKind::String],
+        false,
+    ),
+];
+
+// Reserved in TOSCA Paths, so they cannot be capability or requirement names
+const TOSCA_PATH_KEYWORDS: &[&str] = &["SELF", "SOURCE", "TARGET", "CAPABILITY", "RELATIONSHIP", "ALL"];
+
+// Keywords must be followed by a name or (for incoming relationships) an index
+fn validate_tosca_path<AnnotatedT>(arguments: &[Expression<AnnotatedT>]) -> Result<(), String> {
+    let mut iterator = arguments.iter().enumerate().peekable();
+
+    while let Some((index, argument)) = iterator.next() {
+        if let Expression::Literal(Variant::Text(text)) = argument {
+            let keyword: &str = &text.inner;
+            if matches!(keyword, "CAPABILITY" | "RELATIONSHIP") {
+                match iterator.peek() {
+                    Some((_, Expression::Literal(Variant::Text(name)))) => {
+                        if TOSCA_PATH_KEYWORDS.contains(&name.inner.as_str()) {
+                            return Err(format!(
+                                "invalid TOSCA path: {} at argument {} followed by a keyword instead of a name: {}",
+                                keyword, index, name.inner
+                            ));
+                        }
+                    }
+                    Some((_, Expression::Literal(Variant::Integer(_) | Variant::UnsignedInteger(_))))
+                        if keyword == "RELATIONSHIP" => {}
+                    Some((_, Expression::Literal(name))) => {
+                        return Err(format!(
+                            "invalid TOSCA path: {} at argument {} not followed by a name: {}",
+                            keyword, index, name
+                        ));
+                    }
+                    Some(_) => {}
+                    None => {
+                        return Err(format!(
+                            "invalid TOSCA path: {} at argument {} not followed by a name",
+                            keyword, index
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, compris::annotate::*, kutil::std::immutable::*};
+
+    fn validate(function_name: &'static str, arguments: Vec<Expression<WithoutAnnotations>>) -> Result<(), String> {
+        let call = Call::new_native(ByteString::from_static(function_name).into(), arguments);
+        BuiltinSignature::find(function_name).expect("built-in").validate(&call)
+    }
+
+    #[test]
+    fn tosca_path() {
+        assert_eq!(validate("get_property", vec!["SELF".into(), "port".into()]), Ok(()));
+        assert_eq!(
+            validate("get_property", vec!["SELF".into(), "CAPABILITY".into(), "host".into(), "num_cpus".into()]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(
+                "get_attribute",
+                vec!["SELF".into(), "RELATIONSHIP".into(), "storage".into(), 0u64.into(), "size".into()]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate(
+                "get_attribute",
+                vec![
+                    "server".into(),
+                    1u64.into(),
+                    "RELATIONSHIP".into(),
+                    "storage".into(),
+                    "ALL".into(),
+                    "size".into()
+                ]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn tosca_path_keyword_as_name() {
+        assert_eq!(
+            validate(
+                "get_attribute",
+                vec![
+                    "server".into(),
+                    1u64.into(),
+                    "CAPABILITY".into(),
+                    "RELATIONSHIP".into(),
+                    0u64.into(),
+                    "size".into()
+                ]
+            ),
+            Err("invalid TOSCA path: CAPABILITY at argument 2 followed by a keyword instead of a name: RELATIONSHIP"
+                .into())
+        );
+
+        for keyword in TOSCA_PATH_KEYWORDS {
+            assert!(
+                validate("get_property", vec!["SELF".into(), "RELATIONSHIP".into(), (*keyword).into(), "size".into()])
+                    .is_err(),
+                "{}",
+                keyword
+            );
+        }
+    }
+
+    #[test]
+    fn tosca_path_keyword_without_name() {
+        assert_eq!(
+            validate("get_property", vec!["SELF".into(), "CAPABILITY".into()]),
+            Err("invalid TOSCA path: CAPABILITY at argument 1 not followed by a name".into())
+        );
+        assert_eq!(
+            validate("get_property", vec!["SELF".into(), "port".into(), "RELATIONSHIP".into()]),
+            Err("invalid TOSCA path: RELATIONSHIP at argument 2 not followed by a name".into())
+        );
+        assert!(matches!(
+            validate("get_property", vec!["SELF".into(), "CAPABILITY".into(), 0u64.into(), "num_cpus".into()]),
+            Err(reason) if reason.starts_with("invalid TOSCA path: CAPABILITY at argument 1 not followed by a name: ")
+        ));
+    }
+
+    #[test]
+    fn tosca_path_arguments() {
+        assert_eq!(validate("get_property", vec!["SELF".into()]), Err("must have at least 2 arguments".into()));
+        assert!(matches!(
+            validate("get_property", vec![0u64.into(), "port".into()]),
+            Err(reason) if reason.starts_with("argument 0 not a string: ")
+        ));
+    }
+
+    #[test]
+    fn core_functions_have_signatures() {
+        for function_name in puccini_tosca_2_0_core::CORE_FUNCTIONS {
+            assert!(BuiltinSignature::find(function_name).is_some(), "{}", function_name);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::super::super::super::grammar::*;
 
-use {kutil::std::immutable::*, std::fmt};
+use {compris::normal::*, kutil::std::immutable::*, std::fmt};
 
 //
 // DataKind
@@ -72,6 +72,28 @@ impl DataKind {
             DataKind::Struct => "struct",
         }
     }
+
+    /// True if the literal can represent this data kind.
+    ///
+    /// Kinds that are represented as strings or maps are only checked for that representation.
+    pub fn is_literal<AnnotatedT>(&self, literal: &Variant<AnnotatedT>) -> bool {
+        match self {
+            DataKind::String | DataKind::Timestamp | DataKind::Scalar => matches!(literal, Variant::Text(_)),
+            DataKind::Integer => matches!(literal, Variant::Integer(_) | Variant::UnsignedInteger(_)),
+            DataKind::Float => {
+                matches!(literal, Variant::Float(_) | Variant::Integer(_) | Variant::UnsignedInteger(_))
+            }
+            DataKind::Boolean => matches!(literal, Variant::Boolean(_)),
+            DataKind::Bytes => matches!(literal, Variant::Blob(_) | Variant::Text(_)),
+            DataKind::Nil => matches!(literal, Variant::Null(_)),
+            DataKind::Version => matches!(
+                literal,
+                Variant::Text(_) | Variant::Integer(_) | Variant::UnsignedInteger(_) | Variant::Float(_)
+            ),
+            DataKind::List => matches!(literal, Variant::List(_)),
+            DataKind::Map | DataKind::Struct => matches!(literal, Variant::Map(_)),
+        }
+    }
 }
 
 impl fmt::Display for DataKind {
@@ -1,6 +1,7 @@
 mod expression;
 mod floria;
 mod resolve;
+mod scalar;
 mod validation;
 
 #[allow(unused_imports)]
@@ -0,0 +1,145 @@
+use super::super::{call::*, expression::*};
+
+use {
+    compris::{annotate::*, normal::*},
+    kutil::std::immutable::*,
+    std::mem,
+};
+
+impl<AnnotatedT> Expression<AnnotatedT> {
+    /// The scalar schema of a validation, if it has one.
+    ///
+    /// It is the schema argument of a `$_schema` call, which may be in an `$_apply`.
+    pub fn scalar_schema(&self) -> Option<&Self> {
+        let Expression::Call(call) = self else {
+            return None;
+        };
+
+        if !call.is_native() {
+            return None;
+        }
+
+        match call.function.as_str() {
+            "_schema" => call.arguments.get(1).filter(|schema| is_scalar_schema(schema)),
+            "_apply" => call.arguments.iter().find_map(|argument| argument.scalar_schema()),
+            _ => None,
+        }
+    }
+
+    /// Coerce the string arguments of arithmetic calls through a scalar schema.
+    ///
+    /// The strings are wrapped in `$_schema` calls so that they are scalars in the schema's
+    /// canonical unit by the time the arithmetic function is called. Otherwise, e.g.
+    /// `$sum: [ "1 GB", "512 MB" ]` would have no scalar argument to coerce its strings into.
+    ///
+    /// Only the first argument of `$product`, `$quotient`, and `$remainder` can be a scalar.
+    pub fn coerce_scalar_arguments(&mut self, scalar_schema: &Self)
+    where
+        AnnotatedT: Annotated + Clone + Default,
+    {
+        let Expression::Call(call) = self else {
+            return;
+        };
+
+        if !call.is_native() {
+            return;
+        }
+
+        let scalar_arguments = match call.function.as_str() {
+            "sum" | "difference" => call.arguments.len(),
+            "product" | "quotient" | "remainder" => 1,
+            _ => return,
+        };
+
+        for argument in call.arguments.iter_mut().take(scalar_arguments) {
+            match argument {
+                Expression::Literal(Variant::Text(_)) => {
+                    let text = mem::take(argument);
+                    *argument = Call::new_native(
+                        Text::from(ByteString::from_static("_schema")).with_annotations_from(&text),
+                        vec![text, scalar_schema.clone()],
+                    )
+                    .into();
+                }
+
+                Expression::Call(_) => argument.coerce_scalar_arguments(scalar_schema),
+
+                _ => {}
+            }
+        }
+    }
+}
+
+fn is_scalar_schema<AnnotatedT>(schema: &Expression<AnnotatedT>) -> bool {
+    match schema {
+        Expression::Map(map) => map.iter().any(|(key, value)| {
+            matches!(
+                (key, value),
+                (Expression::Literal(Variant::Text(key)), Expression::Literal(Variant::Text(kind)))
+                    if (key.inner == "kind") && (kind.inner == "scalar")
+            )
+        }),
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::*};
+
+    fn call(
+        function_name: &'static str,
+        arguments: Vec<Expression<WithoutAnnotations>>,
+    ) -> Expression<WithoutAnnotations> {
+        Call::new_native(ByteString::from_static(function_name).into(), arguments).into()
+    }
+
+    fn bytes() -> Expression<WithoutAnnotations> {
+        let units = BTreeMap::from([("B".into(), 1u64.into()), ("GB".into(), 1_000_000_000u64.into())]);
+        BTreeMap::from([("kind".into(), "scalar".into()), ("units".into(), Expression::Map(units))]).into()
+    }
+
+    fn schema(value: &'static str) -> Expression<WithoutAnnotations> {
+        call("_schema", vec![value.into(), bytes()])
+    }
+
+    #[test]
+    fn scalar_schema() {
+        let validation = call("_schema", vec![call("value", vec![]), bytes()]);
+        assert_eq!(validation.scalar_schema(), Some(&bytes()));
+
+        let validation = call("_apply", vec![call("_assert", vec![]), validation]);
+        assert_eq!(validation.scalar_schema(), Some(&bytes()));
+
+        let validation = call("_schema", vec![call("value", vec![]), BTreeMap::default().into()]);
+        assert_eq!(validation.scalar_schema(), None);
+    }
+
+    #[test]
+    fn coerce_scalar_arguments() {
+        let mut sum = call("sum", vec!["1 GB".into(), "512 MB".into()]);
+        sum.coerce_scalar_arguments(&bytes());
+        assert_eq!(sum, call("sum", vec![schema("1 GB"), schema("512 MB")]));
+
+        let mut product = call("product", vec!["1 GB".into(), 2u64.into()]);
+        product.coerce_scalar_arguments(&bytes());
+        assert_eq!(product, call("product", vec![schema("1 GB"), 2u64.into()]));
+
+        let mut difference =
+            call("difference", vec![call("quotient", vec!["1 GB".into(), "2".into()]), "512 MB".into()]);
+        difference.coerce_scalar_arguments(&bytes());
+        assert_eq!(
+            difference,
+            call("difference", vec![call("quotient", vec![schema("1 GB"), "2".into()]), schema("512 MB")])
+        );
+    }
+
+    #[test]
+    fn coerce_only_arithmetic_arguments() {
+        let concat = call("concat", vec!["1 GB".into(), "512 MB".into()]);
+        let mut coerced = concat.clone();
+        coerced.coerce_scalar_arguments(&bytes());
+        assert_eq!(coerced, concat);
+    }
+}
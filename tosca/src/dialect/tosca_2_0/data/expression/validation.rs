@@ -1,6 +1,9 @@
 use super::super::{call::*, expression::*, schema::*};
 
-use compris::{annotate::*, normal::*};
+use {
+    compris::{annotate::*, normal::*},
+    kutil::std::immutable::*,
+};
 
 //
 // ValidationExpression
@@ -34,6 +37,11 @@ pub trait ValidationExpression<AnnotatedT> {
         parent_struct_annotations: &StructAnnotations,
     ) where
         AnnotatedT: Annotated + Clone + Default;
+
+    /// Add the value path and source location to `$_schema` calls, for use in coercion errors.
+    fn locate_schema(&mut self, path: ByteString, location: Option<ByteString>)
+    where
+        AnnotatedT: Default;
 }
 
 impl<AnnotatedT> ValidationExpression<AnnotatedT> for Option<Expression<AnnotatedT>> {
@@ -120,6 +128,43 @@ impl<AnnotatedT> ValidationExpression<AnnotatedT> for Option<Expression<Annotate
             self.apply_validation(parent_validation.clone());
         }
     }
+
+    fn locate_schema(&mut self, path: ByteString, location: Option<ByteString>)
+    where
+        AnnotatedT: Default,
+    {
+        if let Some(validation) = self {
+            locate_schema(validation, &path, location.as_ref());
+        }
+    }
+}
+
+fn locate_schema<AnnotatedT>(expression: &mut Expression<AnnotatedT>, path: &ByteString, location: Option<&ByteString>)
+where
+    AnnotatedT: Default,
+{
+    if let Expression::Call(call) = expression
+        && call.is_native()
+    {
+        match call.function.as_str() {
+            "_schema" => {
+                if call.arguments.len() == 2 {
+                    call.arguments.push(path.clone().into());
+                    if let Some(location) = location {
+                        call.arguments.push(location.clone().into());
+                    }
+                }
+            }
+
+            "_apply" => {
+                for argument in &mut call.arguments {
+                    locate_schema(argument, path, location);
+                }
+            }
+
+            _ => {}
+        }
+    }
 }
 
 /// Complete validation as a flattened `$_apply`.
@@ -141,3 +186,61 @@ macro_rules! complete_validation (
 
 #[allow(unused_imports)]
 pub use complete_validation;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(
+        function_name: &'static str,
+        arguments: Vec<Expression<WithoutAnnotations>>,
+    ) -> Expression<WithoutAnnotations> {
+        Call::new_native(ByteString::from_static(function_name).into(), arguments).into()
+    }
+
+    fn schema(arguments: Vec<&'static str>) -> Expression<WithoutAnnotations> {
+        let mut arguments: Vec<_> = arguments.into_iter().map(|argument| argument.into()).collect();
+        arguments.insert(0, call("value", vec![]));
+        call("_schema", arguments)
+    }
+
+    fn locate(
+        validation: Expression<WithoutAnnotations>,
+        location: Option<&'static str>,
+    ) -> Expression<WithoutAnnotations> {
+        let mut validation = Some(validation);
+        validation.locate_schema(ByteString::from_static("properties.ports"), location.map(ByteString::from_static));
+        validation.expect("validation")
+    }
+
+    #[test]
+    fn locate_schema() {
+        assert_eq!(
+            locate(schema(vec!["schema"]), Some("file.yaml:12:5")),
+            schema(vec!["schema", "properties.ports", "file.yaml:12:5"])
+        );
+
+        // Without a source location
+        assert_eq!(locate(schema(vec!["schema"]), None), schema(vec!["schema", "properties.ports"]));
+    }
+
+    #[test]
+    fn locate_schema_in_apply() {
+        let validation = call("_apply", vec![call("_assert", vec![]), schema(vec!["schema"])]);
+        assert_eq!(
+            locate(validation, Some("file.yaml:12:5")),
+            call("_apply", vec![call("_assert", vec![]), schema(vec!["schema", "properties.ports", "file.yaml:12:5"])])
+        );
+    }
+
+    #[test]
+    fn locate_schema_once() {
+        // Already located schemas are left as they are
+        let validation = schema(vec!["schema", "properties.other"]);
+        assert_eq!(locate(validation.clone(), Some("file.yaml:12:5")), validation);
+
+        // Other calls are left as they are
+        let validation = call("_assert", vec![schema(vec!["schema"])]);
+        assert_eq!(locate(validation.clone(), Some("file.yaml:12:5")), validation);
+    }
+}
@@ -1,3 +1,4 @@
+mod builtin;
 mod call;
 mod data_kind;
 mod expression;
@@ -5,4 +6,4 @@ mod schema;
 mod version;
 
 #[allow(unused_imports)]
-pub use {call::*, data_kind::*, expression::*, schema::*, version::*};
+pub use {builtin::*, call::*, data_kind::*, expression::*, schema::*, version::*};
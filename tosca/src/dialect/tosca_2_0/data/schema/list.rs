@@ -13,8 +13,8 @@ use {kutil::cli::depict::*, std::collections::*};
 #[derive(Clone, Debug, Default, Depict)]
 pub struct ListSchema<AnnotatedT> {
     /// Entry schema reference.
-    #[depict(style(number))]
-    pub entry: SchemaReference,
+    #[depict(option, style(number))]
+    pub entry: Option<SchemaReference>,
 
     /// Default.
     #[depict(option, as(depict))]
@@ -39,7 +39,9 @@ where
         let mut map = BTreeMap::default();
 
         map.insert("kind".into(), DataKind::List.as_str().into());
-        map.insert("entry".into(), (self.entry as u64).into());
+        if let Some(entry) = self.entry {
+            map.insert("entry".into(), (entry as u64).into());
+        }
 
         if let Some(default) = self.default {
             map.insert("default".into(), default);
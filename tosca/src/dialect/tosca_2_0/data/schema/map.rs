@@ -13,12 +13,12 @@ use {kutil::cli::depict::*, std::collections::*};
 #[derive(Clone, Debug, Default, Depict)]
 pub struct MapSchema<AnnotatedT> {
     /// Key schema reference.
-    #[depict(style(number))]
-    pub key: SchemaReference,
+    #[depict(option, style(number))]
+    pub key: Option<SchemaReference>,
 
     /// Entry schema reference.
-    #[depict(style(number))]
-    pub entry: SchemaReference,
+    #[depict(option, style(number))]
+    pub entry: Option<SchemaReference>,
 
     /// Default.
     #[depict(option, as(depict))]
@@ -46,8 +46,12 @@ where
         let mut map = BTreeMap::default();
 
         map.insert("kind".into(), DataKind::Map.as_str().into());
-        map.insert("key".into(), (self.key as u64).into());
-        map.insert("entry".into(), (self.entry as u64).into());
+        if let Some(key) = self.key {
+            map.insert("key".into(), (key as u64).into());
+        }
+        if let Some(entry) = self.entry {
+            map.insert("entry".into(), (entry as u64).into());
+        }
 
         if let Some(default) = self.default {
             map.insert("default".into(), default);
@@ -4,6 +4,7 @@ use super::{
 };
 
 use {
+    compris::normal::*,
     kutil::{cli::depict::*, std::immutable::*},
     std::collections::*,
 };
@@ -19,6 +20,22 @@ pub struct StructSchema<AnnotatedT> {
     #[depict(iter(kv), style(number), key_style(string))]
     pub fields: BTreeMap<ByteString, SchemaReference>,
 
+    /// Fields without a schema (e.g. for recursive data types).
+    #[depict(iter(item), style(string))]
+    pub untyped: BTreeSet<ByteString>,
+
+    /// Required fields.
+    #[depict(iter(item), style(string))]
+    pub required: BTreeSet<ByteString>,
+
+    /// Field defaults.
+    #[depict(iter(kv), as(depict), key_style(string))]
+    pub defaults: BTreeMap<ByteString, Expression<AnnotatedT>>,
+
+    /// Field validators.
+    #[depict(iter(kv), as(depict), key_style(string))]
+    pub validators: BTreeMap<ByteString, Expression<AnnotatedT>>,
+
     /// Default.
     #[depict(option, as(depict))]
     pub default: Option<Expression<AnnotatedT>>,
@@ -30,7 +47,13 @@ pub struct StructSchema<AnnotatedT> {
 
 impl<AnnotatedT> PartialEq for StructSchema<AnnotatedT> {
     fn eq(&self, other: &Self) -> bool {
-        (self.fields == other.fields) && (self.default == other.default) && (self.validator == other.validator)
+        (self.fields == other.fields)
+            && (self.untyped == other.untyped)
+            && (self.required == other.required)
+            && (self.defaults == other.defaults)
+            && (self.validators == other.validators)
+            && (self.default == other.default)
+            && (self.validator == other.validator)
     }
 }
 
@@ -43,10 +66,30 @@ where
 
         map.insert("kind".into(), DataKind::Struct.as_str().into());
 
-        let fields: BTreeMap<_, _> =
+        let mut fields: BTreeMap<_, _> =
             self.fields.into_iter().map(|(key, value)| (key.into(), (value as u64).into())).collect();
+        for name in self.untyped {
+            // Null means no schema
+            fields.insert(name.into(), Variant::Null(Default::default()).into());
+        }
         map.insert("fields".into(), fields.into());
 
+        if !self.required.is_empty() {
+            let required: Vec<_> = self.required.into_iter().map(|name| name.into()).collect();
+            map.insert("required".into(), required.into());
+        }
+
+        if !self.defaults.is_empty() {
+            let defaults: BTreeMap<_, _> = self.defaults.into_iter().map(|(key, value)| (key.into(), value)).collect();
+            map.insert("defaults".into(), defaults.into());
+        }
+
+        if !self.validators.is_empty() {
+            let validators: BTreeMap<_, _> =
+                self.validators.into_iter().map(|(key, value)| (key.into(), value)).collect();
+            map.insert("validators".into(), validators.into());
+        }
+
         if let Some(default) = self.default {
             map.insert("default".into(), default);
         }
@@ -4,7 +4,7 @@ use super::{
     entity_kind::*,
 };
 
-use {compris::annotate::*, kutil::std::error::*};
+use {compris::annotate::*, kutil::std::error::*, std::collections::*};
 
 impl super::Dialect {
     /// Compile service template to Floria.
@@ -118,6 +118,23 @@ impl super::Dialect {
         let node_template_kind_name = self.implementation.entity_kinds.represent(NODE_TEMPLATE);
         let policy_template_kind_name = self.implementation.entity_kinds.represent(POLICY_TEMPLATE);
 
+        // Floria classes of the groups, by member node template name
+        let mut group_ids = BTreeMap::<Name, Vec<floria::ID>>::default();
+        for (entity_kind, name) in source.entity_names() {
+            if entity_kind == GROUP_TEMPLATE
+                && let Ok(group_template) =
+                    source.get_entity::<GroupTemplate<AnnotatedT>, _>(GROUP_TEMPLATE, &group_template_kind_name, &name)
+            {
+                for member in &group_template.members {
+                    group_ids.entry(member.clone()).or_default().push(floria::ID::new_for(
+                        floria::Kind::Class,
+                        directory.clone(),
+                        name.clone().into(),
+                    ));
+                }
+            }
+        }
+
         for (entity_kind, name) in source.entity_names() {
             match entity_kind {
                 GROUP_TEMPLATE => {
@@ -133,10 +150,10 @@ impl super::Dialect {
                         &group_template_kind_name,
                         &name,
                     ) {
-                        Ok(_group_template) => {
-                            let floria_group_template = floria::Class::new_for(directory.clone(), name.into());
+                        Ok(group_template) => {
+                            let mut floria_group_template = floria::Class::new_for(directory.clone(), name.into());
 
-                            // TODO
+                            group_template.compile(&mut floria_group_template, errors)?;
 
                             unwrap_or_give_and_return!(store.add_class(floria_group_template), errors, Ok(None));
                         }
@@ -167,6 +184,10 @@ impl super::Dialect {
 
                             node_template.compile(&mut floria_node_template, directory, store.clone(), errors)?;
 
+                            if let Some(group_ids) = group_ids.get(&name) {
+                                floria_node_template.template.class_ids.extend(group_ids.iter().cloned());
+                            }
+
                             // Capabilities
                             for (name, capability) in &node_template.capabilities {
                                 let mut floria_capability = floria::VertexTemplate::new_for(
@@ -190,9 +211,7 @@ impl super::Dialect {
 
                             // Requirements
                             for (name, requirement) in &node_template.requirements {
-                                // TODO
-                                let node_selector =
-                                    floria::VertexSelector::new_vertex(floria_node_template.template.id.clone());
+                                let node_selector = requirement.target_selector(directory, source_id, catalog);
 
                                 let mut floria_requirement = floria::EdgeTemplate::new_for(
                                     directory.clone(),
@@ -258,19 +277,19 @@ impl super::Dialect {
                         "compiling"
                     );
 
-                    match source.get_entity::<PolicyTemplate<WithAnnotations>, _>(
+                    match source.get_entity::<PolicyTemplate<AnnotatedT>, _>(
                         POLICY_TEMPLATE,
                         &policy_template_kind_name,
                         &name,
                     ) {
-                        Ok(_policy_template) => {
-                            let floria_policy_template = floria::VertexTemplate::new_for(
+                        Ok(policy_template) => {
+                            let mut floria_policy_template = floria::VertexTemplate::new_for(
                                 directory.clone(),
                                 name.into(),
                                 Some(floria_service_template_id.clone()),
                             );
 
-                            // TODO
+                            policy_template.compile(&mut floria_policy_template, errors)?;
 
                             floria_service_template
                                 .contained_vertex_template_ids
@@ -68,4 +68,16 @@ impl DialectTrait for Dialect {
             &mut errors.to_error_recipient().into_annotated(),
         )
     }
+
+    fn lint_source(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<LintWarning> {
+        self.lint::<WithAnnotations>(source_id, catalog)
+    }
+
+    fn source_types(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<TypeInfo> {
+        self.types::<WithAnnotations>(source_id, catalog)
+    }
+
+    fn document_type(&self, type_info: TypeInfo, catalog: &Catalog) -> Option<TypeDocumentation> {
+        self.document::<WithAnnotations>(type_info, catalog)
+    }
 }
@@ -0,0 +1,360 @@
+use super::{
+    super::{super::super::grammar::*, entities::*},
+    entity_kind::*,
+};
+
+use {compris::annotate::*, kutil::std::immutable::*, std::collections::*};
+
+impl super::Dialect {
+    /// Document a type.
+    pub fn document<AnnotatedT>(&self, info: TypeInfo, catalog: &Catalog) -> Option<TypeDocumentation>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+    {
+        macro_rules! document (
+            ( $type:ident $( , $title:literal => $section:ident ( $field:ident ) )* $(,)? ) => {
+                {
+                    let lineage = Lineage::<$type<AnnotatedT>>::new::<AnnotatedT>(&info.type_id, catalog)?;
+                    let sections = vec![
+                        $( DocumentationSection::new($title, lineage.$section(|entity| &entity.$field, catalog)), )*
+                    ];
+                    Some(lineage.into_documentation::<AnnotatedT>(info, sections))
+                }
+            };
+        );
+
+        match info.type_id.entity_kind {
+            ARTIFACT_TYPE => document!(ArtifactType, "Properties" => properties(properties)),
+
+            CAPABILITY_TYPE => document!(
+                CapabilityType,
+                "Properties" => properties(properties),
+                "Attributes" => attributes(attributes),
+            ),
+
+            DATA_TYPE => document!(DataType, "Properties" => properties(properties)),
+
+            GROUP_TYPE => document!(
+                GroupType,
+                "Properties" => properties(properties),
+                "Attributes" => attributes(attributes),
+            ),
+
+            INTERFACE_TYPE => document!(InterfaceType),
+
+            NODE_TYPE => document!(
+                NodeType,
+                "Properties" => properties(properties),
+                "Attributes" => attributes(attributes),
+                "Capabilities" => capabilities(capabilities),
+                "Requirements" => requirements(requirements),
+                "Interfaces" => interfaces(interfaces),
+            ),
+
+            POLICY_TYPE => document!(PolicyType, "Properties" => properties(properties)),
+
+            RELATIONSHIP_TYPE => document!(
+                RelationshipType,
+                "Properties" => properties(properties),
+                "Attributes" => attributes(attributes),
+                "Interfaces" => interfaces(interfaces),
+            ),
+
+            _ => None,
+        }
+    }
+}
+
+//
+// Lineage
+//
+
+// A completed type and its ancestors, from the type itself to the root
+struct Lineage<'own, EntityT> {
+    types: Vec<(TypeID, &'own EntityT)>,
+}
+
+impl<'own, EntityT> Lineage<'own, EntityT>
+where
+    EntityT: 'static,
+{
+    fn new<AnnotatedT>(type_id: &TypeID, catalog: &'own Catalog) -> Option<Self>
+    where
+        EntityT: TypeEntity<AnnotatedT>,
+    {
+        let entity_kind = type_id.entity_kind;
+        let mut entity = catalog
+            .get_entity::<EntityT, WithoutAnnotations>(entity_kind, &type_id.name.clone().into(), &type_id.source_id)
+            .ok()?;
+
+        let mut types = vec![(type_id.clone(), entity)];
+        let mut source_id = &type_id.source_id;
+
+        while let Some(parent) = entity.descriptor().parent {
+            let Some(parent_source_id) = catalog.try_lookup(entity_kind, parent, source_id) else {
+                break;
+            };
+
+            let parent_type_id = TypeID::new(entity_kind, parent_source_id.clone(), parent.name.clone());
+            if types.iter().any(|(type_id, _)| *type_id == parent_type_id) {
+                // Circular
+                break;
+            }
+
+            let Ok(parent_entity) = catalog.get_entity::<EntityT, WithoutAnnotations>(entity_kind, parent, source_id)
+            else {
+                break;
+            };
+
+            entity = parent_entity;
+            source_id = parent_source_id;
+            types.push((parent_type_id, entity));
+        }
+
+        Some(Self { types })
+    }
+
+    fn into_documentation<AnnotatedT>(self, info: TypeInfo, sections: Vec<DocumentationSection>) -> TypeDocumentation
+    where
+        EntityT: TypeEntity<AnnotatedT>,
+    {
+        let descriptor = self.types[0].1.descriptor();
+
+        let mut documentation = TypeDocumentation::new(info);
+        documentation.metadata =
+            descriptor.metadata.iter().map(|(key, value)| (key.clone(), value.to_string())).collect();
+        documentation.ancestors = self.types.iter().skip(1).map(|(type_id, _)| type_id.clone()).collect();
+        documentation.sections = sections.into_iter().filter(|section| !section.members.is_empty()).collect();
+        documentation
+    }
+
+    // New member, crediting the type that declared or last overrode it
+    //
+    // A type declares the member if its parent does not have it, and overrides it if its
+    // definition differs from the parent's. Completed types include their inherited members, so
+    // the definitions are compared via their descriptions.
+    fn member<DescribeT, DescriptionT>(&self, name: &ByteString, describe: DescribeT) -> DocumentationMember
+    where
+        DescribeT: Fn(&EntityT, &SourceID) -> Option<DescriptionT>,
+        DescriptionT: PartialEq,
+    {
+        let descriptions: Vec<_> =
+            self.types.iter().map(|(type_id, entity)| describe(entity, &type_id.source_id)).collect();
+
+        let declarer = |start: usize| {
+            (start..descriptions.len()).find(|index| match (&descriptions[*index], descriptions.get(index + 1)) {
+                (Some(description), Some(Some(parent_description))) => description != parent_description,
+                (Some(_), _) => true,
+                (None, _) => false,
+            })
+        };
+
+        let declared_by = declarer(0).unwrap_or_default();
+
+        let mut member = DocumentationMember::new(name.clone(), self.types[declared_by].0.clone());
+        if let Some(Some(_)) = descriptions.get(declared_by + 1)
+            && let Some(overrides) = declarer(declared_by + 1)
+        {
+            member.overrides = Some(self.types[overrides].0.clone());
+        }
+        member
+    }
+
+    fn reference(&self, entity_kind: EntityKind, full_name: &FullName, catalog: &Catalog) -> TypeReference {
+        TypeReference::new(full_name.clone(), type_id(entity_kind, full_name, &self.types[0].0.source_id, catalog))
+    }
+
+    fn properties<AnnotatedT, GetT>(&self, get: GetT, catalog: &Catalog) -> Vec<DocumentationMember>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        GetT: Fn(&EntityT) -> &PropertyDefinitions<AnnotatedT>,
+    {
+        get(self.types[0].1)
+            .iter()
+            .map(|(name, definition)| {
+                let mut member = self.member(name, |entity, source_id| {
+                    get(entity).get(name).map(|definition| {
+                        (
+                            declaration_location(definition.declaration_annotations()),
+                            type_id(DATA_TYPE, &definition.type_name, source_id, catalog),
+                            definition.default.as_ref().map(|default| default.to_string()),
+                            definition.required,
+                            definition.description.clone(),
+                        )
+                    })
+                });
+                member.types.push(self.reference(DATA_TYPE, &definition.type_name, catalog));
+                member.default = definition.default.as_ref().map(|default| default.to_string());
+                member.required = Some(definition.required);
+                member.description = definition.description.clone();
+                member
+            })
+            .collect()
+    }
+
+    fn attributes<AnnotatedT, GetT>(&self, get: GetT, catalog: &Catalog) -> Vec<DocumentationMember>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        GetT: Fn(&EntityT) -> &AttributeDefinitions<AnnotatedT>,
+    {
+        get(self.types[0].1)
+            .iter()
+            .map(|(name, definition)| {
+                let mut member = self.member(name, |entity, source_id| {
+                    get(entity).get(name).map(|definition| {
+                        (
+                            declaration_location(definition.declaration_annotations()),
+                            type_id(DATA_TYPE, &definition.type_name, source_id, catalog),
+                            definition.default.as_ref().map(|default| default.to_string()),
+                            definition.description.clone(),
+                        )
+                    })
+                });
+                member.types.push(self.reference(DATA_TYPE, &definition.type_name, catalog));
+                member.default = definition.default.as_ref().map(|default| default.to_string());
+                member.description = definition.description.clone();
+                member
+            })
+            .collect()
+    }
+
+    fn capabilities<AnnotatedT, GetT>(&self, get: GetT, catalog: &Catalog) -> Vec<DocumentationMember>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        GetT: Fn(&EntityT) -> &CapabilityDefinitions<AnnotatedT>,
+    {
+        get(self.types[0].1)
+            .iter()
+            .map(|(name, definition)| {
+                let mut member = self.member(name, |entity, source_id| {
+                    get(entity).get(name).map(|definition| {
+                        (
+                            declaration_location(definition.declaration_annotations()),
+                            type_id(CAPABILITY_TYPE, &definition.type_name, source_id, catalog),
+                            definition.description.clone(),
+                        )
+                    })
+                });
+                member.types.push(self.reference(CAPABILITY_TYPE, &definition.type_name, catalog));
+                member.description = definition.description.clone();
+                member
+            })
+            .collect()
+    }
+
+    fn requirements<AnnotatedT, GetT>(&self, get: GetT, catalog: &Catalog) -> Vec<DocumentationMember>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        GetT: Fn(&EntityT) -> &RequirementDefinitions<AnnotatedT>,
+    {
+        // Requirement names can repeat, so we list each only once
+        let mut names = BTreeSet::<ByteString>::default();
+
+        get(self.types[0].1)
+            .into_iter()
+            .filter(|(name, _)| names.insert(name.clone()))
+            .map(|(name, definition)| {
+                let mut member = self.member(name, |entity, source_id| {
+                    get(entity).into_iter().find(|(other_name, _)| other_name == name).map(|(_, definition)| {
+                        (
+                            declaration_location(definition.declaration_annotations()),
+                            type_id(CAPABILITY_TYPE, &definition.capability, source_id, catalog),
+                            definition.node.as_ref().and_then(|node| type_id(NODE_TYPE, node, source_id, catalog)),
+                            definition.description.clone(),
+                        )
+                    })
+                });
+                member.types.push(self.reference(CAPABILITY_TYPE, &definition.capability, catalog));
+                if let Some(node) = &definition.node {
+                    member.types.push(self.reference(NODE_TYPE, node, catalog));
+                }
+                member.description = definition.description.clone();
+                member
+            })
+            .collect()
+    }
+
+    fn interfaces<AnnotatedT, GetT>(&self, get: GetT, catalog: &Catalog) -> Vec<DocumentationMember>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        GetT: Fn(&EntityT) -> &InterfaceDefinitions<AnnotatedT>,
+    {
+        get(self.types[0].1)
+            .iter()
+            .map(|(name, definition)| {
+                let mut member = self.member(name, |entity, source_id| {
+                    get(entity).get(name).map(|definition| {
+                        (
+                            declaration_location(definition.declaration_annotations()),
+                            type_id(INTERFACE_TYPE, &definition.type_name, source_id, catalog),
+                            definition.description.clone(),
+                        )
+                    })
+                });
+                member.types.push(self.reference(INTERFACE_TYPE, &definition.type_name, catalog));
+                member.description = definition.description.clone();
+                member
+            })
+            .collect()
+    }
+}
+
+// Type ID of a type name as seen from a source
+fn type_id(entity_kind: EntityKind, full_name: &FullName, source_id: &SourceID, catalog: &Catalog) -> Option<TypeID> {
+    catalog
+        .try_lookup(entity_kind, full_name, source_id)
+        .map(|source_id| TypeID::new(entity_kind, source_id.clone(), full_name.name.clone()))
+}
+
+// Location of a declaration, e.g. `file.yaml:12:5`
+fn declaration_location(annotations: Option<&Annotations>) -> Option<String> {
+    let annotations = annotations?;
+    let source = annotations.source.as_ref()?;
+    Some(match &annotations.span {
+        Some(span) => format!("{}:{}:{}", source, span.start.row, span.start.column),
+        None => source.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: &[&str] = &["Leaf", "Middle", "Base", "Root"];
+
+    fn type_id(index: usize) -> TypeID {
+        TypeID::new(NODE_TYPE, SourceID::URL(ByteString::from_static("types.yaml")), NAMES[index].into())
+    }
+
+    // Descriptions of the member in the lineage, from the type to the root, and the indexes of the
+    // types it is credited to
+    fn credit(descriptions: [Option<&'static str>; 4]) -> (usize, Option<usize>) {
+        let lineage = Lineage {
+            types: descriptions.iter().enumerate().map(|(index, entity)| (type_id(index), entity)).collect(),
+        };
+        let member = lineage.member(&ByteString::from_static("port"), |entity, _source_id| *entity);
+
+        let index = |type_id: &TypeID| NAMES.iter().position(|name| type_id.name == Name::from(*name)).expect("type");
+        (index(&member.declared_by), member.overrides.as_ref().map(index))
+    }
+
+    #[test]
+    fn inherited() {
+        assert_eq!(credit([Some("a"), Some("a"), Some("a"), None]), (2, None));
+        assert_eq!(credit([Some("a"), Some("a"), Some("a"), Some("a")]), (3, None));
+    }
+
+    #[test]
+    fn declared() {
+        assert_eq!(credit([Some("a"), None, None, None]), (0, None));
+    }
+
+    #[test]
+    fn overridden() {
+        assert_eq!(credit([Some("b"), Some("b"), Some("a"), None]), (1, Some(2)));
+        assert_eq!(credit([Some("c"), Some("b"), Some("a"), None]), (0, Some(1)));
+
+        // The overridden definition is the one the parent inherited
+        assert_eq!(credit([Some("c"), Some("b"), Some("b"), Some("a")]), (0, Some(2)));
+    }
+}
@@ -1,5 +1,11 @@
 use super::super::super::super::grammar::*;
 
+// Names shared with the functions plugin
+pub use puccini_tosca_2_0_core::{
+    ARTIFACT_NAME, ATTRIBUTE_NAME, CAPABILITY_NAME, GROUP_TEMPLATE_NAME, NODE_TEMPLATE_NAME, PARAMETER_NAME,
+    POLICY_TEMPLATE_NAME, PROPERTY_NAME, RELATIONSHIP_TEMPLATE_NAME, REQUIREMENT_NAME, SERVICE_TEMPLATE_NAME,
+};
+
 // Types
 
 /// Artifact type.
@@ -55,33 +61,18 @@ pub const RELATIONSHIP_TYPE_NAME: &str = "RelationshipType";
 /// Service template.
 pub const SERVICE_TEMPLATE: EntityKind = EntityKind(100);
 
-/// Service template name.
-pub const SERVICE_TEMPLATE_NAME: &str = "ServiceTemplate";
-
 /// Group template.
 pub const GROUP_TEMPLATE: EntityKind = EntityKind(104);
 
-/// Group template name.
-pub const GROUP_TEMPLATE_NAME: &str = "GroupTemplate";
-
 /// Node template.
 pub const NODE_TEMPLATE: EntityKind = EntityKind(106);
 
-/// Node template name.
-pub const NODE_TEMPLATE_NAME: &str = "NodeTemplate";
-
 /// Policy template.
 pub const POLICY_TEMPLATE: EntityKind = EntityKind(107);
 
-/// Policy template name.
-pub const POLICY_TEMPLATE_NAME: &str = "PolicyTemplate";
-
 /// Relationship template.
 pub const RELATIONSHIP_TEMPLATE: EntityKind = EntityKind(108);
 
-/// Relationship template name.
-pub const RELATIONSHIP_TEMPLATE_NAME: &str = "RelationshipTemplate";
-
 // Other
 
 /// Repository.
@@ -90,20 +81,11 @@ pub const REPOSITORY: EntityKind = EntityKind(200);
 /// Repository name.
 pub const REPOSITORY_NAME: &str = "Repository";
 
-/// Artifact name.
-pub const ARTIFACT_NAME: &str = "Artifact";
-
-/// Capability name.
-pub const CAPABILITY_NAME: &str = "Capability";
-
-/// Property name.
-pub const PROPERTY_NAME: &str = "Property";
-
-/// Attribute name.
-pub const ATTRIBUTE_NAME: &str = "Attribute";
+/// Function.
+pub const FUNCTION: EntityKind = EntityKind(201);
 
-/// Parameter name.
-pub const PARAMETER_NAME: &str = "Parameter";
+/// Function name.
+pub const FUNCTION_NAME: &str = "Function";
 
 impl super::Dialect {
     /// TOSCA 2.0 supported [EntityKind]s.
@@ -126,6 +108,7 @@ impl super::Dialect {
         entity_kinds.add(RELATIONSHIP_TEMPLATE, RELATIONSHIP_TEMPLATE_NAME.into());
 
         entity_kinds.add(REPOSITORY, REPOSITORY_NAME.into());
+        entity_kinds.add(FUNCTION, FUNCTION_NAME.into());
 
         entity_kinds
     }
@@ -112,6 +112,10 @@ impl super::Dialect {
             unwrap_or_give_and_return!(source.add_entity(REPOSITORY, name, repository), errors, Ok(()));
         }
 
+        for (name, function) in file.functions {
+            unwrap_or_give_and_return!(source.add_entity(FUNCTION, name, function), errors, Ok(()));
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,187 @@
+use super::{
+    super::{super::super::grammar::*, entities::*},
+    entity_kind::*,
+};
+
+use {
+    compris::annotate::*,
+    kutil::std::{collections::*, immutable::*},
+};
+
+impl super::Dialect {
+    /// Lint source.
+    pub fn lint<AnnotatedT>(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<LintWarning>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+    {
+        tracing::info!(source = source_id.to_string(), "linting");
+
+        let mut warnings = Vec::default();
+
+        let Ok(source) = catalog.get_source::<WithoutAnnotations>(source_id) else {
+            return warnings;
+        };
+
+        let entity_kinds = &self.implementation.entity_kinds;
+
+        let mut entity_names = source.entity_names();
+        entity_names.sort();
+
+        for (entity_kind, name) in entity_names {
+            let entity_kind_name = entity_kinds.represent(entity_kind);
+
+            let Some(info) = LintInfo::get::<AnnotatedT>(source, entity_kind, &entity_kind_name, &name) else {
+                continue;
+            };
+
+            // Unused types and repositories
+            if (info.is_type || (entity_kind == REPOSITORY))
+                && !catalog.references.has_entity(entity_kind, source_id, &name)
+            {
+                warnings.push(LintWarning::new(
+                    if info.is_type { LintRule::UnusedType } else { LintRule::UnusedRepository },
+                    format!("{} is never used: {}", entity_kind_name, name),
+                    source_id.clone(),
+                    info.annotations.clone(),
+                ));
+            }
+
+            // Undescribed node templates
+            if entity_kind == NODE_TEMPLATE
+                && let Ok(node_template) = source.get_entity::<NodeTemplate<AnnotatedT>, WithoutAnnotations>(
+                    entity_kind,
+                    &entity_kind_name,
+                    &name,
+                )
+                && node_template.description.is_none()
+                && !catalog
+                    .get_entity::<NodeType<AnnotatedT>, WithoutAnnotations>(
+                        NODE_TYPE,
+                        &node_template.type_name,
+                        source_id,
+                    )
+                    .map(|node_type| node_type.description.is_some())
+                    .unwrap_or_default()
+            {
+                warnings.push(LintWarning::new(
+                    LintRule::UndescribedNodeTemplate,
+                    format!("neither node template {:?} nor its type has a description", name.to_string()),
+                    source_id.clone(),
+                    info.annotations.clone(),
+                ));
+            }
+
+            // Duplicate metadata keys
+            let mut normalized_keys = FastHashMap::<String, &ByteString>::default();
+            for key in info.metadata_keys.iter() {
+                let normalized_key = key.to_lowercase().replace('-', "_");
+                match normalized_keys.get(&normalized_key) {
+                    Some(other_key) => warnings.push(LintWarning::new(
+                        LintRule::DuplicateMetadataKey,
+                        format!(
+                            "{} {:?} has duplicate metadata keys: {:?} and {:?}",
+                            entity_kind_name,
+                            name.to_string(),
+                            other_key.to_string(),
+                            key.to_string()
+                        ),
+                        source_id.clone(),
+                        info.annotations.clone(),
+                    )),
+
+                    None => {
+                        normalized_keys.insert(normalized_key, key);
+                    }
+                }
+            }
+        }
+
+        // Unused inputs
+        if let Ok(service_template) = source.get_entity::<ServiceTemplate<AnnotatedT>, WithoutAnnotations>(
+            SERVICE_TEMPLATE,
+            &entity_kinds.represent(SERVICE_TEMPLATE),
+            &Default::default(),
+        ) {
+            for (input_name, input) in &service_template.inputs {
+                if !catalog.references.has_input(source_id, input_name) {
+                    warnings.push(LintWarning::new(
+                        LintRule::UnusedInput,
+                        format!("input is never read: {}", input_name),
+                        source_id.clone(),
+                        input.field_annotations("type_name").cloned(),
+                    ));
+                }
+            }
+        }
+
+        // Unused imports
+        for (dependency_source_id, scope) in &source.dependencies {
+            if !scope.is_empty() && !catalog.references.has_scope(source_id, scope) {
+                warnings.push(LintWarning::new(
+                    LintRule::UnusedImport,
+                    format!("namespace {:?} is never used: {}", scope.to_string(), dependency_source_id),
+                    source_id.clone(),
+                    None,
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+//
+// LintInfo
+//
+
+// What we need to know about an entity in order to lint it
+struct LintInfo {
+    is_type: bool,
+    metadata_keys: Vec<ByteString>,
+    annotations: Option<Annotations>,
+}
+
+impl LintInfo {
+    fn get<AnnotatedT>(
+        source: &Source,
+        entity_kind: EntityKind,
+        entity_kind_name: &ByteString,
+        name: &Name,
+    ) -> Option<Self>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+    {
+        macro_rules! lint_info (
+            ( $type:ident, $is_type:expr, $field:literal $(,)? ) => {
+                {
+                    let entity = source
+                        .get_entity::<$type<AnnotatedT>, WithoutAnnotations>(entity_kind, entity_kind_name, name)
+                        .ok()?;
+
+                    Self {
+                        is_type: $is_type,
+                        metadata_keys: entity.metadata.keys().cloned().collect(),
+                        annotations: entity.field_annotations($field).cloned(),
+                    }
+                }
+            };
+        );
+
+        Some(match entity_kind {
+            ARTIFACT_TYPE => lint_info!(ArtifactType, true, "derived_from"),
+            CAPABILITY_TYPE => lint_info!(CapabilityType, true, "derived_from"),
+            DATA_TYPE => lint_info!(DataType, true, "derived_from"),
+            GROUP_TYPE => lint_info!(GroupType, true, "derived_from"),
+            INTERFACE_TYPE => lint_info!(InterfaceType, true, "derived_from"),
+            NODE_TYPE => lint_info!(NodeType, true, "derived_from"),
+            POLICY_TYPE => lint_info!(PolicyType, true, "derived_from"),
+            RELATIONSHIP_TYPE => lint_info!(RelationshipType, true, "derived_from"),
+            GROUP_TEMPLATE => lint_info!(GroupTemplate, false, "type_name"),
+            NODE_TEMPLATE => lint_info!(NodeTemplate, false, "type_name"),
+            POLICY_TEMPLATE => lint_info!(PolicyTemplate, false, "type_name"),
+            RELATIONSHIP_TEMPLATE => lint_info!(RelationshipTemplate, false, "type_name"),
+            REPOSITORY => lint_info!(RepositoryDefinition, false, "url"),
+            _ => return None,
+        })
+    }
+}
@@ -1,8 +1,12 @@
 mod compile;
 mod dialect;
+mod documentation;
 mod entity_kind;
 mod implicit;
 mod initialize;
+mod lint;
+mod query;
+mod types;
 
 #[allow(unused_imports)]
-pub use {dialect::*, entity_kind::*, implicit::*};
+pub use {dialect::*, entity_kind::*, implicit::*, query::*};
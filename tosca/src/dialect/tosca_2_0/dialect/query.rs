@@ -0,0 +1,93 @@
+use super::super::{super::super::grammar::*, data::*, entities::*};
+
+use {
+    compris::{annotate::*, normal::*, resolve::*},
+    kutil::std::error::*,
+};
+
+/// Compile a query expression to Floria.
+///
+/// The expression is parsed as if it were a property value in the source, so that calls to
+/// functions declared in the source are completed, too. Non-literal expressions are wrapped in the
+/// same evaluation call as property updaters.
+pub fn compile_query<ErrorRecipientT>(
+    variant: Variant<WithAnnotations>,
+    catalog: &mut Catalog,
+    source_id: &SourceID,
+    errors: &mut ErrorRecipientT,
+) -> Result<Option<floria::Expression>, ToscaError<WithAnnotations>>
+where
+    ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+{
+    let expression: Option<Expression<WithAnnotations>> =
+        variant.resolve_with_errors(&mut errors.into_annotated().to_resolve_error_recipient())?;
+
+    let Some(mut expression) = expression else {
+        return Ok(None);
+    };
+
+    complete_function_calls(&mut expression, catalog, source_id, errors)?;
+
+    let (value, updater) = expression.into_floria_property_fields();
+    Ok(updater.or(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, compris::parse::*, kutil::std::immutable::*};
+
+    // The compiled query and whether there were errors
+    fn compile(yaml: &str) -> (Option<floria::Expression>, bool) {
+        let variant = Parser::new(Format::YAML).parse_reader(&mut yaml.as_bytes()).expect("parse");
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+        let expression =
+            compile_query(variant, &mut Catalog::default(), &Default::default(), &mut errors).expect("compile");
+        (expression, !errors.is_empty())
+    }
+
+    fn call(
+        function_name: &'static str,
+        arguments: Vec<Expression<WithoutAnnotations>>,
+    ) -> Expression<WithoutAnnotations> {
+        Call::new_native(ByteString::from_static(function_name).into(), arguments).into()
+    }
+
+    fn literal(expression: Expression<WithoutAnnotations>) -> Option<floria::Expression> {
+        Some(expression.into())
+    }
+
+    #[test]
+    fn literal_query() {
+        assert_eq!(compile("hello"), (literal("hello".into()), false));
+        assert_eq!(compile("[ a, b ]"), (literal(vec!["a".into(), "b".into()].into()), false));
+    }
+
+    #[test]
+    fn folded_query() {
+        assert_eq!(compile("{ $concat: [ a, b ] }"), (literal("ab".into()), false));
+        assert_eq!(compile("{ $length: [ [ a, b, c ] ] }"), (literal(Variant::from(3i64).into()), false));
+    }
+
+    #[test]
+    fn graph_query() {
+        // Evaluated at the call site like a property updater
+        let (expression, errors) = compile("{ $get_property: [ SELF, port ] }");
+        assert!(!errors);
+
+        let expression = expression.expect("expression");
+        assert!(!expression.is_literal());
+
+        let (value, updater) = call("get_property", vec!["SELF".into(), "port".into()]).into_floria_property_fields();
+        assert!(value.is_none());
+        assert_eq!(Some(expression), updater);
+    }
+
+    #[test]
+    fn invalid_query() {
+        let (_, errors) = compile("{ $not: [ 'true' ] }");
+        assert!(errors);
+
+        let (_, errors) = compile("{ $quotient: [ 1, 0 ] }");
+        assert!(errors);
+    }
+}
@@ -0,0 +1,69 @@
+use super::{
+    super::{super::super::grammar::*, entities::*},
+    entity_kind::*,
+};
+
+use compris::annotate::*;
+
+impl super::Dialect {
+    /// Types in the namespace of a source.
+    pub fn types<AnnotatedT>(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<TypeInfo>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+    {
+        let mut types = Vec::default();
+
+        let Ok(source) = catalog.get_source::<WithoutAnnotations>(source_id) else {
+            return types;
+        };
+
+        let entity_kinds = &self.implementation.entity_kinds;
+
+        for (entity_kind, full_name, entity_source_id) in source.namespace() {
+            macro_rules! type_info (
+                ( $type:ident $(,)? ) => {
+                    {
+                        let Ok(entity) = catalog.get_entity::<$type<AnnotatedT>, WithoutAnnotations>(
+                            entity_kind,
+                            &full_name,
+                            source_id,
+                        ) else {
+                            continue;
+                        };
+
+                        let descriptor = entity.descriptor();
+
+                        let parent_type_id = descriptor.parent.and_then(|parent| {
+                            catalog.try_lookup(entity_kind, parent, &entity_source_id).map(|parent_source_id| {
+                                TypeID::new(entity_kind, parent_source_id.clone(), parent.name.clone())
+                            })
+                        });
+
+                        TypeInfo {
+                            entity_kind_name: entity_kinds.represent(entity_kind),
+                            type_id: TypeID::new(entity_kind, entity_source_id.clone(), full_name.name.clone()),
+                            full_name: full_name.clone(),
+                            parent_type_id,
+                            version: descriptor.version,
+                            description: descriptor.description.cloned(),
+                        }
+                    }
+                };
+            );
+
+            types.push(match entity_kind {
+                ARTIFACT_TYPE => type_info!(ArtifactType),
+                CAPABILITY_TYPE => type_info!(CapabilityType),
+                DATA_TYPE => type_info!(DataType),
+                GROUP_TYPE => type_info!(GroupType),
+                INTERFACE_TYPE => type_info!(InterfaceType),
+                NODE_TYPE => type_info!(NodeType),
+                POLICY_TYPE => type_info!(PolicyType),
+                RELATIONSHIP_TYPE => type_info!(RelationshipType),
+                _ => continue,
+            });
+        }
+
+        types
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     super::{super::super::grammar::*, dialect::*},
     artifact_definition::*,
+    repository_definition::*,
     value_assignment::*,
 };
 
@@ -36,6 +37,7 @@ where
     /// file.
     ///
     /// Puccini note: Should not be mandatory for assignments.
+    #[resolve]
     #[depict(option, style(string))]
     pub file: Option<ByteString>,
 
@@ -81,6 +83,11 @@ where
     #[depict(iter(kv), as(depict), key_style(string))]
     pub properties: ValueAssignments<AnnotatedT>,
 
+    /// Location of the artifact's file, combining the repository URL or the source URL with the
+    /// file URI.
+    #[depict(option, style(string))]
+    pub location: Option<ByteString>,
+
     #[resolve(annotations)]
     #[depict(skip)]
     pub(crate) annotations: StructAnnotations,
@@ -112,11 +119,31 @@ where
             .metadata
             .set_tosca_version(self.artifact_version.as_ref().map(|version| version.to_string()));
 
+        // Used by $get_artifact
+        if let Some(location) = &self.location {
+            vertex_template.template.metadata.into_insert("tosca:location", location.clone());
+        }
+
+        if let Some(checksum) = &self.checksum {
+            vertex_template.template.metadata.into_insert("tosca:checksum", checksum.clone());
+        }
+
+        if let Some(checksum_algorithm) = &self.checksum_algorithm {
+            vertex_template.template.metadata.into_insert("tosca:checksum_algorithm", checksum_algorithm.clone());
+        }
+
         for (name, value_assignment) in &self.properties {
-            vertex_template
-                .template
-                .property_templates
-                .insert(name.clone(), value_assignment.compile(PROPERTY_NAME, true, directory, store.clone(), errors)?);
+            vertex_template.template.property_templates.insert(
+                name.clone(),
+                value_assignment.compile(
+                    PROPERTY_NAME,
+                    format!("properties.{}", name).into(),
+                    true,
+                    directory,
+                    store.clone(),
+                    errors,
+                )?,
+            );
         }
 
         Ok(())
@@ -158,6 +185,24 @@ where
             if_none_clone!(checksum_algorithm, self, parent);
         }
 
+        if let Some(file) = self.file.clone() {
+            self.location = match self.repository.clone() {
+                Some(repository) => catalog
+                    .get_complete_entity::<RepositoryDefinition<AnnotatedT>, _, _>(
+                        REPOSITORY,
+                        &repository.into(),
+                        source_id,
+                        &mut errors.with_field_annotations(self, "repository"),
+                    )?
+                    .map(|repository| join_location(&repository.url, &file)),
+
+                None => Some(match source_id {
+                    SourceID::URL(url) => join_location(base_location(url), &file),
+                    _ => file,
+                }),
+            };
+        }
+
         Ok(())
     }
 }
@@ -177,11 +222,29 @@ where
             checksum: self.checksum.clone(),
             checksum_algorithm: self.checksum_algorithm.clone(),
             properties: self.properties.convert_into_scope(scope),
+            location: None,
             annotations: self.annotations.clone(), // same fields
         }
     }
 }
 
+// Without the last path segment
+fn base_location(url: &str) -> &str {
+    match url.rfind('/') {
+        Some(index) => &url[..index],
+        None => "",
+    }
+}
+
+// Absolute files are not joined
+fn join_location(base: &str, file: &ByteString) -> ByteString {
+    if base.is_empty() || file.starts_with('/') || file.contains("://") {
+        file.clone()
+    } else {
+        format!("{}/{}", base.trim_end_matches('/'), file).into()
+    }
+}
+
 //
 // ArtifactAssignments
 //
@@ -127,6 +127,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<ArtifactDefinition<AnnotatedT>> for ArtifactDefinition<AnnotatedT>
@@ -1,6 +1,7 @@
 use super::{
     super::{super::super::grammar::*, data::*, dialect::*},
     data_type::*,
+    function_definition::*,
 };
 
 use {
@@ -98,6 +99,14 @@ where
             }
         }
 
+        if let Some(default) = &mut self.default {
+            complete_function_calls(default, catalog, source_id, errors)?;
+        }
+
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
         self.validation.finalize_validation(None);
 
         let data_type = get_complete_entity!(DATA_TYPE, DataType, self, type_name, catalog, source_id, errors);
@@ -115,6 +124,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<AttributeDefinition<AnnotatedT>> for AttributeDefinition<AnnotatedT>
@@ -157,6 +157,10 @@ where
 
         Ok(())
     }
+
+    fn declared_kind() -> Option<&'static str> {
+        Some("capability")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<CapabilityAssignment<AnnotatedT>> for CapabilityDefinition<AnnotatedT>
@@ -140,6 +140,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<CapabilityDefinition<AnnotatedT>> for CapabilityDefinition<AnnotatedT>
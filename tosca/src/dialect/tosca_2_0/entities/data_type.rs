@@ -1,5 +1,6 @@
 use super::{
     super::{super::super::grammar::*, data::*, dialect::*},
+    function_definition::*,
     property_definition::*,
 };
 
@@ -112,6 +113,22 @@ where
     #[depict(option, style(symbol))]
     pub scalar_data_kind: Option<DataKind>,
 
+    /// Validation without the schema, used for validators in value schemas.
+    #[depict(skip)]
+    pub(crate) value_validation: Option<Expression<AnnotatedT>>,
+
+    /// Data types of the properties, used for struct schemas.
+    #[depict(skip)]
+    pub(crate) property_data_types: BTreeMap<ByteString, DataTypeReference>,
+
+    /// Data type of the keys, used for map schemas.
+    #[depict(skip)]
+    pub(crate) key_data_type: Option<DataTypeReference>,
+
+    /// Data type of the entries, used for list and map schemas.
+    #[depict(skip)]
+    pub(crate) entry_data_type: Option<DataTypeReference>,
+
     #[resolve(annotations)]
     #[depict(skip)]
     pub(crate) annotations: StructAnnotations,
@@ -124,38 +141,50 @@ impl_type_entity!(DataType);
 
 impl<AnnotatedT> DataType<AnnotatedT>
 where
-    AnnotatedT: Annotated + Clone + Default,
+    AnnotatedT: 'static + Annotated + Clone + Default,
 {
     /// Initialize a schema. Return a reference to the relevant value schema if there is one.
-    pub fn initialize_schema(&self, schema: &mut Schema<AnnotatedT>) -> Option<SchemaReference> {
+    ///
+    /// Referenced data types are looked up in the catalog. The stack contains the references
+    /// currently being initialized, so that recursive data types end the recursion.
+    pub fn initialize_schema(
+        &self,
+        schema: &mut Schema<AnnotatedT>,
+        catalog: &mut Catalog,
+        stack: &mut Vec<DataTypeReference>,
+    ) -> Option<SchemaReference> {
         if let Some(data_kind) = self.data_kind {
             match data_kind {
                 DataKind::Scalar => {
                     if !self.units.is_empty() {
-                        return Some(schema.get_or_add_value(self.scalar_schema().into()));
+                        let mut scalar_schema = self.scalar_schema();
+                        scalar_schema.validator = self.value_validation.clone();
+                        return Some(schema.get_or_add_value(scalar_schema.into()));
                     }
                 }
 
                 DataKind::List => {
                     if self.entry_schema.is_some() {
-                        return self.initialize_list_schema(schema);
+                        return self.initialize_list_schema(schema, catalog, stack);
                     }
                 }
 
                 DataKind::Map => {
                     if self.entry_schema.is_some() {
-                        return self.initialize_map_schema(schema);
+                        return self.initialize_map_schema(schema, catalog, stack);
                     }
                 }
 
                 DataKind::Struct => {
                     if !self.properties.is_empty() {
-                        return self.initialize_struct_schema(schema);
+                        return self.initialize_struct_schema(schema, catalog, stack);
                     }
                 }
 
                 _ => {
-                    return Some(schema.get_or_add_value(data_kind.into()));
+                    let mut primitive_schema = PrimitiveSchema::from(data_kind);
+                    primitive_schema.validator = self.value_validation.clone();
+                    return Some(schema.get_or_add_value(primitive_schema.into()));
                 }
             }
         }
@@ -163,17 +192,59 @@ where
         None
     }
 
+    /// Get a complete data type for a schema.
+    ///
+    /// Errors are ignored here because they are reported elsewhere. Note that recursive data types
+    /// cannot be retrieved while being completed.
+    fn get_schema_data_type(type_name: &FullName, catalog: &mut Catalog, source_id: &SourceID) -> Option<Self> {
+        let mut ignored_errors = Errors::<ToscaError<WithAnnotations>>::default();
+        match catalog.get_complete_entity::<Self, _, _>(DATA_TYPE, type_name, source_id, &mut ignored_errors) {
+            Ok(Some(data_type)) => Some(data_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Initialize the schema of a referenced data type.
+    fn initialize_referenced_schema(
+        reference: &DataTypeReference,
+        schema: &mut Schema<AnnotatedT>,
+        catalog: &mut Catalog,
+        stack: &mut Vec<DataTypeReference>,
+    ) -> Option<SchemaReference> {
+        if stack.contains(reference) {
+            return None;
+        }
+
+        let data_type = Self::get_schema_data_type(&reference.type_name, catalog, &reference.source_id)?;
+
+        stack.push(reference.clone());
+        let schema_reference = data_type.initialize_schema(schema, catalog, stack);
+        stack.pop();
+
+        schema_reference
+    }
+
     /// Scalar schema.
     pub fn scalar_schema(&self) -> ScalarSchema<AnnotatedT> {
         ScalarSchema::new(self.scalar_data_kind, self.units.clone(), self.canonical_unit.clone(), self.prefixes.clone())
     }
 
     /// Initialize list schema.
-    pub fn initialize_list_schema(&self, schema: &mut Schema<AnnotatedT>) -> Option<SchemaReference> {
+    pub fn initialize_list_schema(
+        &self,
+        schema: &mut Schema<AnnotatedT>,
+        catalog: &mut Catalog,
+        stack: &mut Vec<DataTypeReference>,
+    ) -> Option<SchemaReference> {
         // Placeholder
         let list_reference = schema.add_value(0.into());
 
-        let list_schema = ListSchema::default();
+        let mut list_schema = ListSchema::default();
+        list_schema.entry = self
+            .entry_data_type
+            .as_ref()
+            .and_then(|reference| Self::initialize_referenced_schema(reference, schema, catalog, stack));
+        list_schema.validator = self.value_validation.clone();
 
         // Replace placeholder
         schema.values[list_reference] = list_schema.into();
@@ -181,11 +252,25 @@ where
     }
 
     /// Initialize map schema.
-    pub fn initialize_map_schema(&self, schema: &mut Schema<AnnotatedT>) -> Option<SchemaReference> {
+    pub fn initialize_map_schema(
+        &self,
+        schema: &mut Schema<AnnotatedT>,
+        catalog: &mut Catalog,
+        stack: &mut Vec<DataTypeReference>,
+    ) -> Option<SchemaReference> {
         // Placeholder
         let map_reference = schema.add_value(0.into());
 
-        let map_schema = MapSchema::default();
+        let mut map_schema = MapSchema::default();
+        map_schema.key = self
+            .key_data_type
+            .as_ref()
+            .and_then(|reference| Self::initialize_referenced_schema(reference, schema, catalog, stack));
+        map_schema.entry = self
+            .entry_data_type
+            .as_ref()
+            .and_then(|reference| Self::initialize_referenced_schema(reference, schema, catalog, stack));
+        map_schema.validator = self.value_validation.clone();
 
         // Replace placeholder
         schema.values[map_reference] = map_schema.into();
@@ -193,21 +278,49 @@ where
     }
 
     /// Initialize struct schema.
-    pub fn initialize_struct_schema(&self, schema: &mut Schema<AnnotatedT>) -> Option<SchemaReference> {
+    pub fn initialize_struct_schema(
+        &self,
+        schema: &mut Schema<AnnotatedT>,
+        catalog: &mut Catalog,
+        stack: &mut Vec<DataTypeReference>,
+    ) -> Option<SchemaReference> {
         // Placeholder
         let struct_reference = schema.add_value(0.into());
 
         let mut struct_schema = StructSchema::default();
-        // for (name, property) in &self.properties {
-        //     // TODO: use property's data type
+        struct_schema.validator = self.value_validation.clone();
+
+        for (name, property) in &self.properties {
+            match self
+                .property_data_types
+                .get(name)
+                .and_then(|reference| Self::initialize_referenced_schema(reference, schema, catalog, stack))
+            {
+                Some(field_reference) => {
+                    struct_schema.fields.insert(name.clone(), field_reference);
+                }
 
-        //     let data_type: &Self = todo!();
-        //     //get_complete_entity!(DATA_TYPE, DataType, self, type_name, catalog, source_id, errors);
+                None => {
+                    struct_schema.untyped.insert(name.clone());
+                }
+            }
+
+            match &property.default {
+                Some(default) => {
+                    struct_schema.defaults.insert(name.clone(), default.clone());
+                }
 
-        //     let field_reference = data_type.initialize_schema(schema).unwrap();
+                None => {
+                    if property.required {
+                        struct_schema.required.insert(name.clone());
+                    }
+                }
+            }
 
-        //     struct_schema.fields.insert(name.clone(), field_reference);
-        // }
+            if let Some(validation) = &property.value_validation {
+                struct_schema.validators.insert(name.clone(), validation.clone());
+            }
+        }
 
         // Replace placeholder
         schema.values[struct_reference] = struct_schema.into();
@@ -335,12 +448,47 @@ where
             self.scalar_data_kind = data_type.data_kind;
         }
 
-        let mut schema = Default::default();
-        self.initialize_schema(&mut schema);
-        self.validation.finalize_validation(schema.into_option());
+        self.property_data_types = self
+            .properties
+            .iter()
+            .map(|(name, property)| {
+                (name.clone(), DataTypeReference::new(property.type_name.clone(), source_id.clone()))
+            })
+            .collect();
+
+        self.key_data_type = self
+            .key_schema
+            .as_ref()
+            .and_then(schema_type_name)
+            .map(|type_name| DataTypeReference::new(type_name, source_id.clone()));
+
+        self.entry_data_type = self
+            .entry_schema
+            .as_ref()
+            .and_then(schema_type_name)
+            .map(|type_name| DataTypeReference::new(type_name, source_id.clone()));
+
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
+        self.validation.finalize_validation(None);
 
         if let Some((parent, _scope)) = &parent {
-            complete_validation!(self, parent);
+            self.validation.complete_validation(
+                parent.value_validation.as_ref(),
+                &mut self.annotations,
+                &parent.annotations,
+            );
+        }
+
+        // Value schemas carry the validation, so it must not include the schema itself
+        self.value_validation = self.validation.clone();
+
+        let mut schema = Default::default();
+        self.initialize_schema(&mut schema, catalog, &mut Default::default());
+        if let Some(schema) = schema.into_option() {
+            self.validation = Some(schema.into_validation());
         }
 
         self.completion = Completion::Complete;
@@ -348,9 +496,108 @@ where
     }
 }
 
+// Only the short notation (a type name) is supported
+fn schema_type_name<AnnotatedT>(schema: &Variant<AnnotatedT>) -> Option<FullName> {
+    match schema {
+        Variant::Text(text) => text.inner.parse().ok(),
+        _ => None,
+    }
+}
+
+//
+// DataTypeReference
+//
+
+/// Reference to a [DataType], looked up in the catalog when needed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataTypeReference {
+    /// Type name.
+    pub type_name: FullName,
+
+    /// Source ID of the referrer.
+    pub source_id: SourceID,
+}
+
+impl DataTypeReference {
+    /// Constructor.
+    pub fn new(type_name: FullName, source_id: SourceID) -> Self {
+        Self { type_name, source_id }
+    }
+}
+
 //
 // DataTypes
 //
 
 /// Map of [DataType].
 pub type DataTypes<AnnotatedT> = BTreeMap<Name, DataType<AnnotatedT>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Struct schema of a data type with the properties
+    fn struct_schema(
+        properties: Vec<(&'static str, PropertyDefinition<WithoutAnnotations>)>,
+    ) -> StructSchema<WithoutAnnotations> {
+        let data_type = DataType {
+            properties: properties.into_iter().map(|(name, property)| (name.into(), property)).collect(),
+            ..Default::default()
+        };
+
+        let mut schema = Schema::default();
+        let reference = data_type
+            .initialize_struct_schema(&mut schema, &mut Catalog::default(), &mut Default::default())
+            .expect("struct schema");
+
+        match schema.values.remove(reference) {
+            ValueSchema::Struct(struct_schema) => struct_schema,
+            _ => panic!("not a struct schema"),
+        }
+    }
+
+    #[test]
+    fn required_fields() {
+        let struct_schema = struct_schema(vec![
+            // Properties are required by default
+            ("host", PropertyDefinition::default()),
+            ("port", PropertyDefinition { default: Some("8080".into()), ..Default::default() }),
+            ("comment", PropertyDefinition { required: false, ..Default::default() }),
+        ]);
+
+        // A default satisfies the requirement
+        assert_eq!(struct_schema.required, BTreeSet::from(["host".into()]));
+        assert_eq!(struct_schema.defaults.len(), 1);
+        assert!(struct_schema.defaults.contains_key(&ByteString::from_static("port")));
+
+        // Without data types the fields are untyped
+        assert!(struct_schema.fields.is_empty());
+        assert_eq!(struct_schema.untyped, BTreeSet::from(["comment".into(), "host".into(), "port".into()]));
+    }
+
+    #[test]
+    fn field_validators() {
+        let struct_schema = struct_schema(vec![
+            ("host", PropertyDefinition { value_validation: Some("validation".into()), ..Default::default() }),
+            ("port", PropertyDefinition::default()),
+        ]);
+
+        assert_eq!(struct_schema.validators, BTreeMap::from([("host".into(), "validation".into())]));
+    }
+
+    #[test]
+    fn required_fields_in_expression() {
+        let expression: Expression<WithoutAnnotations> = struct_schema(vec![
+            ("host", PropertyDefinition::default()),
+            ("comment", PropertyDefinition { required: false, ..Default::default() }),
+        ])
+        .into();
+
+        let Expression::Map(map) = expression else {
+            panic!("not a map");
+        };
+
+        assert_eq!(map.get(&"required".into()), Some(&Expression::List(vec!["host".into()])));
+        assert!(!map.contains_key(&"defaults".into()));
+    }
+}
@@ -1,8 +1,14 @@
-use super::{super::super::super::grammar::*, function_signature::*};
+use super::{
+    super::{super::super::grammar::*, data::*, dialect::*, native::*},
+    function_signature::*,
+};
 
 use {
-    compris::{annotate::*, resolve::*},
-    kutil::{cli::depict::*, std::immutable::*},
+    compris::{annotate::*, normal::*, resolve::*},
+    kutil::{
+        cli::depict::*,
+        std::{error::*, immutable::*},
+    },
     std::collections::*,
 };
 
@@ -33,6 +39,7 @@ where
     AnnotatedT: Annotated + Clone + Default,
 {
     /// The map of signature definitions.
+    #[resolve]
     #[depict(iter(kv), as(depict), key_style(string))]
     pub signatures: FunctionSignatures<AnnotatedT>,
 
@@ -49,6 +56,105 @@ where
     #[resolve(annotations)]
     #[depict(skip)]
     pub(crate) annotations: StructAnnotations,
+
+    #[depict(skip)]
+    completion: Completion,
+}
+
+impl<AnnotatedT> FunctionDefinition<AnnotatedT>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+{
+    /// Complete a call to this function.
+    ///
+    /// The arguments are validated against the signatures. The first matching signature's
+    /// implementation, if it has one, determines the plugin for the call.
+    pub fn complete_call<ErrorRecipientT>(
+        &self,
+        full_name: &FullName,
+        call: &mut Call<AnnotatedT>,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<WithAnnotations>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+    {
+        if self.signatures.is_empty() {
+            return Ok(());
+        }
+
+        let mut reason = None;
+
+        for signature in self.signatures.values() {
+            if !signature.accepts_arity(call.arguments.len()) {
+                continue;
+            }
+
+            match Self::validate_arguments(signature, call) {
+                Ok(()) => {
+                    if let Some(plugin) = signature.plugin() {
+                        call.plugin = plugin;
+                        call.function.inner = full_name.name.0.clone();
+                    }
+
+                    return Ok(());
+                }
+
+                Err(message) => {
+                    if reason.is_none() {
+                        reason = Some(message);
+                    }
+                }
+            }
+        }
+
+        let reason = reason.unwrap_or_else(|| format!("wrong number of arguments: {}", call.arguments.len()));
+        errors.give(InvalidCallError::new(full_name.to_string(), reason).with_annotations_from(call))?;
+
+        Ok(())
+    }
+
+    fn validate_arguments(signature: &FunctionSignature<AnnotatedT>, call: &Call<AnnotatedT>) -> Result<(), String> {
+        for (index, argument) in call.arguments.iter().enumerate() {
+            if let Expression::Literal(literal) = argument
+                && let Some(schema) = signature.argument_schema(index)
+                && let Some(data_kind) = schema.data_kind
+                && !data_kind.is_literal(literal)
+            {
+                return Err(format!("argument {} is not {}: {}", index, schema.type_name, literal));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<AnnotatedT> Entity for FunctionDefinition<AnnotatedT>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+{
+    fn completion(&self) -> Completion {
+        self.completion
+    }
+
+    fn complete(
+        &mut self,
+        catalog: &mut Catalog,
+        source_id: &SourceID,
+        _callstack: &mut CallStack,
+        errors: ToscaErrorRecipientRef,
+    ) -> Result<(), ToscaError<WithAnnotations>> {
+        assert!(self.completion == Completion::Incomplete);
+        self.completion = Completion::Cannot;
+
+        let errors = &mut errors.to_error_recipient();
+
+        for signature in self.signatures.values_mut() {
+            signature.complete(catalog, source_id, errors)?;
+        }
+
+        self.completion = Completion::Complete;
+        Ok(())
+    }
 }
 
 //
@@ -56,4 +162,297 @@ where
 //
 
 /// Map of [FunctionDefinition].
-pub type FunctionDefinitions<AnnotatedT> = BTreeMap<ByteString, FunctionDefinition<AnnotatedT>>;
+pub type FunctionDefinitions<AnnotatedT> = BTreeMap<Name, FunctionDefinition<AnnotatedT>>;
+
+/// Complete calls to declared functions, recursively.
+///
+/// Calls to built-in functions are validated against their [BuiltinSignature]. Calls to pure
+/// built-in functions with literal arguments are folded into their literal result, so that they
+/// need not be evaluated at runtime. Calls to other functions that are not declared are left as
+/// is.
+///
+/// Then the inputs referred to by `$get_input` are recorded for linting, and calls to plugins that
+/// are not registered are reported.
+pub fn complete_function_calls<AnnotatedT, ErrorRecipientT>(
+    expression: &mut Expression<AnnotatedT>,
+    catalog: &mut Catalog,
+    source_id: &SourceID,
+    errors: &mut ErrorRecipientT,
+) -> Result<(), ToscaError<WithAnnotations>>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+    ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+{
+    complete_calls(expression, catalog, source_id, errors)?;
+    collect_input_references(expression, catalog, source_id);
+    validate_call_plugins(expression, catalog, errors)
+}
+
+fn complete_calls<AnnotatedT, ErrorRecipientT>(
+    expression: &mut Expression<AnnotatedT>,
+    catalog: &mut Catalog,
+    source_id: &SourceID,
+    errors: &mut ErrorRecipientT,
+) -> Result<(), ToscaError<WithAnnotations>>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+    ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+{
+    match expression {
+        Expression::List(list) => {
+            for item in list {
+                complete_calls(item, catalog, source_id, errors)?;
+            }
+        }
+
+        Expression::Map(map) => {
+            for value in map.values_mut() {
+                complete_calls(value, catalog, source_id, errors)?;
+            }
+        }
+
+        Expression::Call(call) => {
+            for argument in &mut call.arguments {
+                complete_calls(argument, catalog, source_id, errors)?;
+            }
+
+            if call.is_native()
+                && let Ok(full_name) = call.function.inner.parse::<FullName>()
+                && catalog.try_lookup(FUNCTION, &full_name, source_id).is_some()
+                && let Some(function_definition) = catalog.get_complete_entity::<FunctionDefinition<AnnotatedT>, _, _>(
+                    FUNCTION, &full_name, source_id, errors,
+                )?
+            {
+                function_definition.complete_call(&full_name, call, errors)?;
+            } else if call.is_native()
+                && let Some(signature) = BuiltinSignature::find(&call.function.inner)
+            {
+                match signature.validate(call) {
+                    Ok(_) => {
+                        // Constant folding
+                        if is_pure_function(signature.name) {
+                            match dispatch_native(signature.name, &call.arguments, &PureHost) {
+                                Ok(Some(value)) => {
+                                    *expression = Expression::Literal(value.with_annotations_from(call));
+                                }
+
+                                Ok(None) => {}
+
+                                Err(reason) => errors.give(
+                                    InvalidCallError::new(signature.name.into(), reason).with_annotations_from(call),
+                                )?,
+                            }
+                        }
+                    }
+
+                    Err(reason) => {
+                        errors.give(InvalidCallError::new(signature.name.into(), reason).with_annotations_from(call))?
+                    }
+                }
+            }
+        }
+
+        Expression::Literal(_) => {}
+    }
+
+    Ok(())
+}
+
+// For linting
+fn collect_input_references<AnnotatedT>(
+    expression: &Expression<AnnotatedT>,
+    catalog: &mut Catalog,
+    source_id: &SourceID,
+) {
+    match expression {
+        Expression::List(list) => {
+            for item in list {
+                collect_input_references(item, catalog, source_id);
+            }
+        }
+
+        Expression::Map(map) => {
+            for value in map.values() {
+                collect_input_references(value, catalog, source_id);
+            }
+        }
+
+        Expression::Call(call) => {
+            for argument in &call.arguments {
+                collect_input_references(argument, catalog, source_id);
+            }
+
+            if call.is_native()
+                && (call.function.inner == "get_input")
+                && let Some(input_name) = call.arguments.first().and_then(get_input_name)
+            {
+                catalog.references.inputs.insert((source_id.clone(), input_name));
+            }
+        }
+
+        Expression::Literal(_) => {}
+    }
+}
+
+// All calls, whether to built-in, declared, or undeclared functions
+fn validate_call_plugins<AnnotatedT, ErrorRecipientT>(
+    expression: &Expression<AnnotatedT>,
+    catalog: &Catalog,
+    errors: &mut ErrorRecipientT,
+) -> Result<(), ToscaError<WithAnnotations>>
+where
+    AnnotatedT: Annotated,
+    ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+{
+    match expression {
+        Expression::List(list) => {
+            for item in list {
+                validate_call_plugins(item, catalog, errors)?;
+            }
+        }
+
+        Expression::Map(map) => {
+            for value in map.values() {
+                validate_call_plugins(value, catalog, errors)?;
+            }
+        }
+
+        Expression::Call(call) => {
+            for argument in &call.arguments {
+                validate_call_plugins(argument, catalog, errors)?;
+            }
+
+            if !catalog.is_plugin_registered(&call.plugin) {
+                errors.give(
+                    InvalidCallError::new(
+                        call.function.inner.to_string(),
+                        format!("plugin not registered: {}", call.plugin),
+                    )
+                    .with_annotations_from(call),
+                )?;
+            }
+        }
+
+        Expression::Literal(_) => {}
+    }
+
+    Ok(())
+}
+
+// The input name is either the argument or the first item of a list argument
+fn get_input_name<AnnotatedT>(argument: &Expression<AnnotatedT>) -> Option<ByteString> {
+    match argument {
+        Expression::Literal(Variant::Text(text)) => Some(text.inner.clone()),
+        Expression::List(list) => match list.first() {
+            Some(Expression::Literal(Variant::Text(text))) => Some(text.inner.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(
+        function_name: &'static str,
+        arguments: Vec<Expression<WithoutAnnotations>>,
+    ) -> Expression<WithoutAnnotations> {
+        Call::new_native(ByteString::from_static(function_name).into(), arguments).into()
+    }
+
+    // The completed expression and whether there were errors
+    fn complete(expression: Expression<WithoutAnnotations>) -> (Expression<WithoutAnnotations>, bool) {
+        complete_in(expression, &mut Catalog::default())
+    }
+
+    fn complete_in(
+        mut expression: Expression<WithoutAnnotations>,
+        catalog: &mut Catalog,
+    ) -> (Expression<WithoutAnnotations>, bool) {
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+        complete_function_calls(&mut expression, catalog, &Default::default(), &mut errors).expect("complete");
+        (expression, !errors.is_empty())
+    }
+
+    #[test]
+    fn fold_pure_call() {
+        assert_eq!(complete(call("concat", vec!["a".into(), "b".into()])), ("ab".into(), false));
+        assert_eq!(
+            complete(call("length", vec![vec!["a".into(), "b".into()].into()])),
+            (Variant::from(2i64).into(), false)
+        );
+    }
+
+    #[test]
+    fn fold_nested_calls() {
+        let product = call("product", vec![2u64.into(), 3u64.into()]);
+        assert_eq!(complete(call("sum", vec![product, 1u64.into()])), (7u64.into(), false));
+
+        let list = vec![call("concat", vec!["a".into(), "b".into()]), "c".into()].into();
+        assert_eq!(complete(call("join", vec![list, "-".into()])), ("ab-c".into(), false));
+    }
+
+    #[test]
+    fn leave_graph_calls() {
+        let get_property = call("get_property", vec!["SELF".into(), "port".into()]);
+        assert_eq!(complete(get_property.clone()), (get_property, false));
+
+        let concat = call("concat", vec![call("get_input", vec!["name".into()]), "b".into()]);
+        assert_eq!(complete(concat.clone()), (concat, false));
+    }
+
+    #[test]
+    fn leave_scalar_arithmetic() {
+        let sum = call("sum", vec!["1 GB".into(), "512 MB".into()]);
+        assert_eq!(complete(sum.clone()), (sum, false));
+
+        let product = call("product", vec!["1 GB".into(), 2u64.into()]);
+        assert_eq!(complete(product.clone()), (product, false));
+    }
+
+    #[test]
+    fn report_folding_errors() {
+        let quotient = call("quotient", vec![1u64.into(), 0u64.into()]);
+        assert_eq!(complete(quotient.clone()), (quotient, true));
+
+        let product = call("product", vec![u64::MAX.into(), 2u64.into()]);
+        assert_eq!(complete(product.clone()), (product, true));
+    }
+
+    #[test]
+    fn report_invalid_calls() {
+        let not = call("not", vec!["true".into()]);
+        assert_eq!(complete(not.clone()), (not, true));
+
+        let xor = call("xor", vec![Variant::from(true).into()]);
+        assert_eq!(complete(xor.clone()), (xor, true));
+    }
+
+    #[test]
+    fn collect_input_references() {
+        let mut catalog = Catalog::default();
+        let concat = call("concat", vec![call("get_input", vec!["name".into()]), "b".into()]);
+        complete_in(concat, &mut catalog);
+        assert!(catalog.references.inputs.contains(&(Default::default(), "name".into())));
+    }
+
+    #[test]
+    fn report_unregistered_plugins() {
+        // Built-in calls that are not folded are checked, too
+        let concat = call("concat", vec![call("get_input", vec!["name".into()]), "b".into()]);
+
+        let mut catalog = Catalog::default();
+        catalog.add_plugin_id("other".into());
+        assert_eq!(complete_in(concat.clone(), &mut catalog), (concat.clone(), true));
+
+        catalog.add_plugin_id(DIALECT_ID);
+        assert_eq!(complete_in(concat.clone(), &mut catalog), (concat, false));
+
+        // Folded calls are no longer calls
+        let mut catalog = Catalog::default();
+        catalog.add_plugin_id("other".into());
+        assert_eq!(complete_in(call("concat", vec!["a".into(), "b".into()]), &mut catalog), ("ab".into(), false));
+    }
+}
@@ -1,8 +1,11 @@
-use super::implementation_definition::*;
+use super::{super::super::super::grammar::*, implementation_definition::*, schema_definition::*};
 
 use {
-    compris::{annotate::*, resolve::*},
-    kutil::{cli::depict::*, std::immutable::*},
+    compris::{annotate::*, normal::*, resolve::*},
+    kutil::{
+        cli::depict::*,
+        std::{error::*, immutable::*},
+    },
     std::collections::*,
 };
 
@@ -19,6 +22,7 @@ use {
 /// intrinsic functions may accept other argument definition syntaxes.
 #[derive(Clone, Debug, Default, Depict, Resolve)]
 #[depict(tag = tag::source_and_span)]
+#[resolve(annotated_parameter=AnnotatedT)]
 pub struct FunctionSignature<AnnotatedT>
 where
     AnnotatedT: Annotated + Clone + Default,
@@ -26,27 +30,32 @@ where
     /// All defined arguments must be used in the function invocation (and in the order defined
     /// here). If no arguments are defined, the signature either accepts no arguments or any
     /// arguments of any form (depending on if the variadic keyname is false or true).
-    #[depict(iter(kv), key_style(string), style(string))]
-    pub arguments: BTreeMap<ByteString, ByteString>,
+    #[resolve]
+    #[depict(iter(item), as(depict))]
+    pub arguments: SchemaDefinitions<AnnotatedT>,
 
     /// Optional arguments may be used in the function invocation after the regular arguments.
     /// Still the order defined here must be respected.
-    #[depict(iter(kv), key_style(string), style(string))]
-    pub optional_arguments: BTreeMap<ByteString, ByteString>,
+    #[resolve]
+    #[depict(iter(item), as(depict))]
+    pub optional_arguments: SchemaDefinitions<AnnotatedT>,
 
     /// Specifies if the last defined argument (or optional_arguments if defined) may be repeated
     /// any number of times in the function invocation. If this value is not specified, a default
     /// of False is assumed.
+    #[resolve]
     #[depict(style(symbol))]
     pub variadic: bool,
 
     /// Defines the type of the function result. If no result keyname is defined, then the function
     /// may return any result
-    #[depict(style(symbol))]
-    pub result: bool,
+    #[resolve]
+    #[depict(option, as(depict))]
+    pub result: Option<SchemaDefinition<AnnotatedT>>,
 
     /// Defines the implementation (e.g., artifact) for the function. The same definition as for
     /// operation/notification implementation is used.
+    #[resolve]
     #[depict(option, as(depict))]
     pub implementation: Option<ImplementationDefinition<AnnotatedT>>,
 
@@ -55,6 +64,78 @@ where
     pub(crate) annotations: StructAnnotations,
 }
 
+impl<AnnotatedT> FunctionSignature<AnnotatedT>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+{
+    /// Complete.
+    pub fn complete<ErrorRecipientT>(
+        &mut self,
+        catalog: &mut Catalog,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<WithAnnotations>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+    {
+        for argument in &mut self.arguments {
+            argument.complete(catalog, source_id, errors)?;
+        }
+
+        for optional_argument in &mut self.optional_arguments {
+            optional_argument.complete(catalog, source_id, errors)?;
+        }
+
+        if let Some(result) = &mut self.result {
+            result.complete(catalog, source_id, errors)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the number of arguments is acceptable.
+    pub fn accepts_arity(&self, arity: usize) -> bool {
+        let minimum = self.arguments.len();
+        let maximum = minimum + self.optional_arguments.len();
+
+        if self.variadic {
+            // An empty variadic signature accepts any arguments
+            (maximum == 0) || (arity >= minimum)
+        } else {
+            (arity >= minimum) && (arity <= maximum)
+        }
+    }
+
+    /// The schema definition for an argument.
+    ///
+    /// Takes into account optional and variadic arguments.
+    pub fn argument_schema(&self, index: usize) -> Option<&SchemaDefinition<AnnotatedT>> {
+        let length = self.arguments.len();
+        if index < length {
+            return self.arguments.get(index);
+        }
+
+        let optional_length = self.optional_arguments.len();
+        if index < length + optional_length {
+            return self.optional_arguments.get(index - length);
+        }
+
+        if self.variadic { self.optional_arguments.last().or_else(|| self.arguments.last()) } else { None }
+    }
+
+    /// The plugin ID from the implementation, if there is one.
+    pub fn plugin(&self) -> Option<ByteString> {
+        match &self.implementation {
+            Some(implementation) => match &implementation.primary {
+                Variant::Text(text) => Some(text.inner.clone()),
+                _ => None,
+            },
+
+            None => None,
+        }
+    }
+}
+
 //
 // FunctionSignatures
 //
@@ -72,6 +72,31 @@ where
     completion: Completion,
 }
 
+impl<AnnotatedT> GroupTemplate<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// Compile to Floria.
+    ///
+    /// Groups are compiled to Floria classes. The member node templates are added to the class
+    /// when they are compiled.
+    pub fn compile<ErrorRecipientT>(
+        &self,
+        class: &mut floria::Class,
+        _errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<AnnotatedT>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        class.metadata.set_tosca_entity_static(DIALECT_ID, GROUP_TEMPLATE_NAME);
+        class.metadata.set_tosca_description(self.description.as_ref());
+        class.metadata.merge_tosca_metadata(&self.metadata);
+        class.metadata.set_tosca_members(&self.members);
+
+        Ok(())
+    }
+}
+
 impl<AnnotatedT> Entity for GroupTemplate<AnnotatedT>
 where
     AnnotatedT: 'static + Annotated + Clone + Default,
@@ -106,6 +106,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<InterfaceDefinition<AnnotatedT>> for InterfaceDefinition<AnnotatedT>
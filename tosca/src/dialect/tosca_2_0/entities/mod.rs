@@ -33,6 +33,7 @@ mod relationship_type;
 mod repository_definition;
 mod requirement_assignment;
 mod requirement_definition;
+mod schema_definition;
 mod service_template;
 mod trigger_definition;
 mod value_assignment;
@@ -47,6 +48,6 @@ pub use {
     notification_assignment::*, notification_definition::*, operation_assignment::*, operation_definition::*,
     parameter_definition::*, policy_template::*, policy_type::*, property_definition::*, relationship_assignment::*,
     relationship_definition::*, relationship_template::*, relationship_type::*, repository_definition::*,
-    requirement_assignment::*, requirement_definition::*, service_template::*, trigger_definition::*,
-    value_assignment::*, workflow_definition::*,
+    requirement_assignment::*, requirement_definition::*, schema_definition::*, service_template::*,
+    trigger_definition::*, value_assignment::*, workflow_definition::*,
 };
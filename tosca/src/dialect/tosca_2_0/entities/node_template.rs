@@ -1,7 +1,8 @@
 use super::{
-    super::{super::super::grammar::*, dialect::*},
+    super::{super::super::grammar::*, data::*, dialect::*},
     artifact_assignment::*,
     capability_assignment::*,
+    function_definition::*,
     interface_assignment::*,
     node_type::*,
     requirement_assignment::*,
@@ -95,7 +96,7 @@ where
     /// existing node if this node template is marked with the "select" directive.
     #[resolve]
     #[depict(option, as(depict))]
-    pub node_filter: Option<Variant<AnnotatedT>>,
+    pub node_filter: Option<Expression<AnnotatedT>>,
 
     /// The optional (symbolic) name of another node template from which to copy all keynames and
     /// values into this node template.
@@ -134,10 +135,17 @@ where
         vertex_template.template.metadata.set_tosca_directives(&self.directives);
 
         for (name, value_assignment) in &self.properties {
-            vertex_template
-                .template
-                .property_templates
-                .insert(name.clone(), value_assignment.compile(PROPERTY_NAME, true, directory, store.clone(), errors)?);
+            vertex_template.template.property_templates.insert(
+                name.clone(),
+                value_assignment.compile(
+                    PROPERTY_NAME,
+                    format!("properties.{}", name).into(),
+                    true,
+                    directory,
+                    store.clone(),
+                    errors,
+                )?,
+            );
         }
 
         // TODO: name collisions?
@@ -145,7 +153,14 @@ where
         for (name, value_assignment) in &self.attributes {
             vertex_template.template.property_templates.insert(
                 name.clone(),
-                value_assignment.compile(ATTRIBUTE_NAME, false, directory, store.clone(), errors)?,
+                value_assignment.compile(
+                    ATTRIBUTE_NAME,
+                    format!("attributes.{}", name).into(),
+                    false,
+                    directory,
+                    store.clone(),
+                    errors,
+                )?,
             );
         }
 
@@ -173,6 +188,11 @@ where
 
         let errors = &mut errors.to_error_recipient();
 
+        if let Some(node_filter) = &mut self.node_filter {
+            complete_function_calls(node_filter, catalog, source_id, errors)?;
+        }
+
+        let mut copied = Vec::default();
         if let Some(copy) = &self.copy {
             let Some(copy) = catalog.get_complete_entity::<NodeTemplate<AnnotatedT>, _, _>(
                 NODE_TEMPLATE,
@@ -184,18 +204,25 @@ where
                 return Ok(());
             };
 
-            if_empty_clone!(type_name, self, copy);
-            if_none_clone!(description, self, copy);
-            if_empty_clone!(metadata, self, copy);
-            if_empty_clone!(directives, self, copy);
-            if_empty_clone!(properties, self, copy);
-            if_empty_clone!(attributes, self, copy);
-            if_empty_clone!(requirements, self, copy);
-            if_empty_clone!(capabilities, self, copy);
-            if_empty_clone!(interfaces, self, copy);
-            if_empty_clone!(artifacts, self, copy);
-            if_none_clone!(count, self, copy);
-            if_none_clone!(node_filter, self, copy);
+            if_empty_clone!(type_name, self, copy, copied);
+            if_none_clone!(description, self, copy, copied);
+            if_empty_clone!(metadata, self, copy, copied);
+            if_empty_clone!(directives, self, copy, copied);
+            if_empty_clone!(properties, self, copy, copied);
+            if_empty_clone!(attributes, self, copy, copied);
+            if_empty_clone!(requirements, self, copy, copied);
+            if_empty_clone!(capabilities, self, copy, copied);
+            if_empty_clone!(interfaces, self, copy, copied);
+            if_empty_clone!(artifacts, self, copy, copied);
+            if_none_clone!(count, self, copy, copied);
+            if_none_clone!(node_filter, self, copy, copied);
+        }
+
+        if let Some(copy) = &self.copy {
+            let from = catalog.provenance_entity(NODE_TEMPLATE, &copy.clone().into(), source_id);
+            for field_name in copied {
+                catalog.provenance.add_copied(field_name, from.clone(), self.field_annotations(field_name).cloned());
+            }
         }
 
         if self.type_name.is_empty() {
@@ -207,6 +234,13 @@ where
 
         complete_map_for_field!(properties, self, node_type, catalog, source_id, errors);
         complete_map_for_field!(attributes, self, node_type, catalog, source_id, errors);
+
+        if catalog.report_undeclared {
+            for (_, requirement) in &self.requirements {
+                requirement.validate_node(catalog, source_id, errors)?;
+            }
+        }
+
         complete_tagged_values_for_field!(requirements, self, node_type, catalog, source_id, errors);
         complete_map_for_field!(capabilities, self, node_type, catalog, source_id, errors);
         complete_map_for_field!(interfaces, self, node_type, catalog, source_id, errors);
@@ -1,6 +1,7 @@
 use super::{
     super::{super::super::grammar::*, data::*, dialect::*},
     data_type::*,
+    function_definition::*,
 };
 
 use {
@@ -117,6 +118,18 @@ where
             if_none_clone!(type_name, self, parent);
         }
 
+        if let Some(value) = &mut self.value {
+            complete_function_calls(value, catalog, source_id, errors)?;
+        }
+
+        if let Some(default) = &mut self.default {
+            complete_function_calls(default, catalog, source_id, errors)?;
+        }
+
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
         self.validation.finalize_validation(None);
 
         if let Some(type_name) = &self.type_name {
@@ -148,6 +161,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name").or_else(|| self.field_annotations("value"))
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<ParameterDefinition<AnnotatedT>> for ParameterDefinition<AnnotatedT>
@@ -72,6 +72,30 @@ where
     completion: Completion,
 }
 
+impl<AnnotatedT> PolicyTemplate<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// Compile to Floria.
+    pub fn compile<ErrorRecipientT>(
+        &self,
+        vertex_template: &mut floria::VertexTemplate,
+        _errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<AnnotatedT>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        // TODO: properties and triggers
+
+        vertex_template.template.metadata.set_tosca_entity_static(DIALECT_ID, POLICY_TEMPLATE_NAME);
+        vertex_template.template.metadata.set_tosca_description(self.description.as_ref());
+        vertex_template.template.metadata.merge_tosca_metadata(&self.metadata);
+        vertex_template.template.metadata.set_tosca_targets(&self.targets);
+
+        Ok(())
+    }
+}
+
 impl<AnnotatedT> Entity for PolicyTemplate<AnnotatedT>
 where
     AnnotatedT: 'static + Annotated + Clone + Default,
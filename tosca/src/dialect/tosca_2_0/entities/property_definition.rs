@@ -1,6 +1,7 @@
 use super::{
     super::{super::super::grammar::*, data::*, dialect::*},
     data_type::*,
+    function_definition::*,
 };
 
 use {
@@ -87,6 +88,10 @@ where
     #[depict(option, as(depict))]
     pub entry_schema: Option<Variant<AnnotatedT>>,
 
+    /// Validation without the data type's validation, used for struct field validators.
+    #[depict(skip)]
+    pub(crate) value_validation: Option<Expression<AnnotatedT>>,
+
     #[resolve(annotations)]
     #[depict(skip)]
     pub(crate) annotations: StructAnnotations,
@@ -114,7 +119,20 @@ where
             }
         }
 
+        if let Some(default) = &mut self.default {
+            complete_function_calls(default, catalog, source_id, errors)?;
+        }
+
+        if let Some(value) = &mut self.value {
+            complete_function_calls(value, catalog, source_id, errors)?;
+        }
+
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
         self.validation.finalize_validation(None);
+        self.value_validation = self.validation.clone();
 
         let data_type = get_complete_entity!(DATA_TYPE, DataType, self, type_name, catalog, source_id, errors);
 
@@ -135,6 +153,23 @@ where
 
         Ok(())
     }
+
+    fn lint(&self, name: &ByteString, parent: &Self, catalog: &mut Catalog, source_id: &SourceID) {
+        if let Some(default) = &self.default
+            && (parent.default.as_ref() == Some(default))
+        {
+            catalog.add_lint_warning(LintWarning::new(
+                LintRule::RedundantDefault,
+                format!("property {:?} redeclares the default of its parent without changing it", name.to_string()),
+                source_id.clone(),
+                default.annotations().cloned(),
+            ));
+        }
+    }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("type_name")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<PropertyDefinition<AnnotatedT>> for PropertyDefinition<AnnotatedT>
@@ -152,6 +187,7 @@ where
             validation: self.validation.clone(),
             key_schema: self.key_schema.clone(),
             entry_schema: self.entry_schema.clone(),
+            value_validation: self.value_validation.clone(),
             annotations: self.annotations.clone(),
         }
     }
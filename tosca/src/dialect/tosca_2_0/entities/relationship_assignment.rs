@@ -1,5 +1,9 @@
 use super::{
-    super::super::super::grammar::*, interface_assignment::*, relationship_definition::*, value_assignment::*,
+    super::{super::super::grammar::*, dialect::*},
+    interface_assignment::*,
+    relationship_definition::*,
+    relationship_template::*,
+    value_assignment::*,
 };
 
 use {
@@ -29,7 +33,10 @@ where
 {
     /// The optional keyname used to provide the name of the relationship type for the requirement
     /// assignment's relationship.
-    #[resolve(required, key = "type")]
+    ///
+    /// The short notation can instead name a relationship template, in which case it is moved to
+    /// [template_name](Self::template_name) during completion.
+    #[resolve(single, key = "type")]
     #[depict(as(depict))]
     pub type_name: FullName,
 
@@ -49,6 +56,11 @@ where
     #[depict(iter(kv), as(depict), key_style(string))]
     pub interfaces: InterfaceAssignments<AnnotatedT>,
 
+    /// The symbolic name of the relationship template from which this relationship assignment
+    /// was filled in.
+    #[depict(option, as(depict))]
+    pub template_name: Option<Name>,
+
     #[resolve(annotations)]
     #[depict(skip)]
     pub(crate) annotations: StructAnnotations,
@@ -68,6 +80,29 @@ where
     ) -> Result<(), ToscaError<WithAnnotations>> {
         let errors = &mut errors.to_error_recipient();
 
+        if catalog.try_lookup(RELATIONSHIP_TEMPLATE, &self.type_name, source_id).is_some() {
+            let Some(template) = catalog.get_complete_entity::<RelationshipTemplate<AnnotatedT>, _, _>(
+                RELATIONSHIP_TEMPLATE,
+                &self.type_name,
+                source_id,
+                errors,
+            )?
+            else {
+                return Ok(());
+            };
+
+            self.template_name = Some(self.type_name.name.clone());
+            self.type_name = template.type_name.clone();
+            if_empty_clone!(properties, self, template);
+            if_empty_clone!(attributes, self, template);
+            if_empty_clone!(interfaces, self, template);
+        } else if self.type_name.is_empty()
+            && let Some((parent, _scope)) = &parent
+        {
+            // The relationship type was specified in the requirement definition
+            self.type_name = parent.type_name.clone();
+        }
+
         if let Some((parent, _scope)) = &parent {
             validate_type_name(&self.type_name, &parent.type_name, catalog, errors)?;
         }
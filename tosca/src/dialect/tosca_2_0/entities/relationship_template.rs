@@ -95,6 +95,7 @@ where
 
         let errors = &mut errors.to_error_recipient();
 
+        let mut copied = Vec::default();
         if let Some(copy) = &self.copy {
             let Some(copy) = catalog.get_complete_entity::<RelationshipTemplate<AnnotatedT>, _, _>(
                 RELATIONSHIP_TEMPLATE,
@@ -106,12 +107,19 @@ where
                 return Ok(());
             };
 
-            if_empty_clone!(type_name, self, copy);
-            if_none_clone!(description, self, copy);
-            if_empty_clone!(metadata, self, copy);
-            if_empty_clone!(properties, self, copy);
-            if_empty_clone!(attributes, self, copy);
-            if_empty_clone!(interfaces, self, copy);
+            if_empty_clone!(type_name, self, copy, copied);
+            if_none_clone!(description, self, copy, copied);
+            if_empty_clone!(metadata, self, copy, copied);
+            if_empty_clone!(properties, self, copy, copied);
+            if_empty_clone!(attributes, self, copy, copied);
+            if_empty_clone!(interfaces, self, copy, copied);
+        }
+
+        if let Some(copy) = &self.copy {
+            let from = catalog.provenance_entity(RELATIONSHIP_TEMPLATE, &copy.clone().into(), source_id);
+            for field_name in copied {
+                catalog.provenance.add_copied(field_name, from.clone(), self.field_annotations(field_name).cloned());
+            }
         }
 
         if self.type_name.is_empty() {
@@ -1,4 +1,9 @@
-use super::{super::super::super::grammar::*, relationship_assignment::*, requirement_definition::*};
+use super::{
+    super::{super::super::grammar::*, data::*, dialect::*},
+    function_definition::*,
+    relationship_assignment::*,
+    requirement_definition::*,
+};
 
 use {
     compris::{annotate::*, normal::*, resolve::*},
@@ -91,7 +96,7 @@ where
     /// type-compatible target node that can fulfill the requirement at runtime.
     #[resolve]
     #[depict(option, as(depict))]
-    pub node_filter: Option<Variant<AnnotatedT>>,
+    pub node_filter: Option<Expression<AnnotatedT>>,
 
     /// An optional list of directive values to provide processing instructions to
     /// orchestrators and tooling.
@@ -117,16 +122,149 @@ impl<AnnotatedT> RequirementAssignment<AnnotatedT>
 where
     AnnotatedT: Annotated + Clone + Default,
 {
+    /// Validate that the node is declared, either as a node template or as a node type.
+    pub fn validate_node<ErrorRecipientT>(
+        &self,
+        catalog: &Catalog,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<WithAnnotations>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+    {
+        if let Some(node) = &self.node
+            && catalog.try_lookup(NODE_TEMPLATE, &node.full_name, source_id).is_none()
+            && catalog.try_lookup(NODE_TYPE, &node.full_name, source_id).is_none()
+        {
+            let suggestions = catalog
+                .get_source::<WithoutAnnotations>(source_id)
+                .map(|source| source.suggest_names(&[NODE_TEMPLATE, NODE_TYPE], &node.full_name))
+                .unwrap_or_default();
+
+            errors.give(
+                UndeclaredError::new("node template or node type".into(), node.full_name.to_string())
+                    .with_suggestions(suggestions)
+                    .with_annotations_from_field(self, "node"),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Target capability, either a capability type name or a capability name.
+    pub fn target_capability(
+        &self,
+        source_id: &SourceID,
+        catalog: &Catalog,
+    ) -> Option<RequirementAssignmentCapability> {
+        self.capability.as_ref().map(|capability| {
+            if catalog.try_lookup(CAPABILITY_TYPE, capability, source_id).is_some() {
+                RequirementAssignmentCapability::TypeName(capability.clone())
+            } else {
+                RequirementAssignmentCapability::Name(capability.name.clone())
+            }
+        })
+    }
+
+    /// Target node, either a node type name or a node template name with an optional index.
+    pub fn target_node(&self, source_id: &SourceID, catalog: &Catalog) -> Option<RequirementAssignmentNode> {
+        self.node.as_ref().map(|node| {
+            if catalog.try_lookup(NODE_TEMPLATE, &node.full_name, source_id).is_some() {
+                RequirementAssignmentNode::Template((node.full_name.name.clone(), node.index))
+            } else {
+                RequirementAssignmentNode::TypeName(node.full_name.clone())
+            }
+        })
+    }
+
+    /// Floria selector for the target capability.
+    ///
+    /// The capability is selected when instantiating by the `select_capability` function, which
+    /// also rejects candidates that do not have enough capacity left for the allocation.
+    pub fn target_selector(
+        &self,
+        directory: &floria::Directory,
+        source_id: &SourceID,
+        catalog: &Catalog,
+    ) -> floria::VertexSelector {
+        let mut argument = Map::<WithoutAnnotations>::default();
+
+        match self.target_capability(source_id, catalog) {
+            Some(RequirementAssignmentCapability::TypeName(type_name)) => {
+                argument.into_insert("capability_type_name", class_id(directory, &type_name));
+            }
+
+            Some(RequirementAssignmentCapability::Name(name)) => {
+                argument.into_insert("capability_name", name.to_string());
+            }
+
+            None => {}
+        }
+
+        match self.target_node(source_id, catalog) {
+            Some(RequirementAssignmentNode::TypeName(type_name)) => {
+                argument.into_insert("node_type_name", class_id(directory, &type_name));
+            }
+
+            Some(RequirementAssignmentNode::Template((template_name, index))) => {
+                argument.into_insert("node_template_name", template_name.to_string());
+                if let Some(index) = index {
+                    argument.into_insert("node_template_index", index as u64);
+                }
+            }
+
+            None => {}
+        }
+
+        if let Some(allocation) = &self.allocation {
+            argument.into_insert("allocation", allocation.clone().into_annotated());
+        }
+
+        let select_capability = floria::Call::new(
+            DIALECT_ID,
+            ByteString::from_static("select_capability"),
+            vec![Variant::from(argument).into()],
+            false,
+        );
+
+        floria::VertexSelector::new_finder(select_capability)
+    }
+
     /// Compile to Floria.
     pub fn compile<ErrorRecipientT>(
         &self,
-        _edge_template: &mut floria::EdgeTemplate,
+        edge_template: &mut floria::EdgeTemplate,
         _errors: &mut ErrorRecipientT,
     ) -> Result<(), ToscaError<AnnotatedT>>
     where
         ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
     {
         // TODO
+
+        edge_template.template.metadata.set_tosca_entity_static(DIALECT_ID, REQUIREMENT_NAME);
+        edge_template.template.metadata.set_tosca_directives(&self.directives);
+
+        // The target as written; it is resolved only when instantiating
+        if let Some(node) = &self.node {
+            edge_template.template.metadata.into_insert("tosca:node", node.full_name.to_string());
+        }
+
+        if let Some(capability) = &self.capability {
+            edge_template.template.metadata.into_insert("tosca:capability", capability.to_string());
+        }
+
+        // Used by TOSCA paths that start with a relationship template name
+        if let Some(relationship) = &self.relationship
+            && let Some(template_name) = &relationship.template_name
+        {
+            edge_template.template.metadata.into_insert("tosca:relationship_template", template_name.to_string());
+        }
+
+        // Used by $available_allocation and select_capability to track capability capacity
+        if let Some(allocation) = &self.allocation {
+            edge_template.template.metadata.into_insert("tosca:allocation", allocation.clone().into_annotated());
+        }
+
         Ok(())
     }
 
@@ -209,88 +347,6 @@ where
 
     //     Ok(Some(id))
     // }
-
-    // fn new_finder(
-    //     &self,
-    //     catalog: &OldCatalog<'_, AnnotatedT>,
-    //     index: &Index,
-    // ) -> Result<floria::Call, ToscaError<AnnotatedT>> {
-    //     let mut finder = floria::Call::new(get_dispatch_name("select_capability"), Default::default());
-
-    //     let mut argument = Map::default();
-
-    //     if let Some(capability) = self.get_capability(catalog)? {
-    //         match capability {
-    //             RequirementAssignmentCapability::TypeName(type_name) => {
-    //                 argument.into_insert("capability_type_name", type_name.to_string());
-    //             }
-
-    //             RequirementAssignmentCapability::Name(name) => {
-    //                 argument.into_insert("capability_name", name.to_string());
-    //             }
-    //         }
-    //     }
-
-    //     if let Some(node) = self.get_node(catalog, index)? {
-    //         match node {
-    //             RequirementAssignmentNode::TypeName(type_name) => {
-    //                 argument.into_insert("node_type_name", type_name.to_string());
-    //             }
-
-    //             RequirementAssignmentNode::Template((template_name, index)) => {
-    //                 argument.into_insert("node_template_name", template_name.to_string());
-    //                 argument.into_insert("node_template_index", index);
-    //             }
-    //         }
-    //     }
-
-    //     if !argument.inner.is_empty() {
-    //         finder.arguments.push(floria::Expression::Literal(argument.into()));
-    //     }
-
-    //     Ok(finder)
-    // }
-
-    // fn get_capability(
-    //     &self,
-    //     _catalog: &OldCatalog<'_, AnnotatedT>,
-    // ) -> Result<Option<RequirementAssignmentCapability>, ToscaError<AnnotatedT>> {
-    //     // TODO: capability type name or capability name
-    //     Ok(self.capability.as_ref().map(|name| RequirementAssignmentCapability::Name(name.name.clone())))
-    // }
-
-    // fn get_node(
-    //     &self,
-    //     catalog: &OldCatalog<'_, AnnotatedT>,
-    //     index: &Index,
-    // ) -> Result<Option<RequirementAssignmentNode>, ToscaError<AnnotatedT>> {
-    //     match &self.node {
-    //         Some(node) => {
-    //             match node.index {
-    //                 // node type name or node template name
-    //                 // TODO
-    //                 None => match index.index.get(&node.full_name) {
-    //                     Some(node_id) => match catalog.node_types.has_id(node_id) {
-    //                         true => Ok(Some(RequirementAssignmentNode::TypeName(node.full_name.clone()))),
-
-    //                         false => Err(UnknownTypeError::new(
-    //                             node.full_name.to_string(),
-    //                             "RequirementAssignment.get_node".into(),
-    //                         )
-    //                         .into()),
-    //                     },
-
-    //                     None => Ok(None),
-    //                 },
-
-    //                 // node template name and index
-    //                 Some(index) => Ok(Some(RequirementAssignmentNode::Template((node.full_name.name.clone(), index)))),
-    //             }
-    //         }
-
-    //         None => Ok(None),
-    //     }
-    // }
 }
 
 impl<AnnotatedT> Subentity<RequirementDefinition<AnnotatedT>> for RequirementAssignment<AnnotatedT>
@@ -305,6 +361,19 @@ where
         source_id: &SourceID,
         errors: ToscaErrorRecipientRef,
     ) -> Result<(), ToscaError<WithAnnotations>> {
+        if let Some(allocation) = &self.allocation
+            && !matches!(allocation, Variant::Map(_))
+        {
+            errors.to_error_recipient().give(
+                MalformedError::new("allocation".into(), "is not a map".into())
+                    .with_annotations_from_field(self, "allocation"),
+            )?;
+        }
+
+        if let Some(node_filter) = &mut self.node_filter {
+            complete_function_calls(node_filter, catalog, source_id, &mut errors.to_error_recipient())?;
+        }
+
         // TODO: validate node (template) adheres to parent's node (type)
 
         // TODO: validate capability adheres to parent's capability type
@@ -325,6 +394,10 @@ where
 
         Ok(())
     }
+
+    fn declared_kind() -> Option<&'static str> {
+        Some("requirement")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<RequirementAssignment<AnnotatedT>> for RequirementDefinition<AnnotatedT>
@@ -364,8 +437,8 @@ pub enum RequirementAssignmentNode {
     /// Node type name.
     TypeName(FullName),
 
-    /// Node template.
-    Template((Name, usize)),
+    /// Node template name and optional index.
+    Template((Name, Option<usize>)),
 }
 
 //
@@ -374,3 +447,8 @@ pub enum RequirementAssignmentNode {
 
 /// [TaggedValues] of [RequirementAssignment].
 pub type RequirementAssignments<AnnotatedT> = TaggedValues<ByteString, RequirementAssignment<AnnotatedT>>;
+
+// Floria class ID of a type, as compiled by TypeEntityCompiler
+fn class_id(directory: &floria::Directory, type_name: &FullName) -> String {
+    floria::ID::new_for(floria::Kind::Class, directory.clone(), type_name.to_string().into()).to_string()
+}
@@ -1,4 +1,8 @@
-use super::{super::super::super::grammar::*, relationship_definition::*};
+use super::{
+    super::{super::super::grammar::*, data::*},
+    function_definition::*,
+    relationship_definition::*,
+};
 
 use {
     compris::{annotate::*, normal::*, resolve::*},
@@ -59,7 +63,7 @@ where
     /// type-compatible target node that can fulfill this requirement at runtime.
     #[resolve]
     #[depict(option, as(depict))]
-    pub node_filter: Option<Variant<AnnotatedT>>,
+    pub node_filter: Option<Expression<AnnotatedT>>,
 
     /// The optional minimum required and maximum allowed number of relationships created by the
     /// requirement. If this key is not specified, the implied default of [ 0, UNBOUNDED ] will be
@@ -95,6 +99,10 @@ where
 
         let errors = &mut errors.to_error_recipient();
 
+        if let Some(node_filter) = &mut self.node_filter {
+            complete_function_calls(node_filter, catalog, source_id, errors)?;
+        }
+
         if let Some((parent, scope)) = parent {
             if_none_else!(node, self, parent, parent.node.clone().map(|node| node.in_scope(scope.clone())));
             if_none_clone!(node_filter, self, parent);
@@ -106,6 +114,10 @@ where
 
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.field_annotations("capability")
+    }
 }
 
 impl<AnnotatedT> ConvertIntoScope<RequirementDefinition<AnnotatedT>> for RequirementDefinition<AnnotatedT>
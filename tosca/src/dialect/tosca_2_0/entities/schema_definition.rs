@@ -0,0 +1,111 @@
+use super::{
+    super::{super::super::grammar::*, data::*, dialect::*},
+    data_type::*,
+    function_definition::*,
+};
+
+use {
+    compris::{annotate::*, normal::*, resolve::*},
+    kutil::{
+        cli::depict::*,
+        std::{error::*, immutable::*},
+    },
+};
+
+//
+// SchemaDefinition
+//
+
+/// (Documentation copied from
+/// [TOSCA specification 2.0](https://docs.oasis-open.org/tosca/TOSCA/v2.0/TOSCA-v2.0.html))
+///
+/// All entries in a map or list for one property or parameter must be of the same type.
+/// Similarly, all keys for map entries for one property or parameter must be of the same type as
+/// well. A TOSCA schema definition must be used to specify the type (for simple entries) or schema
+/// (for complex entries) for keys and entries in TOSCA set types such as the TOSCA list or map.
+#[derive(Clone, Debug, Default, Depict, Resolve)]
+#[depict(tag = tag::source_and_span)]
+#[resolve(annotated_parameter=AnnotatedT)]
+pub struct SchemaDefinition<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// The mandatory data type for the key or entry. If this schema definition is for a map key,
+    /// then the referred type must be derived originally from string.
+    #[resolve(single, key = "type")]
+    #[depict(as(depict))]
+    pub type_name: FullName,
+
+    /// The optional description for the schema.
+    #[resolve]
+    #[depict(option, style(string))]
+    pub description: Option<ByteString>,
+
+    /// The optional validation clause that must evaluate to True for the property.
+    #[resolve]
+    #[depict(option, as(depict))]
+    pub validation: Option<Expression<AnnotatedT>>,
+
+    /// When the schema itself is of type map, the optional schema definition that is used to
+    /// specify the type of the keys of that map's entries (if key_schema is not defined it is
+    /// assumed to be "string" by default). For other schema types, the key_schema must not be
+    /// defined.
+    #[resolve]
+    #[depict(option, as(depict))]
+    pub key_schema: Option<Variant<AnnotatedT>>,
+
+    /// When the schema itself is of type list or map, the schema definition is mandatory and is
+    /// used to specify the type of the entries in that map or list. For other schema types, the
+    /// entry_schema must not be defined.
+    #[resolve]
+    #[depict(option, as(depict))]
+    pub entry_schema: Option<Variant<AnnotatedT>>,
+
+    /// Data kind.
+    #[depict(option, style(string))]
+    pub data_kind: Option<DataKind>,
+
+    #[resolve(annotations)]
+    #[depict(skip)]
+    pub(crate) annotations: StructAnnotations,
+}
+
+impl<AnnotatedT> SchemaDefinition<AnnotatedT>
+where
+    AnnotatedT: 'static + Annotated + Clone + Default,
+{
+    /// Complete.
+    ///
+    /// Will set the data kind from the data type.
+    pub fn complete<ErrorRecipientT>(
+        &mut self,
+        catalog: &mut Catalog,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<WithAnnotations>>
+    where
+        ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+    {
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
+        self.validation.finalize_validation(None);
+
+        let data_type = get_complete_entity!(DATA_TYPE, DataType, self, type_name, catalog, source_id, errors);
+
+        if let Some((data_type, _scope)) = data_type {
+            self.data_kind = data_type.data_kind;
+            complete_validation!(self, data_type);
+        }
+
+        Ok(())
+    }
+}
+
+//
+// SchemaDefinitions
+//
+
+/// List of [SchemaDefinition].
+pub type SchemaDefinitions<AnnotatedT> = Vec<SchemaDefinition<AnnotatedT>>;
@@ -2,6 +2,7 @@ use super::{
     super::{super::super::grammar::*, data::*, dialect::*},
     attribute_definition::*,
     data_type::*,
+    function_definition::*,
     parameter_definition::*,
     property_definition::*,
 };
@@ -12,6 +13,7 @@ use {
         cli::depict::*,
         std::{error::*, immutable::*},
     },
+    puccini_tosca_2_0_core::SourceLocation,
     std::collections::*,
 };
 
@@ -54,27 +56,82 @@ pub struct ValueAssignment<AnnotatedT> {
 
 impl<AnnotatedT> ValueAssignment<AnnotatedT> {
     /// Floria property value, preparer, and updater.
+    ///
+    /// The value path (e.g. `properties.ports`) is used in coercion errors.
     pub fn floria_property_fields(
         &self,
+        value_path: ByteString,
     ) -> (Option<floria::Expression>, Option<floria::Expression>, Option<floria::Expression>)
     where
         AnnotatedT: Annotated + Clone + Default,
     {
+        let scalar_schema = self.validation.as_ref().and_then(|validation| validation.scalar_schema());
+
         let (value, updater) = self
             .expression
             .as_ref()
-            .map(|expression| expression.clone().into_floria_property_fields())
+            .map(|expression| {
+                let mut expression = expression.clone();
+                if let Some(scalar_schema) = scalar_schema {
+                    expression.coerce_scalar_arguments(scalar_schema);
+                }
+                expression.into_floria_property_fields()
+            })
             .unwrap_or_default();
 
-        let preparer = self.validation.as_ref().map(|validation| validation.clone().into());
+        let mut validation = self.validation.clone();
+        validation.locate_schema(value_path, self.source_location());
+        let preparer = validation.map(|validation| validation.into());
 
         (value, preparer, updater)
     }
 
+    /// Annotations of the value.
+    pub fn expression_annotations(&self) -> Option<&Annotations>
+    where
+        AnnotatedT: Annotated,
+    {
+        self.expression.as_ref()?.annotations()
+    }
+
+    /// [SourceLocation] of the value, e.g. `file.yaml:12:5`.
+    pub fn source_location(&self) -> Option<ByteString>
+    where
+        AnnotatedT: Annotated,
+    {
+        let annotations = self.expression_annotations()?;
+        let source = annotations.source.as_ref()?;
+        let position = annotations.span.as_ref().map(|span| (span.start.row, span.start.column));
+        Some(SourceLocation::new(source.to_string(), position).to_string().into())
+    }
+
+    /// Complete calls to declared functions.
+    pub fn complete_function_calls<ErrorRecipientT>(
+        &mut self,
+        catalog: &mut Catalog,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<WithAnnotations>>
+    where
+        AnnotatedT: 'static + Annotated + Clone + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
+    {
+        if let Some(expression) = &mut self.expression {
+            complete_function_calls(expression, catalog, source_id, errors)?;
+        }
+
+        if let Some(validation) = &mut self.validation {
+            complete_function_calls(validation, catalog, source_id, errors)?;
+        }
+
+        Ok(())
+    }
+
     /// Compile to Floria.
     pub fn compile<ErrorRecipientT>(
         &self,
         tosca_entity: &'static str,
+        value_path: ByteString,
         read_only: bool,
         directory: &floria::Directory,
         store: floria::StoreRef,
@@ -84,7 +141,7 @@ impl<AnnotatedT> ValueAssignment<AnnotatedT> {
         AnnotatedT: Annotated + Clone + Default,
         ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
     {
-        let (value, preparer, updater) = self.floria_property_fields();
+        let (value, preparer, updater) = self.floria_property_fields(value_path);
         let mut floria_property = floria::Property::new(read_only, preparer, updater, value);
 
         floria_property.metadata.set_tosca_entity_static(DIALECT_ID, tosca_entity);
@@ -140,8 +197,14 @@ where
 
         if_none_clone!(description, self, parent);
 
+        self.complete_function_calls(catalog, source_id, errors)?;
+
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.expression_annotations()
+    }
 }
 
 impl<AnnotatedT> Subentity<PropertyDefinition<AnnotatedT>> for ValueAssignment<AnnotatedT>
@@ -189,8 +252,38 @@ where
 
         if_none_clone!(description, self, parent);
 
+        self.complete_function_calls(catalog, source_id, errors)?;
+
         Ok(())
     }
+
+    fn lint(
+        &self,
+        name: &ByteString,
+        parent: &PropertyDefinition<AnnotatedT>,
+        catalog: &mut Catalog,
+        source_id: &SourceID,
+    ) {
+        if let Some(expression) = &self.expression
+            && parent.value.is_none()
+            && (parent.default.as_ref() == Some(expression))
+        {
+            catalog.add_lint_warning(LintWarning::new(
+                LintRule::RedundantDefault,
+                format!("property {:?} is assigned the default of its definition", name.to_string()),
+                source_id.clone(),
+                expression.annotations().cloned(),
+            ));
+        }
+    }
+
+    fn declared_kind() -> Option<&'static str> {
+        Some("property")
+    }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.expression_annotations()
+    }
 }
 
 impl<AnnotatedT> Subentity<AttributeDefinition<AnnotatedT>> for ValueAssignment<AnnotatedT>
@@ -229,8 +322,14 @@ where
 
         if_none_clone!(description, self, parent);
 
+        self.complete_function_calls(catalog, source_id, errors)?;
+
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.expression_annotations()
+    }
 }
 
 impl<AnnotatedT> Subentity<ParameterDefinition<AnnotatedT>> for ValueAssignment<AnnotatedT>
@@ -282,8 +381,14 @@ where
 
         if_none_clone!(description, self, parent);
 
+        self.complete_function_calls(catalog, source_id, errors)?;
+
         Ok(())
     }
+
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        self.expression_annotations()
+    }
 }
 
 // For ArtifactAssignment and ArtifactDefinition
@@ -398,3 +503,55 @@ where
 
 /// Map of [ValueAssignment].
 pub type ValueAssignments<AnnotatedT> = BTreeMap<ByteString, ValueAssignment<AnnotatedT>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Complete the child with the parent and return it
+    fn complete(
+        mut child: ValueAssignment<WithoutAnnotations>,
+        parent: &ValueAssignment<WithoutAnnotations>,
+    ) -> ValueAssignment<WithoutAnnotations> {
+        let mut catalog = Catalog::default();
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+        Subentity::<ValueAssignment<_>>::complete(
+            &mut child,
+            None,
+            Some((parent, &Scope::default())),
+            &mut catalog,
+            &Default::default(),
+            errors.to_ref(),
+        )
+        .expect("complete");
+        assert!(errors.is_empty());
+        child
+    }
+
+    fn parent() -> ValueAssignment<WithoutAnnotations> {
+        ValueAssignment {
+            expression: Some("parent".into()),
+            description: Some("parent description".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn inherit_unset_fields() {
+        let child = complete(ValueAssignment::default(), &parent());
+        assert_eq!(child.expression, Some("parent".into()));
+        assert_eq!(child.description, Some("parent description".into()));
+    }
+
+    #[test]
+    fn keep_set_fields() {
+        let child = ValueAssignment {
+            expression: Some("child".into()),
+            description: Some("child description".into()),
+            ..Default::default()
+        };
+        let child = complete(child, &parent());
+        assert_eq!(child.expression, Some("child".into()));
+        assert_eq!(child.description, Some("child description".into()));
+    }
+}
@@ -1,6 +1,7 @@
 mod data;
 mod dialect;
 mod entities;
+mod native;
 
 #[allow(unused_imports)]
-pub use {data::*, dialect::*, entities::*};
+pub use {data::*, dialect::*, entities::*, native::*};
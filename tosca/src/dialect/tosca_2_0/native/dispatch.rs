@@ -0,0 +1,153 @@
+use super::{super::data::*, host::*, utils::*, value::*};
+
+use {
+    compris::{annotate::*, normal::*},
+    puccini_tosca_2_0_core::{dispatch, is_core_function},
+};
+
+/// Built-in functions that have a native implementation.
+///
+/// Other built-in functions are only available in the `tosca-2.0-functions` plugin. The pure ones
+/// share their implementation with the plugin.
+pub const NATIVE_FUNCTIONS: &[&str] = &[
+    // Inputs (requires a host)
+    "get_input",
+    // Boolean logic
+    "and",
+    "or",
+    "not",
+    "xor",
+    // Boolean comparison
+    "equal",
+    "greater_than",
+    "greater_or_equal",
+    "less_than",
+    "less_or_equal",
+    // Collection
+    "length",
+    "concat",
+    "join",
+    "token",
+    // Set
+    "union",
+    "intersection",
+    // Arithmetic
+    "sum",
+    "difference",
+    "product",
+    "quotient",
+    "remainder",
+    "round",
+    "floor",
+    "ceil",
+    // Puccini
+    "_evaluate",
+];
+
+/// True if the built-in function is pure, meaning that its result depends only on its arguments.
+pub fn is_pure_function(function_name: &str) -> bool {
+    (function_name != "get_input") && NATIVE_FUNCTIONS.contains(&function_name)
+}
+
+/// Dispatch a built-in function natively.
+///
+/// Returns [None] if the function or its arguments are not supported natively, in which case the
+/// call should be left for the plugin.
+pub fn dispatch_native<AnnotatedT, HostT>(
+    function_name: &str,
+    arguments: &[Expression<AnnotatedT>],
+    host: &HostT,
+) -> Result<Option<Variant<AnnotatedT>>, String>
+where
+    AnnotatedT: Annotated + Clone + Default,
+    HostT: NativeHost<AnnotatedT>,
+{
+    if is_core_function(function_name) {
+        let function_host = NativeFunctionHost::new(host);
+        return Ok(dispatch(function_name, arguments.iter().collect(), &function_host)?.and_then(value_to_variant));
+    }
+
+    if !NATIVE_FUNCTIONS.contains(&function_name) {
+        return Ok(None);
+    }
+
+    let mut evaluated_arguments = Vec::with_capacity(arguments.len());
+    for argument in arguments {
+        match argument.evaluate_native(host)? {
+            Some(argument) => evaluated_arguments.push(argument),
+            None => return Ok(None),
+        }
+    }
+
+    match function_name {
+        // Inputs
+        "get_input" => get_input(evaluated_arguments, host),
+
+        // Puccini
+        "_evaluate" => match evaluated_arguments.len() {
+            1 => Ok(evaluated_arguments.pop()),
+            _ => Err("must have one argument".into()),
+        },
+
+        _ => Ok(None),
+    }
+}
+
+fn get_input<AnnotatedT, HostT>(
+    arguments: Vec<Variant<AnnotatedT>>,
+    host: &HostT,
+) -> Result<Option<Variant<AnnotatedT>>, String>
+where
+    AnnotatedT: Clone,
+    HostT: NativeHost<AnnotatedT>,
+{
+    let Some((input_name, keys)) = arguments.split_first() else {
+        return Err("must have at least one argument".into());
+    };
+
+    let Variant::Text(input_name) = input_name else {
+        return Err(format!("input name not a string: {}", type_name(input_name)));
+    };
+
+    match host.get_input(&input_name.inner)? {
+        Some(input) => Ok(Some(get_nested(&input, keys)?.clone())),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::*};
+
+    fn inputs_host() -> InputsHost<WithoutAnnotations> {
+        let ports: Vec<Variant<WithoutAnnotations>> = vec![80u64.into(), 443u64.into()];
+        InputsHost { inputs: BTreeMap::from([("name".into(), "web".into()), ("ports".into(), ports.into())]) }
+    }
+
+    #[test]
+    fn get_input() {
+        let host = inputs_host();
+        assert_eq!(dispatch_native("get_input", &["name".into()], &host), Ok(Some("web".into())));
+        assert_eq!(dispatch_native("get_input", &["ports".into(), 1u64.into()], &host), Ok(Some(443u64.into())));
+        assert_eq!(
+            dispatch_native("get_input", &["ports".into(), 2u64.into()], &host),
+            Err("2 not found in value".into())
+        );
+    }
+
+    #[test]
+    fn get_input_without_inputs() {
+        assert_eq!(dispatch_native::<WithoutAnnotations, _>("get_input", &["name".into()], &PureHost), Ok(None));
+        assert_eq!(dispatch_native("get_input", &["other".into()], &inputs_host()), Ok(None));
+    }
+
+    #[test]
+    fn not_native() {
+        assert_eq!(
+            dispatch_native::<WithoutAnnotations, _>("get_property", &["SELF".into(), "port".into()], &PureHost),
+            Ok(None)
+        );
+        assert!(!is_pure_function("get_input"));
+        assert!(is_pure_function("concat"));
+    }
+}
@@ -0,0 +1,74 @@
+use super::{super::data::*, dispatch::*, host::*};
+
+use {
+    compris::{annotate::*, normal::*},
+    std::collections::*,
+};
+
+impl<AnnotatedT> Expression<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// Evaluate natively, without the plugin.
+    ///
+    /// Returns [None] if the expression cannot be evaluated natively, e.g. because it calls a
+    /// function that is only implemented in the plugin or because the host does not provide a
+    /// graph value.
+    pub fn evaluate_native<HostT>(&self, host: &HostT) -> Result<Option<Variant<AnnotatedT>>, String>
+    where
+        HostT: NativeHost<AnnotatedT>,
+    {
+        match self {
+            Self::Literal(literal) => Ok(Some(literal.clone())),
+
+            Self::List(list) => {
+                let mut evaluated_list = Vec::with_capacity(list.len());
+                for item in list {
+                    match item.evaluate_native(host)? {
+                        Some(item) => evaluated_list.push(item),
+                        None => return Ok(None),
+                    }
+                }
+
+                Ok(Some(evaluated_list.into()))
+            }
+
+            Self::Map(map) => {
+                let mut evaluated_map = BTreeMap::default();
+                for (key, value) in map {
+                    match (key.evaluate_native(host)?, value.evaluate_native(host)?) {
+                        (Some(key), Some(value)) => {
+                            evaluated_map.insert(key, value);
+                        }
+
+                        _ => return Ok(None),
+                    }
+                }
+
+                Ok(Some(evaluated_map.into()))
+            }
+
+            Self::Call(call) => call.evaluate_native(host),
+        }
+    }
+}
+
+impl<AnnotatedT> Call<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// Evaluate natively, without the plugin.
+    ///
+    /// Returns [None] if the call cannot be evaluated natively.
+    pub fn evaluate_native<HostT>(&self, host: &HostT) -> Result<Option<Variant<AnnotatedT>>, String>
+    where
+        HostT: NativeHost<AnnotatedT>,
+    {
+        if !self.is_native() {
+            return Ok(None);
+        }
+
+        dispatch_native(&self.function.inner, &self.arguments, host)
+            .map_err(|error| format!("${}: {}", self.function.inner, error))
+    }
+}
@@ -0,0 +1,131 @@
+use super::{
+    super::{data::*, entities::*},
+    value::*,
+};
+
+use {
+    compris::{annotate::*, normal::*},
+    puccini_tosca_2_0_core::{FunctionHost, Value},
+    std::{collections::*, convert::*, marker::*},
+};
+
+//
+// NativeHost
+//
+
+/// Host for natively evaluated functions.
+///
+/// Provides the input values that the Wasm plugin gets from the Floria host. Graph values, such as
+/// properties and attributes, are only available to the plugin.
+pub trait NativeHost<AnnotatedT> {
+    /// Input value for `$get_input`.
+    ///
+    /// Returns [None] if the value is not available natively, in which case the call is left to
+    /// the plugin.
+    fn get_input(&self, _input_name: &str) -> Result<Option<Variant<AnnotatedT>>, String> {
+        Ok(None)
+    }
+}
+
+//
+// PureHost
+//
+
+/// [NativeHost] without inputs.
+///
+/// Only pure functions can be evaluated with it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PureHost;
+
+impl<AnnotatedT> NativeHost<AnnotatedT> for PureHost {}
+
+//
+// InputsHost
+//
+
+/// [NativeHost] with input values.
+#[derive(Clone, Debug, Default)]
+pub struct InputsHost<AnnotatedT> {
+    /// Input values.
+    pub inputs: BTreeMap<String, Variant<AnnotatedT>>,
+}
+
+impl<AnnotatedT> InputsHost<AnnotatedT>
+where
+    AnnotatedT: Annotated + Clone + Default,
+{
+    /// Constructor.
+    ///
+    /// Values are taken from the input definitions' "value" or else "default". Inputs that cannot
+    /// be evaluated natively are left out.
+    pub fn new(input_definitions: &ParameterDefinitions<AnnotatedT>) -> Self {
+        let mut inputs = BTreeMap::default();
+
+        for (input_name, input_definition) in input_definitions {
+            if let Some(expression) = input_definition.value.as_ref().or(input_definition.default.as_ref())
+                && let Ok(Some(value)) = expression.evaluate_native(&PureHost)
+            {
+                inputs.insert(input_name.to_string(), value);
+            }
+        }
+
+        Self { inputs }
+    }
+}
+
+impl<AnnotatedT> NativeHost<AnnotatedT> for InputsHost<AnnotatedT>
+where
+    AnnotatedT: Clone,
+{
+    fn get_input(&self, input_name: &str) -> Result<Option<Variant<AnnotatedT>>, String> {
+        Ok(self.inputs.get(input_name).cloned())
+    }
+}
+
+//
+// NativeFunctionHost
+//
+
+/// [FunctionHost] for the shared built-in functions over a [NativeHost].
+///
+/// Arguments that cannot be evaluated natively make the function return [None].
+pub struct NativeFunctionHost<'own, AnnotatedT, HostT> {
+    /// Native host.
+    pub host: &'own HostT,
+
+    annotated: PhantomData<AnnotatedT>,
+}
+
+impl<'own, AnnotatedT, HostT> NativeFunctionHost<'own, AnnotatedT, HostT> {
+    /// Constructor.
+    pub fn new(host: &'own HostT) -> Self {
+        Self { host, annotated: PhantomData }
+    }
+}
+
+impl<'own, AnnotatedT, HostT> FunctionHost for NativeFunctionHost<'own, AnnotatedT, HostT>
+where
+    AnnotatedT: 'own + Annotated + Clone + Default,
+    HostT: NativeHost<AnnotatedT>,
+{
+    type Argument = &'own Expression<AnnotatedT>;
+    type Custom = Infallible;
+    type Scalar = Infallible;
+
+    fn evaluate(&self, argument: Self::Argument) -> Result<Option<Value<Self::Custom>>, String> {
+        Ok(argument.evaluate_native(self.host)?.as_ref().and_then(variant_to_value))
+    }
+
+    // Only the plugin knows the scalar schemas, so a string might be a scalar
+    fn coerce_scalar(&self, _value: Value<Self::Custom>) -> Result<Option<Value<Self::Custom>>, String> {
+        Ok(None)
+    }
+
+    fn custom_kind(&self, custom: &Self::Custom) -> String {
+        match *custom {}
+    }
+
+    fn custom_comparator(&self, custom: &Self::Custom) -> Result<Value<Self::Custom>, String> {
+        match *custom {}
+    }
+}
@@ -0,0 +1,8 @@
+mod dispatch;
+mod evaluate;
+mod host;
+mod utils;
+mod value;
+
+#[allow(unused_imports)]
+pub use {dispatch::*, evaluate::*, host::*};
@@ -0,0 +1,45 @@
+use compris::normal::*;
+
+/// Type name.
+pub fn type_name<AnnotatedT>(value: &Variant<AnnotatedT>) -> &'static str {
+    match value {
+        Variant::Null(_) => "null",
+        Variant::Integer(_) => "integer",
+        Variant::UnsignedInteger(_) => "unsigned integer",
+        Variant::Float(_) => "float",
+        Variant::Boolean(_) => "boolean",
+        Variant::Text(_) => "string",
+        Variant::Blob(_) => "bytes",
+        Variant::List(_) => "list",
+        Variant::Map(_) => "map",
+        _ => "undefined",
+    }
+}
+
+/// Index.
+pub fn index<AnnotatedT>(value: &Variant<AnnotatedT>) -> Option<usize> {
+    match value {
+        Variant::Integer(integer) => integer.inner.try_into().ok(),
+        Variant::UnsignedInteger(unsigned_integer) => unsigned_integer.inner.try_into().ok(),
+        _ => None,
+    }
+}
+
+/// Get nested value.
+///
+/// Lists are indexed by integers and maps by keys.
+pub fn get_nested<'own, AnnotatedT>(
+    mut value: &'own Variant<AnnotatedT>,
+    keys: &[Variant<AnnotatedT>],
+) -> Result<&'own Variant<AnnotatedT>, String> {
+    for key in keys {
+        value = match value {
+            Variant::List(list) => index(key).and_then(|index| list.inner.get(index)),
+            Variant::Map(map) => map.inner.get(key),
+            _ => None,
+        }
+        .ok_or_else(|| format!("{} not found in value", key))?;
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,77 @@
+use {
+    compris::normal::*,
+    kutil::std::immutable::*,
+    puccini_tosca_2_0_core::Value,
+    std::{collections::*, convert::*},
+};
+
+/// Variant into a value for the shared built-in functions.
+///
+/// Returns [None] if the variant is not supported.
+pub fn variant_to_value<AnnotatedT>(variant: &Variant<AnnotatedT>) -> Option<Value<Infallible>> {
+    Some(match variant {
+        Variant::Null(_) => Value::Null,
+        Variant::Integer(integer) => Value::Integer(integer.inner),
+        Variant::UnsignedInteger(unsigned_integer) => Value::UnsignedInteger(unsigned_integer.inner),
+        Variant::Float(float) => Value::Float(f64::from(float.inner)),
+        Variant::Boolean(boolean) => Value::Boolean(boolean.inner),
+        Variant::Text(text) => Value::Text(text.inner.to_string()),
+        Variant::Blob(blob) => Value::Bytes(blob.inner.to_vec()),
+
+        Variant::List(list) => {
+            let mut value_list = Vec::with_capacity(list.inner.len());
+            for item in &list.inner {
+                value_list.push(variant_to_value(item)?);
+            }
+            Value::List(value_list)
+        }
+
+        Variant::Map(map) => {
+            let mut value_map = Vec::with_capacity(map.inner.len());
+            for (key, value) in &map.inner {
+                value_map.push((variant_to_value(key)?, variant_to_value(value)?));
+            }
+            Value::Map(value_map)
+        }
+
+        _ => return None,
+    })
+}
+
+/// Value of the shared built-in functions into a variant.
+///
+/// Returns [None] if the value is not supported.
+pub fn value_to_variant<AnnotatedT>(value: Value<Infallible>) -> Option<Variant<AnnotatedT>>
+where
+    AnnotatedT: Default,
+{
+    Some(match value {
+        Value::Null => Variant::Null(Default::default()),
+        Value::Integer(integer) => integer.into(),
+        Value::UnsignedInteger(unsigned_integer) => unsigned_integer.into(),
+        Value::Float(float) => float.into(),
+        Value::Boolean(boolean) => boolean.into(),
+        Value::Text(text) => ByteString::from(text).into(),
+
+        // Left to the plugin
+        Value::Bytes(_) => return None,
+
+        Value::List(list) => {
+            let mut variant_list = Vec::with_capacity(list.len());
+            for item in list {
+                variant_list.push(value_to_variant(item)?);
+            }
+            variant_list.into()
+        }
+
+        Value::Map(map) => {
+            let mut variant_map = BTreeMap::default();
+            for (key, value) in map {
+                variant_map.insert(value_to_variant(key)?, value_to_variant(value)?);
+            }
+            variant_map.into()
+        }
+
+        Value::Custom(custom) => match custom {},
+    })
+}
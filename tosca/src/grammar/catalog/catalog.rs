@@ -1,6 +1,9 @@
-use super::super::{dialect::*, source::*};
+use super::super::{dialect::*, lint::*, provenance::*, source::*};
 
-use kutil::std::collections::*;
+use {
+    kutil::std::{collections::*, immutable::*},
+    std::collections::*,
+};
 
 //
 // Catalog
@@ -14,4 +17,35 @@ pub struct Catalog {
 
     /// Sources.
     pub sources: FastHashMap<SourceID, Source>,
+
+    /// Registered plugin IDs.
+    ///
+    /// When [None], calls to plugins are not checked.
+    pub plugin_ids: Option<BTreeSet<ByteString>>,
+
+    /// Whether to report undeclared members.
+    ///
+    /// When true, properties, capabilities, and requirements that the type does not declare, and
+    /// requirement nodes that are neither node templates nor node types, are reported as errors.
+    pub report_undeclared: bool,
+
+    /// References followed during completion.
+    pub references: References,
+
+    /// Lint warnings found during completion.
+    pub lint_warnings: Vec<LintWarning>,
+
+    /// Provenance recorded during completion.
+    pub provenance: Provenance,
+}
+
+impl Catalog {
+    /// Clear what was recorded during completion: references, lint warnings, and provenance.
+    ///
+    /// Call this before completing another source with the same catalog.
+    pub fn clear_completion_records(&mut self) {
+        self.references = Default::default();
+        self.lint_warnings.clear();
+        self.provenance = Default::default();
+    }
 }
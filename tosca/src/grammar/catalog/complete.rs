@@ -30,7 +30,41 @@ impl Catalog {
     {
         let mut entities = self.entity_names();
         entities.sort();
+        self.complete_named_entities(entities, errors)
+    }
+
+    /// Complete the entities of a source.
+    ///
+    /// Entities in other sources, e.g. imports, are completed only as they are referenced. When
+    /// several sources share a catalog this means that common imports are completed only once.
+    pub fn complete_source_entities<AnnotatedT, ErrorRecipientT>(
+        &mut self,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        let source = unwrap_or_give_and_return!(self.get_source(source_id), errors, Ok(()));
+        let mut entities: Vec<_> = source
+            .entity_names()
+            .into_iter()
+            .map(|(entity_kind, name)| (source_id.clone(), entity_kind, name))
+            .collect();
+        entities.sort();
+        self.complete_named_entities(entities, errors)
+    }
 
+    fn complete_named_entities<AnnotatedT, ErrorRecipientT>(
+        &mut self,
+        entities: Vec<(SourceID, EntityKind, Name)>,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<(), ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
         for (source_id, entity_kind, name) in entities {
             let entity_kinds = unwrap_or_give!(self.source_entity_kinds(&source_id), errors, &EntityKinds::default());
             let entity_kind_name = entity_kinds.represent(entity_kind);
@@ -41,6 +75,7 @@ impl Catalog {
                         Ok(mut entity) => {
                             self.complete_entity(
                                 &mut entity,
+                                entity_kind,
                                 &entity_kind_name,
                                 &name,
                                 &source_id,
@@ -75,6 +110,7 @@ impl Catalog {
     pub(crate) fn complete_entity<AnnotatedT, ErrorRecipientT>(
         &mut self,
         entity: &mut EntityRef,
+        entity_kind: EntityKind,
         entity_kind_name: &ByteString,
         name: &Name,
         source_id: &SourceID,
@@ -93,9 +129,15 @@ impl Catalog {
                 "completing",
             );
 
-            entity
-                .complete(self, source_id, callstack, errors.into_annotated().to_ref())
-                .map_err(|error| error.into_annotated())?;
+            self.provenance.enter_entity(ProvenanceEntity::new(
+                entity_kind,
+                entity_kind_name.clone(),
+                source_id.clone(),
+                name.clone(),
+            ));
+            let result = entity.complete(self, source_id, callstack, errors.into_annotated().to_ref());
+            self.provenance.exit();
+            result.map_err(|error| error.into_annotated())?;
 
             if entity.is_complete() {
                 tracing::debug!(
@@ -0,0 +1,39 @@
+use super::{
+    super::{documentation::*, errors::*, hierarchy::*, source::*},
+    catalog::*,
+};
+
+use {compris::annotate::*, kutil::std::error::*};
+
+impl Catalog {
+    /// Reference documentation for the types of a source, including imported types.
+    ///
+    /// Sorted by entity kind and full name.
+    ///
+    /// Should be called after completion.
+    pub fn type_documentation<AnnotatedT, ErrorRecipientT>(
+        &self,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<Vec<TypeDocumentation>, ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        let source = unwrap_or_give_and_return!(self.get_source(source_id), errors, Ok(Default::default()));
+        let dialect =
+            unwrap_or_give_and_return!(self.get_dialect_ref(&source.dialect_id), errors, Ok(Default::default()));
+
+        let mut documentation: Vec<_> = TypeInfo::unique(dialect.source_types(source_id, self))
+            .into_values()
+            .filter_map(|info| dialect.document_type(info, self))
+            .collect();
+
+        documentation.sort_by(|documentation, other| {
+            (documentation.info.type_id.entity_kind, &documentation.info.full_name)
+                .cmp(&(other.info.type_id.entity_kind, &other.info.full_name))
+        });
+
+        Ok(documentation)
+    }
+}
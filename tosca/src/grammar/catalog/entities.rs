@@ -75,6 +75,12 @@ impl Catalog {
 
         unwrap_or_give_and_return!(callstack.add(entity_source_id.clone(), full_name.name.clone()), errors, Ok(None));
 
+        // For linting
+        self.references.entities.insert((entity_kind, entity_source_id.clone(), full_name.name.clone()));
+        if !full_name.scope.is_empty() {
+            self.references.scopes.insert((source_id.clone(), full_name.scope.clone()));
+        }
+
         let entity_source = unwrap_or_give_and_return!(self.get_source_mut(&entity_source_id), errors, Ok(None));
 
         let mut entity = unwrap_or_give_and_return!(
@@ -86,6 +92,7 @@ impl Catalog {
         let complete = {
             match self.complete_entity(
                 &mut entity,
+                entity_kind,
                 &entity_kind_name,
                 &full_name.name,
                 &entity_source_id,
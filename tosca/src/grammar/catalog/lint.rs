@@ -0,0 +1,45 @@
+use super::{
+    super::{errors::*, lint::*, source::*},
+    catalog::*,
+};
+
+use {compris::annotate::*, kutil::std::error::*};
+
+impl Catalog {
+    /// Add a lint warning found during completion.
+    pub fn add_lint_warning(&mut self, warning: LintWarning) {
+        self.lint_warnings.push(warning);
+    }
+
+    /// Lint a source.
+    ///
+    /// Should be called after completion, because some rules depend on the references followed and
+    /// the warnings found during completion.
+    pub fn lint<AnnotatedT, ErrorRecipientT>(
+        &self,
+        source_id: &SourceID,
+        rules: &LintRules,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<LintWarnings, ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        let mut warnings = LintWarnings::default();
+
+        let source = unwrap_or_give_and_return!(self.get_source(source_id), errors, Ok(warnings));
+        let dialect = unwrap_or_give_and_return!(self.get_dialect_ref(&source.dialect_id), errors, Ok(warnings));
+
+        for warning in &self.lint_warnings {
+            if warning.source_id == *source_id {
+                warnings.give(warning.clone(), rules);
+            }
+        }
+
+        for warning in dialect.lint_source(source_id, self) {
+            warnings.give(warning, rules);
+        }
+
+        Ok(warnings)
+    }
+}
@@ -3,10 +3,15 @@ mod compile;
 mod complete;
 mod depict;
 mod dialects;
+mod documentation;
 mod entities;
+mod lint;
 mod load;
 mod namespace;
+mod plugins;
+mod provenance;
 mod sources;
+mod types;
 
 #[allow(unused_imports)]
 pub use catalog::*;
@@ -0,0 +1,20 @@
+use super::catalog::*;
+
+use kutil::std::immutable::*;
+
+impl Catalog {
+    /// Register a plugin ID.
+    ///
+    /// Once any plugin ID is registered, calls to unregistered plugins are reported as errors.
+    pub fn add_plugin_id(&mut self, plugin_id: ByteString) {
+        self.plugin_ids.get_or_insert_default().insert(plugin_id);
+    }
+
+    /// True if the plugin ID is registered or if plugin IDs are not checked.
+    pub fn is_plugin_registered(&self, plugin_id: &ByteString) -> bool {
+        match &self.plugin_ids {
+            Some(plugin_ids) => plugin_ids.contains(plugin_id),
+            None => true,
+        }
+    }
+}
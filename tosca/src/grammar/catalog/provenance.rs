@@ -0,0 +1,48 @@
+use super::{
+    super::{entity::*, errors::*, name::*, provenance::*, source::*},
+    catalog::*,
+};
+
+use compris::annotate::*;
+
+impl Catalog {
+    /// Provenance entity for a [FullName] as seen from a source.
+    pub fn provenance_entity(
+        &self,
+        entity_kind: EntityKind,
+        full_name: &FullName,
+        source_id: &SourceID,
+    ) -> Option<ProvenanceEntity> {
+        let entity_kind_name = self.source_entity_kinds::<WithoutAnnotations>(source_id).ok()?.represent(entity_kind);
+        let entity_source_id = self.try_lookup(entity_kind, full_name, source_id)?;
+        Some(ProvenanceEntity::new(entity_kind, entity_kind_name, entity_source_id.clone(), full_name.name.clone()))
+    }
+
+    /// Set the entity from which the entity or member being completed inherits, e.g. its type.
+    pub fn set_provenance_parent(&mut self, entity_kind: EntityKind, full_name: &FullName, source_id: &SourceID) {
+        if let Some(parent) = self.provenance_entity(entity_kind, full_name, source_id) {
+            self.provenance.set_parent(parent);
+        }
+    }
+
+    /// Explanation of a completed entity.
+    pub fn explanation<AnnotatedT>(
+        &self,
+        entity_kind: EntityKind,
+        full_name: &FullName,
+        source_id: &SourceID,
+    ) -> Result<Explanation<'_>, ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Default,
+    {
+        let entity_kind_name = self.source_entity_kinds(source_id)?.represent(entity_kind);
+        let source = self.lookup(entity_kind, &entity_kind_name, source_id, full_name)?;
+        let entity = source.get_entity_ref(entity_kind, &entity_kind_name, &full_name.name)?;
+
+        let provenance_entity =
+            ProvenanceEntity::new(entity_kind, entity_kind_name, source.source_id.clone(), full_name.name.clone());
+        let fields = self.provenance.get(&provenance_entity);
+
+        Ok(Explanation::new(entity, provenance_entity, fields))
+    }
+}
@@ -0,0 +1,26 @@
+use super::{
+    super::{errors::*, hierarchy::*, source::*},
+    catalog::*,
+};
+
+use {compris::annotate::*, kutil::std::error::*};
+
+impl Catalog {
+    /// Type hierarchy of a source, including imported types.
+    ///
+    /// Should be called after completion.
+    pub fn type_hierarchy<AnnotatedT, ErrorRecipientT>(
+        &self,
+        source_id: &SourceID,
+        errors: &mut ErrorRecipientT,
+    ) -> Result<TypeHierarchy, ToscaError<AnnotatedT>>
+    where
+        AnnotatedT: Annotated + Default,
+        ErrorRecipientT: ErrorRecipient<ToscaError<AnnotatedT>>,
+    {
+        let source = unwrap_or_give_and_return!(self.get_source(source_id), errors, Ok(Default::default()));
+        let dialect =
+            unwrap_or_give_and_return!(self.get_dialect_ref(&source.dialect_id), errors, Ok(Default::default()));
+        Ok(TypeHierarchy::new(dialect.source_types(source_id, self)))
+    }
+}
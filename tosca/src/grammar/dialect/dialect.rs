@@ -1,5 +1,5 @@
 use super::{
-    super::{catalog::*, entity::*, errors::*, source::*},
+    super::{catalog::*, entity::*, errors::*, lint::*, source::*},
     id::*,
 };
 
@@ -51,4 +51,20 @@ where
         catalog: &Catalog,
         errors: ToscaErrorRecipientRef,
     ) -> Result<Option<floria::ID>, ToscaError<WithAnnotations>>;
+
+    /// Lint a source.
+    ///
+    /// Returns warnings for all rules. The caller is responsible for filtering out the disabled
+    /// rules.
+    fn lint_source(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<LintWarning>;
+
+    /// Types in the namespace of a source, including imported types.
+    ///
+    /// Should be called after completion.
+    fn source_types(&self, source_id: &SourceID, catalog: &Catalog) -> Vec<TypeInfo>;
+
+    /// Reference documentation for a type.
+    ///
+    /// Should be called after completion.
+    fn document_type(&self, type_info: TypeInfo, catalog: &Catalog) -> Option<TypeDocumentation>;
 }
@@ -0,0 +1,51 @@
+use super::{super::hierarchy::*, member::*};
+
+use kutil::std::immutable::*;
+
+//
+// TypeDocumentation
+//
+
+/// Reference documentation for a completed type.
+#[derive(Clone, Debug)]
+pub struct TypeDocumentation {
+    /// Info.
+    pub info: TypeInfo,
+
+    /// Metadata as text.
+    pub metadata: Vec<(ByteString, String)>,
+
+    /// Ancestor type IDs, from the parent to the root.
+    pub ancestors: Vec<TypeID>,
+
+    /// Sections.
+    pub sections: Vec<DocumentationSection>,
+}
+
+impl TypeDocumentation {
+    /// Constructor.
+    pub fn new(info: TypeInfo) -> Self {
+        Self { info, metadata: Default::default(), ancestors: Default::default(), sections: Default::default() }
+    }
+}
+
+//
+// DocumentationSection
+//
+
+/// Section of a [TypeDocumentation], e.g. "Properties".
+#[derive(Clone, Debug)]
+pub struct DocumentationSection {
+    /// Title.
+    pub title: &'static str,
+
+    /// Members.
+    pub members: Vec<DocumentationMember>,
+}
+
+impl DocumentationSection {
+    /// Constructor.
+    pub fn new(title: &'static str, members: Vec<DocumentationMember>) -> Self {
+        Self { title, members }
+    }
+}
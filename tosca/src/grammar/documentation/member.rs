@@ -0,0 +1,70 @@
+use super::super::{hierarchy::*, name::*};
+
+use kutil::std::immutable::*;
+
+//
+// DocumentationMember
+//
+
+/// Member of a [DocumentationSection](super::DocumentationSection), e.g. a property definition.
+#[derive(Clone, Debug)]
+pub struct DocumentationMember {
+    /// Name.
+    pub name: ByteString,
+
+    /// Referenced types, e.g. the data type of a property definition.
+    pub types: Vec<TypeReference>,
+
+    /// Default as text.
+    pub default: Option<String>,
+
+    /// Whether a value is required.
+    pub required: Option<bool>,
+
+    /// Description.
+    pub description: Option<ByteString>,
+
+    /// ID of the type that declared the member.
+    ///
+    /// If it is not the documented type then the member is inherited.
+    pub declared_by: TypeID,
+
+    /// ID of the ancestor type whose member was overridden by the declaring type, if any.
+    pub overrides: Option<TypeID>,
+}
+
+impl DocumentationMember {
+    /// Constructor.
+    pub fn new(name: ByteString, declared_by: TypeID) -> Self {
+        Self {
+            name,
+            types: Default::default(),
+            default: None,
+            required: None,
+            description: None,
+            declared_by,
+            overrides: None,
+        }
+    }
+}
+
+//
+// TypeReference
+//
+
+/// Reference to a type.
+#[derive(Clone, Debug)]
+pub struct TypeReference {
+    /// Full name as written.
+    pub full_name: FullName,
+
+    /// Type ID if the reference could be resolved.
+    pub type_id: Option<TypeID>,
+}
+
+impl TypeReference {
+    /// Constructor.
+    pub fn new(full_name: FullName, type_id: Option<TypeID>) -> Self {
+        Self { full_name, type_id }
+    }
+}
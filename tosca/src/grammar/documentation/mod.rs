@@ -0,0 +1,5 @@
+mod documentation;
+mod member;
+
+#[allow(unused_imports)]
+pub use {documentation::*, member::*};
@@ -72,6 +72,14 @@ impl EntityKinds {
         self.0.get(&entity_kind)
     }
 
+    /// Find an entity kind by its name, ignoring ASCII case.
+    pub fn find(&self, name: &str) -> Option<EntityKind> {
+        self.0
+            .iter()
+            .find(|(_, entity_kind_name)| name.eq_ignore_ascii_case(entity_kind_name))
+            .map(|(entity_kind, _)| *entity_kind)
+    }
+
     /// Representation of an entity kind.
     ///
     /// Will use its name if supported, otherwise will use a numeric representation.
@@ -20,4 +20,28 @@ pub trait Subentity<ParentT> {
         source_id: &SourceID,
         errors: ToscaErrorRecipientRef,
     ) -> Result<(), ToscaError<WithAnnotations>>;
+
+    /// Lint, before completion.
+    ///
+    /// Only called when the subentity is declared explicitly, i.e. not when it is inherited from
+    /// the parent. Warnings should be added to the catalog.
+    #[allow(unused_variables)]
+    fn lint(&self, name: &ByteString, parent: &ParentT, catalog: &mut Catalog, source_id: &SourceID) {}
+
+    /// If the subentity must be declared by its parent, the name of its kind, e.g. "property".
+    ///
+    /// Subentities that are not declared by the parent are reported as undeclared if
+    /// [Catalog::report_undeclared] is true. When [None], subentities may add to the parent's
+    /// declarations, e.g. in type refinement.
+    fn declared_kind() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Annotations of where the subentity is declared, for provenance.
+    fn declaration_annotations(&self) -> Option<&Annotations> {
+        None
+    }
 }
@@ -0,0 +1,61 @@
+use {
+    compris::annotate::*,
+    kutil::cli::depict::*,
+    std::{fmt, io},
+    thiserror::*,
+};
+
+//
+// InvalidCallError
+//
+
+/// Invalid call error.
+#[derive(Debug, Error)]
+pub struct InvalidCallError<AnnotatedT> {
+    /// Function name.
+    pub function_name: String,
+
+    /// Reason.
+    pub reason: String,
+
+    /// Annotated.
+    pub annotated: AnnotatedT,
+}
+
+impl<AnnotatedT> InvalidCallError<AnnotatedT> {
+    /// Constructor.
+    pub fn new(function_name: String, reason: String) -> Self
+    where
+        AnnotatedT: Default,
+    {
+        Self { function_name, reason, annotated: Default::default() }
+    }
+
+    /// Into different [Annotated] implementation.
+    pub fn into_annotated<NewAnnotationsT>(self) -> InvalidCallError<NewAnnotationsT>
+    where
+        AnnotatedT: Annotated,
+        NewAnnotationsT: Annotated + Default,
+    {
+        InvalidCallError { function_name: self.function_name, reason: self.reason, annotated: Default::default() }
+            .with_annotations_from(&self.annotated)
+    }
+}
+
+impl_dyn_annotated_error!(InvalidCallError);
+
+impl<AnnotatedT> Depict for InvalidCallError<AnnotatedT> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        let function_name = format!("${}", self.function_name);
+        write!(writer, "invalid call to {}: {}", context.theme.name(function_name), context.theme.error(&self.reason))
+    }
+}
+
+impl<AnnotatedT> fmt::Display for InvalidCallError<AnnotatedT> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "${}: {}", self.function_name, self.reason)
+    }
+}
@@ -1,10 +1,12 @@
 mod cyclical_derivation;
+mod invalid_call;
 mod missing_required;
 mod name_reused;
 mod number_overflow;
 mod recipient;
 mod r#ref;
 mod source_not_loaded;
+mod suggestions;
 mod tosca;
 mod undeclared;
 mod unknown_type;
@@ -14,7 +16,7 @@ mod wrong_type;
 
 #[allow(unused_imports)]
 pub use {
-    cyclical_derivation::*, missing_required::*, name_reused::*, number_overflow::*, recipient::*, r#ref::*,
-    source_not_loaded::*, tosca::*, undeclared::*, unknown_type::*, unsupported_dialect::*, unsupported_source::*,
-    wrong_type::*,
+    cyclical_derivation::*, invalid_call::*, missing_required::*, name_reused::*, number_overflow::*, recipient::*,
+    r#ref::*, source_not_loaded::*, suggestions::*, tosca::*, undeclared::*, unknown_type::*, unsupported_dialect::*,
+    unsupported_source::*, wrong_type::*,
 };
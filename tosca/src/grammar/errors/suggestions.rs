@@ -0,0 +1,37 @@
+use {
+    kutil::cli::depict::*,
+    std::{fmt, io},
+};
+
+/// Depict suggestions, if there are any, as a "did you mean" suffix.
+pub fn depict_suggestions<WriteT>(
+    suggestions: &Vec<String>,
+    writer: &mut WriteT,
+    context: &DepictionContext,
+) -> io::Result<()>
+where
+    WriteT: io::Write,
+{
+    if !suggestions.is_empty() {
+        write!(writer, ", did you mean ")?;
+        for (index, suggestion) in suggestions.iter().enumerate() {
+            if index != 0 {
+                write!(writer, " or ")?;
+            }
+            write!(writer, "{}", context.theme.name(format!("{:?}", suggestion)))?;
+        }
+        write!(writer, "?")?;
+    }
+
+    Ok(())
+}
+
+/// Format suggestions, if there are any, as a "did you mean" suffix.
+pub fn fmt_suggestions(suggestions: &Vec<String>, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if !suggestions.is_empty() {
+        let suggestions: Vec<_> = suggestions.iter().map(|suggestion| format!("{:?}", suggestion)).collect();
+        write!(formatter, ", did you mean {}?", suggestions.join(" or "))?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use super::{
-    cyclical_derivation::*, missing_required::*, name_reused::*, number_overflow::*, source_not_loaded::*,
-    undeclared::*, unknown_type::*, unsupported_dialect::*, unsupported_source::*, wrong_type::*,
+    cyclical_derivation::*, invalid_call::*, missing_required::*, name_reused::*, number_overflow::*,
+    source_not_loaded::*, undeclared::*, unknown_type::*, unsupported_dialect::*, unsupported_source::*, wrong_type::*,
 };
 
 use {
@@ -93,6 +93,11 @@ pub enum ToscaError<AnnotatedT> {
     #[depict(as(depict))]
     MissingRequired(#[from] MissingRequiredError<AnnotatedT>),
 
+    /// Invalid call.
+    #[error("invalid call: {0}")]
+    #[depict(as(depict))]
+    InvalidCall(#[from] InvalidCallError<AnnotatedT>),
+
     /// Number overflow.
     #[error("number overflow")]
     #[depict(as(depict))]
@@ -113,6 +118,30 @@ impl<AnnotatedT> ToscaError<AnnotatedT> {
         Box::new(self)
     }
 
+    /// Kind of error, e.g. `invalid_call`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::SourceNotLoaded(_) => "source_not_loaded",
+            Self::UnsupportedSource(_) => "unsupported_source",
+            Self::UnsupportedDialect(_) => "unsupported_dialect",
+            Self::URL(_) => "url",
+            Self::Parse(_) => "parse",
+            Self::Resolve(_) => "resolve",
+            Self::Malformed(_) => "malformed",
+            Self::InvalidKey(_) => "invalid_key",
+            Self::IncompatibleVariantType(_) => "incompatible_variant_type",
+            Self::NameReused(_) => "name_reused",
+            Self::CyclicalDerivation(_) => "cyclical_derivation",
+            Self::UnknownType(_) => "unknown_type",
+            Self::WrongType(_) => "wrong_type",
+            Self::Undeclared(_) => "undeclared",
+            Self::MissingRequired(_) => "missing_required",
+            Self::InvalidCall(_) => "invalid_call",
+            Self::NumberOverflow(_) => "number_overflow",
+            Self::Store(_) => "store",
+        }
+    }
+
     /// Into different [Annotated] implementation.
     pub fn into_annotated<NewAnnotationsT>(self) -> ToscaError<NewAnnotationsT>
     where
@@ -137,6 +166,7 @@ impl<AnnotatedT> ToscaError<AnnotatedT> {
             Self::WrongType(wrong_type) => wrong_type.into_annotated().into(),
             Self::Undeclared(undeclared) => undeclared.into_annotated().into(),
             Self::MissingRequired(missing_required) => missing_required.into_annotated().into(),
+            Self::InvalidCall(invalid_call) => invalid_call.into_annotated().into(),
             Self::NumberOverflow(number_overflow) => number_overflow.into_annotated().into(),
             Self::Store(store) => store.into(),
         }
@@ -170,6 +200,7 @@ where
             Self::WrongType(wrong_type) => wrong_type.annotations(),
             Self::Undeclared(undeclared) => undeclared.annotations(),
             Self::MissingRequired(missing_required) => missing_required.annotations(),
+            Self::InvalidCall(invalid_call) => invalid_call.annotations(),
             Self::NumberOverflow(number_overflow) => number_overflow.annotations(),
             Self::Store(_) => None,
         }
@@ -192,6 +223,7 @@ where
             Self::WrongType(wrong_type) => wrong_type.annotations_mut(),
             Self::Undeclared(undeclared) => undeclared.annotations_mut(),
             Self::MissingRequired(missing_required) => missing_required.annotations_mut(),
+            Self::InvalidCall(invalid_call) => invalid_call.annotations_mut(),
             Self::NumberOverflow(number_overflow) => number_overflow.annotations_mut(),
             Self::Store(_) => None,
         }
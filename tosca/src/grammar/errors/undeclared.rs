@@ -1,3 +1,5 @@
+use super::suggestions::*;
+
 use {
     compris::annotate::*,
     kutil::cli::depict::*,
@@ -18,6 +20,9 @@ pub struct UndeclaredError<AnnotatedT> {
     /// Name.
     pub name: String,
 
+    /// Suggestions for what was meant.
+    pub suggestions: Vec<String>,
+
     /// Annotated.
     pub annotated: AnnotatedT,
 }
@@ -28,7 +33,13 @@ impl<AnnotatedT> UndeclaredError<AnnotatedT> {
     where
         AnnotatedT: Default,
     {
-        Self { type_name, name, annotated: Default::default() }
+        Self { type_name, name, suggestions: Default::default(), annotated: Default::default() }
+    }
+
+    /// With suggestions.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 
     /// Into different [Annotated] implementation.
@@ -37,8 +48,13 @@ impl<AnnotatedT> UndeclaredError<AnnotatedT> {
         AnnotatedT: Annotated,
         NewAnnotationsT: Annotated + Default,
     {
-        UndeclaredError { type_name: self.type_name, name: self.name, annotated: Default::default() }
-            .with_annotations_from(&self.annotated)
+        UndeclaredError {
+            type_name: self.type_name,
+            name: self.name,
+            suggestions: self.suggestions,
+            annotated: Default::default(),
+        }
+        .with_annotations_from(&self.annotated)
     }
 }
 
@@ -50,12 +66,14 @@ impl<AnnotatedT> Depict for UndeclaredError<AnnotatedT> {
         WriteT: io::Write,
     {
         let name = format!("{:?}", self.name);
-        write!(writer, "undeclared {}: {}", context.theme.name(&self.type_name), context.theme.error(name))
+        write!(writer, "undeclared {}: {}", context.theme.name(&self.type_name), context.theme.error(name))?;
+        depict_suggestions(&self.suggestions, writer, context)
     }
 }
 
 impl<AnnotatedT> fmt::Display for UndeclaredError<AnnotatedT> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(formatter, "{}: {}", self.type_name, self.name)
+        write!(formatter, "{}: {}", self.type_name, self.name)?;
+        fmt_suggestions(&self.suggestions, formatter)
     }
 }
@@ -1,3 +1,5 @@
+use super::suggestions::*;
+
 use {
     compris::annotate::*,
     kutil::cli::depict::*,
@@ -18,6 +20,9 @@ pub struct UnknownTypeError<AnnotatedT> {
     /// Context.
     pub context: String,
 
+    /// Suggestions for what was meant.
+    pub suggestions: Vec<String>,
+
     /// Annotated.
     pub annotated: AnnotatedT,
 }
@@ -28,7 +33,13 @@ impl<AnnotatedT> UnknownTypeError<AnnotatedT> {
     where
         AnnotatedT: Default,
     {
-        Self { type_name, context, annotated: Default::default() }
+        Self { type_name, context, suggestions: Default::default(), annotated: Default::default() }
+    }
+
+    /// With suggestions.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
     }
 
     /// Into different [Annotated] implementation.
@@ -37,8 +48,13 @@ impl<AnnotatedT> UnknownTypeError<AnnotatedT> {
         AnnotatedT: Annotated,
         NewAnnotationsT: Annotated + Default,
     {
-        UnknownTypeError { type_name: self.type_name, context: self.context, annotated: Default::default() }
-            .with_annotations_from(&self.annotated)
+        UnknownTypeError {
+            type_name: self.type_name,
+            context: self.context,
+            suggestions: self.suggestions,
+            annotated: Default::default(),
+        }
+        .with_annotations_from(&self.annotated)
     }
 }
 
@@ -50,12 +66,14 @@ impl<AnnotatedT> Depict for UnknownTypeError<AnnotatedT> {
         WriteT: io::Write,
     {
         let type_name = format!("{:?}", self.type_name);
-        write!(writer, "unknown type for {}: {}", self.context, context.theme.error(type_name))
+        write!(writer, "unknown type for {}: {}", self.context, context.theme.error(type_name))?;
+        depict_suggestions(&self.suggestions, writer, context)
     }
 }
 
 impl<AnnotatedT> fmt::Display for UnknownTypeError<AnnotatedT> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(formatter, "{}, {}", self.type_name, self.context)
+        write!(formatter, "{}, {}", self.type_name, self.context)?;
+        fmt_suggestions(&self.suggestions, formatter)
     }
 }
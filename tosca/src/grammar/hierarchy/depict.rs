@@ -0,0 +1,57 @@
+use super::hierarchy::*;
+
+use {
+    kutil::{cli::depict::*, std::iter::*},
+    std::io,
+};
+
+const PREFIX: char = '@';
+
+impl Depict for TypeHierarchy {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        for ((entity_kind_name, roots), first) in IterateWithFirst::new(&self.kinds) {
+            if !first {
+                writeln!(writer)?;
+                context.indent(writer)?;
+            }
+
+            context.theme.write_heading(writer, entity_kind_name)?;
+
+            for (root, last) in IterateWithLast::new(roots) {
+                context.indent_into_branch(writer, last)?;
+                root.depict(writer, &context.child().increase_indentation_branch(last))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Depict for TypeNode {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        write!(
+            writer,
+            "{} {}{}",
+            context.theme.name(&self.info.full_name),
+            context.theme.delimiter(PREFIX),
+            context.theme.meta(&self.info.type_id.source_id)
+        )?;
+
+        if let Some(version) = &self.info.version {
+            write!(writer, " {}", context.theme.string(format!("{:?}", version)))?;
+        }
+
+        for (child, last) in IterateWithLast::new(&self.children) {
+            context.indent_into_branch(writer, last)?;
+            child.depict(writer, &context.child().increase_indentation_branch(last))?;
+        }
+
+        Ok(())
+    }
+}
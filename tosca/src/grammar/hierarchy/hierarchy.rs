@@ -0,0 +1,272 @@
+use super::{super::entity::*, info::*};
+
+use {
+    kutil::std::{collections::*, immutable::*},
+    std::collections::*,
+};
+
+//
+// TypeHierarchy
+//
+
+/// Type hierarchy.
+///
+/// A `derived_from` tree per entity kind.
+#[derive(Clone, Debug, Default)]
+pub struct TypeHierarchy {
+    /// Root types by entity kind.
+    pub kinds: Vec<(ByteString, Vec<TypeNode>)>,
+}
+
+impl TypeHierarchy {
+    /// Constructor.
+    ///
+    /// A type that is seen under more than one full name appears once, under its shortest full
+    /// name. Types whose parent is unknown are roots.
+    pub fn new(types: Vec<TypeInfo>) -> Self {
+        let unique = TypeInfo::unique(types);
+
+        let mut children = FastHashMap::<TypeID, Vec<TypeInfo>>::default();
+        let mut roots = BTreeMap::<EntityKind, (ByteString, Vec<TypeInfo>)>::default();
+
+        for info in unique.values() {
+            match &info.parent_type_id {
+                Some(parent_type_id) if unique.contains_key(parent_type_id) => {
+                    children.entry(parent_type_id.clone()).or_default().push(info.clone())
+                }
+
+                _ => roots
+                    .entry(info.type_id.entity_kind)
+                    .or_insert_with(|| (info.entity_kind_name.clone(), Default::default()))
+                    .1
+                    .push(info.clone()),
+            }
+        }
+
+        Self {
+            kinds: roots
+                .into_values()
+                .map(|(entity_kind_name, roots)| (entity_kind_name, TypeNode::new_list(roots, &children)))
+                .collect(),
+        }
+    }
+
+    /// True if empty.
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    /// Keep only the entity kinds.
+    ///
+    /// Entity kind names are compared case-insensitively.
+    pub fn retain_entity_kinds(&mut self, entity_kind_names: &[String]) {
+        self.kinds.retain(|(entity_kind_name, _)| {
+            entity_kind_names.iter().any(|name| name.eq_ignore_ascii_case(entity_kind_name))
+        });
+    }
+
+    /// Keep only the types that match and their ancestors.
+    pub fn retain_names<MatchT>(&mut self, matches: MatchT)
+    where
+        MatchT: Fn(&str) -> bool,
+    {
+        for (_, roots) in &mut self.kinds {
+            TypeNode::retain_names(roots, &matches);
+        }
+
+        self.kinds.retain(|(_, roots)| !roots.is_empty());
+    }
+
+    /// Keep only the types with the name and their descendants.
+    ///
+    /// The name can be a full name or a name without the namespace prefix.
+    pub fn retain_descendants_of(&mut self, name: &str) {
+        for (_, roots) in &mut self.kinds {
+            let mut found = Vec::default();
+            TypeNode::find(std::mem::take(roots), name, &mut found);
+            *roots = found;
+        }
+
+        self.kinds.retain(|(_, roots)| !roots.is_empty());
+    }
+}
+
+//
+// TypeNode
+//
+
+/// Type node in a [TypeHierarchy].
+#[derive(Clone, Debug)]
+pub struct TypeNode {
+    /// Info.
+    pub info: TypeInfo,
+
+    /// Derived types.
+    pub children: Vec<TypeNode>,
+}
+
+impl TypeNode {
+    /// True if the name matches the full name or the name without the namespace prefix.
+    pub fn is_named(&self, name: &str) -> bool {
+        (self.info.full_name.to_string() == name) || (self.info.full_name.name.to_string() == name)
+    }
+
+    // Sorted by full name
+    fn new_list(infos: Vec<TypeInfo>, children: &FastHashMap<TypeID, Vec<TypeInfo>>) -> Vec<Self> {
+        let mut nodes: Vec<_> = infos
+            .into_iter()
+            .map(|info| {
+                let node_children = children.get(&info.type_id).cloned().unwrap_or_default();
+                Self { children: Self::new_list(node_children, children), info }
+            })
+            .collect();
+
+        nodes.sort_by_key(|node| node.info.full_name.to_string());
+        nodes
+    }
+
+    fn retain_names<MatchT>(nodes: &mut Vec<Self>, matches: &MatchT)
+    where
+        MatchT: Fn(&str) -> bool,
+    {
+        nodes.retain_mut(|node| {
+            Self::retain_names(&mut node.children, matches);
+            !node.children.is_empty() || matches(&node.info.full_name.to_string())
+        });
+    }
+
+    fn find(nodes: Vec<Self>, name: &str, found: &mut Vec<Self>) {
+        for node in nodes {
+            if node.is_named(name) {
+                found.push(node);
+            } else {
+                Self::find(node.children, name, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{super::super::source::*, *},
+        kutil::std::immutable::*,
+    };
+
+    const NODE_TYPE: EntityKind = EntityKind(1);
+    const DATA_TYPE: EntityKind = EntityKind(2);
+
+    fn type_id(entity_kind: EntityKind, source: &'static str, name: &'static str) -> TypeID {
+        TypeID::new(entity_kind, SourceID::URL(ByteString::from_static(source)), name.into())
+    }
+
+    fn info(type_id: TypeID, full_name: &str, parent_type_id: Option<TypeID>) -> TypeInfo {
+        TypeInfo {
+            entity_kind_name: ByteString::from_static(if type_id.entity_kind == NODE_TYPE {
+                "node type"
+            } else {
+                "data type"
+            }),
+            type_id,
+            full_name: full_name.parse().expect("full name"),
+            parent_type_id,
+            version: None,
+            description: None,
+        }
+    }
+
+    /// Hierarchy:
+    ///
+    /// * node types: "Root" (also seen as "base:Root") <- "Server" <- "web:Web", and "Orphan" with
+    ///   an unknown parent
+    /// * data types: "Root", which has the same name as the node type
+    fn type_hierarchy() -> TypeHierarchy {
+        let root = type_id(NODE_TYPE, "base.yaml", "Root");
+        let server = type_id(NODE_TYPE, "main.yaml", "Server");
+
+        TypeHierarchy::new(vec![
+            info(root.clone(), "base:Root", None),
+            info(type_id(NODE_TYPE, "web.yaml", "Web"), "web:Web", Some(server.clone())),
+            info(server, "Server", Some(root.clone())),
+            info(root, "Root", None),
+            info(
+                type_id(NODE_TYPE, "main.yaml", "Orphan"),
+                "Orphan",
+                Some(type_id(NODE_TYPE, "other.yaml", "Missing")),
+            ),
+            info(type_id(DATA_TYPE, "base.yaml", "Root"), "Root", None),
+        ])
+    }
+
+    // Entity kind names with their trees, e.g. "Root(Server)"
+    fn trees(hierarchy: &TypeHierarchy) -> Vec<(String, Vec<String>)> {
+        fn trees(nodes: &[TypeNode]) -> Vec<String> {
+            nodes
+                .iter()
+                .map(|node| {
+                    if node.children.is_empty() {
+                        node.info.full_name.to_string()
+                    } else {
+                        format!("{}({})", node.info.full_name, trees(&node.children).join(", "))
+                    }
+                })
+                .collect()
+        }
+
+        hierarchy.kinds.iter().map(|(entity_kind_name, roots)| (entity_kind_name.to_string(), trees(roots))).collect()
+    }
+
+    fn tree(entity_kind_name: &str, roots: &[&str]) -> (String, Vec<String>) {
+        (entity_kind_name.into(), roots.iter().map(|root| (*root).into()).collect())
+    }
+
+    #[test]
+    fn new() {
+        // Types are kept once under their shortest full name, and types of different kinds stay apart
+        assert_eq!(
+            trees(&type_hierarchy()),
+            [tree("node type", &["Orphan", "Root(Server(web:Web))"]), tree("data type", &["Root"])]
+        );
+    }
+
+    #[test]
+    fn retain_entity_kinds() {
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_entity_kinds(&["Data Type".into()]);
+        assert_eq!(trees(&hierarchy), [tree("data type", &["Root"])]);
+    }
+
+    #[test]
+    fn retain_names() {
+        // Ancestors are kept
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_names(|name| name == "web:Web");
+        assert_eq!(trees(&hierarchy), [tree("node type", &["Root(Server(web:Web))"])]);
+
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_names(|name| name.starts_with('O'));
+        assert_eq!(trees(&hierarchy), [tree("node type", &["Orphan"])]);
+    }
+
+    #[test]
+    fn retain_descendants_of() {
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_descendants_of("Server");
+        assert_eq!(trees(&hierarchy), [tree("node type", &["Server(web:Web)"])]);
+
+        // With or without the namespace prefix
+        for name in ["web:Web", "Web"] {
+            let mut hierarchy = type_hierarchy();
+            hierarchy.retain_descendants_of(name);
+            assert_eq!(trees(&hierarchy), [tree("node type", &["web:Web"])]);
+        }
+
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_descendants_of("Root");
+        assert_eq!(trees(&hierarchy), [tree("node type", &["Root(Server(web:Web))"]), tree("data type", &["Root"])]);
+
+        let mut hierarchy = type_hierarchy();
+        hierarchy.retain_descendants_of("Missing");
+        assert!(hierarchy.is_empty());
+    }
+}
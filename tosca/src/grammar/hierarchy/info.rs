@@ -0,0 +1,75 @@
+use super::super::{entity::*, name::*, source::*};
+
+use kutil::std::{collections::*, immutable::*};
+
+//
+// TypeID
+//
+
+/// Identifies a type independently of the namespace it is seen from.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TypeID {
+    /// Entity kind.
+    pub entity_kind: EntityKind,
+
+    /// Source ID of the source in which the type is declared.
+    pub source_id: SourceID,
+
+    /// Name in the source in which the type is declared.
+    pub name: Name,
+}
+
+impl TypeID {
+    /// Constructor.
+    pub fn new(entity_kind: EntityKind, source_id: SourceID, name: Name) -> Self {
+        Self { entity_kind, source_id, name }
+    }
+}
+
+//
+// TypeInfo
+//
+
+/// Type information for a [TypeHierarchy](super::TypeHierarchy).
+#[derive(Clone, Debug)]
+pub struct TypeInfo {
+    /// Entity kind name.
+    pub entity_kind_name: ByteString,
+
+    /// Type ID.
+    pub type_id: TypeID,
+
+    /// Full name as seen from the source, including the namespace prefix.
+    pub full_name: FullName,
+
+    /// Parent (`derived_from`) type ID.
+    pub parent_type_id: Option<TypeID>,
+
+    /// Version.
+    pub version: Option<String>,
+
+    /// Description.
+    pub description: Option<ByteString>,
+}
+
+impl TypeInfo {
+    /// Deduplicate.
+    ///
+    /// A type that is seen under more than one full name is kept once, under its shortest full
+    /// name.
+    pub fn unique(types: Vec<Self>) -> FastHashMap<TypeID, Self> {
+        let mut unique = FastHashMap::<TypeID, Self>::default();
+
+        for info in types {
+            match unique.get(&info.type_id) {
+                Some(existing) if existing.full_name.scope.segments.len() <= info.full_name.scope.segments.len() => {}
+
+                _ => {
+                    unique.insert(info.type_id.clone(), info);
+                }
+            }
+        }
+
+        unique
+    }
+}
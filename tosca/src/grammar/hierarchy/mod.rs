@@ -0,0 +1,6 @@
+mod depict;
+mod hierarchy;
+mod info;
+
+#[allow(unused_imports)]
+pub use {depict::*, hierarchy::*, info::*};
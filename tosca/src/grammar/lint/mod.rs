@@ -0,0 +1,6 @@
+mod references;
+mod rule;
+mod warning;
+
+#[allow(unused_imports)]
+pub use {references::*, rule::*, warning::*};
@@ -0,0 +1,41 @@
+use super::super::{entity::*, name::*, source::*};
+
+use {kutil::std::immutable::*, std::collections::*};
+
+//
+// References
+//
+
+/// References that were followed during completion.
+///
+/// Used for linting.
+#[derive(Debug, Default)]
+pub struct References {
+    /// Referenced entities as kind, declaring source, and name.
+    pub entities: BTreeSet<(EntityKind, SourceID, Name)>,
+
+    /// Scopes used in full names as referencing source and scope.
+    pub scopes: BTreeSet<(SourceID, Scope)>,
+
+    /// Inputs read with `$get_input` as referencing source and input name.
+    pub inputs: BTreeSet<(SourceID, ByteString)>,
+}
+
+impl References {
+    /// True if the entity was referenced.
+    pub fn has_entity(&self, entity_kind: EntityKind, source_id: &SourceID, name: &Name) -> bool {
+        self.entities.contains(&(entity_kind, source_id.clone(), name.clone()))
+    }
+
+    /// True if the scope (or a scope nested in it) was used by the source.
+    pub fn has_scope(&self, source_id: &SourceID, scope: &Scope) -> bool {
+        self.scopes.iter().any(|(referencing_source_id, referenced_scope)| {
+            (referencing_source_id == source_id) && referenced_scope.segments.starts_with(&scope.segments)
+        })
+    }
+
+    /// True if the input was read by the source.
+    pub fn has_input(&self, source_id: &SourceID, name: &ByteString) -> bool {
+        self.inputs.contains(&(source_id.clone(), name.clone()))
+    }
+}
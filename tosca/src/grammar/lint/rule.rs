@@ -0,0 +1,101 @@
+use std::{collections::*, fmt};
+
+//
+// LintRule
+//
+
+/// Lint rule.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LintRule {
+    /// Type that is declared but never referenced.
+    UnusedType,
+
+    /// Service template input that is never read with `$get_input`.
+    UnusedInput,
+
+    /// Repository that is declared but never referenced.
+    UnusedRepository,
+
+    /// Import with a namespace that is never used in a name.
+    UnusedImport,
+
+    /// Node template for which neither the template nor its type has a description.
+    UndescribedNodeTemplate,
+
+    /// Metadata keys that differ only in case or in `-` versus `_`.
+    DuplicateMetadataKey,
+
+    /// Property that is redeclared with the same default (or value) as its parent.
+    RedundantDefault,
+}
+
+impl LintRule {
+    /// All rules.
+    pub const ALL: &[Self] = &[
+        Self::UnusedType,
+        Self::UnusedInput,
+        Self::UnusedRepository,
+        Self::UnusedImport,
+        Self::UndescribedNodeTemplate,
+        Self::DuplicateMetadataKey,
+        Self::RedundantDefault,
+    ];
+
+    /// Name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedType => "unused-type",
+            Self::UnusedInput => "unused-input",
+            Self::UnusedRepository => "unused-repository",
+            Self::UnusedImport => "unused-import",
+            Self::UndescribedNodeTemplate => "undescribed-node-template",
+            Self::DuplicateMetadataKey => "duplicate-metadata-key",
+            Self::RedundantDefault => "redundant-default",
+        }
+    }
+
+    /// Find by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|rule| rule.name() == name).copied()
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.name(), formatter)
+    }
+}
+
+//
+// LintRules
+//
+
+/// Enabled lint rules.
+#[derive(Clone, Debug)]
+pub struct LintRules {
+    /// Enabled rules.
+    pub enabled: BTreeSet<LintRule>,
+}
+
+impl LintRules {
+    /// True if the rule is enabled.
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        self.enabled.contains(&rule)
+    }
+
+    /// Enable or disable a rule.
+    pub fn set(&mut self, rule: LintRule, enabled: bool) {
+        if enabled {
+            self.enabled.insert(rule);
+        } else {
+            self.enabled.remove(&rule);
+        }
+    }
+}
+
+impl Default for LintRules {
+    // All rules are enabled
+    fn default() -> Self {
+        Self { enabled: LintRule::ALL.iter().copied().collect() }
+    }
+}
@@ -0,0 +1,108 @@
+use super::{super::source::*, rule::*};
+
+use {
+    compris::annotate::*,
+    kutil::{cli::depict::*, std::iter::*},
+    std::{fmt, io},
+};
+
+//
+// LintWarning
+//
+
+/// Lint warning.
+///
+/// Unlike [ToscaError](super::super::errors::ToscaError), a lint warning is not fatal.
+#[derive(Clone, Debug)]
+pub struct LintWarning {
+    /// Rule.
+    pub rule: LintRule,
+
+    /// Message.
+    pub message: String,
+
+    /// Source ID.
+    pub source_id: SourceID,
+
+    /// Annotations.
+    pub annotations: Option<Annotations>,
+}
+
+impl LintWarning {
+    /// Constructor.
+    pub fn new(rule: LintRule, message: String, source_id: SourceID, annotations: Option<Annotations>) -> Self {
+        Self { rule, message, source_id, annotations }
+    }
+
+    /// Location, e.g. `file.yaml:12:5`.
+    pub fn location(&self) -> String {
+        match &self.annotations {
+            Some(annotations) => match (&annotations.source, &annotations.span) {
+                (Some(source), Some(span)) => format!("{}:{}:{}", source, span.start.row, span.start.column),
+                (Some(source), None) => source.to_string(),
+                _ => self.source_id.to_string(),
+            },
+
+            None => self.source_id.to_string(),
+        }
+    }
+}
+
+impl Depict for LintWarning {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        write!(
+            writer,
+            "{} {} {}",
+            context.theme.meta(self.location()),
+            context.theme.name(self.rule),
+            context.theme.error(&self.message)
+        )
+    }
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}: {} ({})", self.location(), self.message, self.rule)
+    }
+}
+
+//
+// LintWarnings
+//
+
+/// Lint warnings.
+#[derive(Clone, Debug, Default)]
+pub struct LintWarnings(pub Vec<LintWarning>);
+
+impl LintWarnings {
+    /// True if empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add a warning if its rule is enabled.
+    pub fn give(&mut self, warning: LintWarning, rules: &LintRules) {
+        if rules.is_enabled(warning.rule) {
+            self.0.push(warning);
+        }
+    }
+}
+
+impl Depict for LintWarnings {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.theme.write_heading(writer, "Lint Warnings")?;
+
+        for (warning, last) in IterateWithLast::new(&self.0) {
+            context.indent_into_branch(writer, last)?;
+            warning.depict(writer, context)?;
+        }
+
+        Ok(())
+    }
+}
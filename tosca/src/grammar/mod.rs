@@ -1,11 +1,18 @@
 mod catalog;
 mod data;
 mod dialect;
+mod documentation;
 mod entity;
 mod errors;
+mod hierarchy;
+mod lint;
 mod name;
+mod provenance;
 mod source;
 mod utils;
 
 #[allow(unused_imports)]
-pub use {catalog::*, data::*, dialect::*, entity::*, errors::*, name::*, source::*, utils::*};
+pub use {
+    catalog::*, data::*, dialect::*, documentation::*, entity::*, errors::*, hierarchy::*, lint::*, name::*,
+    provenance::*, source::*, utils::*,
+};
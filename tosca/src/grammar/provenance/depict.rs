@@ -0,0 +1,62 @@
+use super::{entity::*, field::*, provenance::*};
+
+use {
+    kutil::{cli::depict::*, std::iter::*},
+    std::{collections::*, io},
+};
+
+impl Provenance {
+    /// To [ProvenanceDepiction], if the entity was completed.
+    pub fn depiction<'own>(&'own self, entity: &ProvenanceEntity) -> Option<ProvenanceDepiction<'own>> {
+        Some(ProvenanceDepiction::new(self.get(entity)?))
+    }
+}
+
+impl Depict for FieldProvenance {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        write!(writer, "{}", self.origin)?;
+
+        if let Some(location) = self.location() {
+            write!(writer, " {}", context.theme.meta(location))?;
+        }
+
+        Ok(())
+    }
+}
+
+//
+// ProvenanceDepiction
+//
+
+/// Provenance depiction of a completed entity.
+pub struct ProvenanceDepiction<'own> {
+    /// Field provenance by field path.
+    pub fields: &'own BTreeMap<String, FieldProvenance>,
+}
+
+impl<'own> ProvenanceDepiction<'own> {
+    /// Constructor.
+    pub fn new(fields: &'own BTreeMap<String, FieldProvenance>) -> Self {
+        Self { fields }
+    }
+}
+
+impl<'own> Depict for ProvenanceDepiction<'own> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.theme.write_heading(writer, "Provenance")?;
+
+        for ((path, field), last) in IterateWithLast::new(self.fields) {
+            context.indent_into_branch(writer, last)?;
+            write!(writer, "{} ", context.theme.name(path))?;
+            field.depict(writer, context)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::{entity::*, name::*, source::*};
+
+use {kutil::std::immutable::*, std::fmt};
+
+//
+// ProvenanceEntity
+//
+
+/// Entity that contributed fields to a completed entity.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ProvenanceEntity {
+    /// Entity kind.
+    pub entity_kind: EntityKind,
+
+    /// Entity kind name.
+    pub entity_kind_name: ByteString,
+
+    /// Source ID of the source in which the entity is declared.
+    pub source_id: SourceID,
+
+    /// Name in the source in which the entity is declared.
+    pub name: Name,
+}
+
+impl ProvenanceEntity {
+    /// Constructor.
+    pub fn new(entity_kind: EntityKind, entity_kind_name: ByteString, source_id: SourceID, name: Name) -> Self {
+        Self { entity_kind, entity_kind_name, source_id, name }
+    }
+}
+
+impl fmt::Display for ProvenanceEntity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} {:?}", self.entity_kind_name, self.name.to_string())
+    }
+}
@@ -0,0 +1,53 @@
+use super::{super::entity::*, depict::*, entity::*, field::*};
+
+use {
+    kutil::cli::depict::*,
+    std::{collections::*, io},
+};
+
+//
+// Explanation
+//
+
+/// Explanation of a completed entity.
+///
+/// Depicts the entity followed by the provenance of its fields.
+pub struct Explanation<'own> {
+    /// Entity.
+    pub entity: &'own EntityRef,
+
+    /// Provenance entity.
+    pub provenance_entity: ProvenanceEntity,
+
+    /// Field provenance by field path, if recorded.
+    pub fields: Option<&'own BTreeMap<String, FieldProvenance>>,
+}
+
+impl<'own> Explanation<'own> {
+    /// Constructor.
+    pub fn new(
+        entity: &'own EntityRef,
+        provenance_entity: ProvenanceEntity,
+        fields: Option<&'own BTreeMap<String, FieldProvenance>>,
+    ) -> Self {
+        Self { entity, provenance_entity, fields }
+    }
+}
+
+impl<'own> Depict for Explanation<'own> {
+    fn depict<WriteT>(&self, writer: &mut WriteT, context: &DepictionContext) -> io::Result<()>
+    where
+        WriteT: io::Write,
+    {
+        context.theme.write_heading(writer, self.provenance_entity.to_string())?;
+        self.entity.dyn_depict(Box::new(&mut *writer), &context.child())?;
+
+        writeln!(writer)?;
+        context.indent(writer)?;
+
+        match self.fields {
+            Some(fields) => ProvenanceDepiction::new(fields).depict(writer, context),
+            None => write!(writer, "{}", context.theme.meta("(no provenance recorded)")),
+        }
+    }
+}
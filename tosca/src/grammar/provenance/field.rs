@@ -0,0 +1,90 @@
+use super::entity::*;
+
+use {compris::annotate::*, std::fmt};
+
+//
+// FieldOrigin
+//
+
+/// Origin of a completed field.
+///
+/// The entity is the one in which the field was originally written. It is [None] if it could not
+/// be determined.
+#[derive(Clone, Debug)]
+pub enum FieldOrigin {
+    /// Written in the entity itself with nothing to inherit.
+    Declared,
+
+    /// Written in the entity itself, overriding the field in another entity.
+    Overridden(Option<ProvenanceEntity>),
+
+    /// Inherited from another entity, e.g. a type or an ancestor type.
+    Inherited(Option<ProvenanceEntity>),
+
+    /// Copied from another template with `copy`.
+    Copied(Option<ProvenanceEntity>),
+}
+
+impl FieldOrigin {
+    /// The entity in which the field was written, if it is another entity.
+    pub fn entity(&self) -> Option<&ProvenanceEntity> {
+        match self {
+            Self::Declared => None,
+            Self::Overridden(entity) | Self::Inherited(entity) | Self::Copied(entity) => entity.as_ref(),
+        }
+    }
+}
+
+impl fmt::Display for FieldOrigin {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Declared => write!(formatter, "declared here"),
+            Self::Overridden(None) => write!(formatter, "overridden here"),
+            Self::Overridden(Some(entity)) => write!(formatter, "overridden here (was in {})", entity),
+            Self::Inherited(None) => write!(formatter, "inherited"),
+            Self::Inherited(Some(entity)) => write!(formatter, "inherited from {}", entity),
+            Self::Copied(None) => write!(formatter, "copied"),
+            Self::Copied(Some(entity)) => write!(formatter, "copied from {}", entity),
+        }
+    }
+}
+
+//
+// FieldProvenance
+//
+
+/// Provenance of a completed field.
+#[derive(Clone, Debug)]
+pub struct FieldProvenance {
+    /// Origin.
+    pub origin: FieldOrigin,
+
+    /// Annotations of the field where it was written.
+    pub annotations: Option<Annotations>,
+}
+
+impl FieldProvenance {
+    /// Constructor.
+    pub fn new(origin: FieldOrigin, annotations: Option<Annotations>) -> Self {
+        Self { origin, annotations }
+    }
+
+    /// Location, e.g. `file.yaml:12`.
+    pub fn location(&self) -> Option<String> {
+        let annotations = self.annotations.as_ref()?;
+        let source = annotations.source.as_ref()?;
+        Some(match &annotations.span {
+            Some(span) => format!("{}:{}", source, span.start.row),
+            None => source.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for FieldProvenance {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location() {
+            Some(location) => write!(formatter, "{} ({})", self.origin, location),
+            None => fmt::Display::fmt(&self.origin, formatter),
+        }
+    }
+}
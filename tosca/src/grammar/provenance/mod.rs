@@ -0,0 +1,8 @@
+mod depict;
+mod entity;
+mod explanation;
+mod field;
+mod provenance;
+
+#[allow(unused_imports)]
+pub use {depict::*, entity::*, explanation::*, field::*, provenance::*};
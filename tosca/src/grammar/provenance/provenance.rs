@@ -0,0 +1,263 @@
+use super::{entity::*, field::*};
+
+use {compris::annotate::*, kutil::std::collections::*, std::collections::*};
+
+//
+// Provenance
+//
+
+/// Provenance recorded during completion.
+///
+/// For every completed entity, maps field paths (e.g. `properties.port` or
+/// `capabilities.host.properties.num_cpus`) to where they came from.
+#[derive(Debug, Default)]
+pub struct Provenance {
+    /// Field provenance by entity and field path.
+    pub entities: FastHashMap<ProvenanceEntity, BTreeMap<String, FieldProvenance>>,
+
+    frames: Vec<ProvenanceFrame>,
+}
+
+impl Provenance {
+    /// Field provenance of an entity.
+    pub fn get(&self, entity: &ProvenanceEntity) -> Option<&BTreeMap<String, FieldProvenance>> {
+        self.entities.get(entity)
+    }
+
+    /// Enter the completion of an entity.
+    pub fn enter_entity(&mut self, entity: ProvenanceEntity) {
+        self.frames.push(ProvenanceFrame { entity: Some(entity), segment: None, parent: None });
+    }
+
+    /// Enter the completion of a member of a map field, e.g. `properties.port`.
+    pub fn enter_member(&mut self, field_name: &str, name: &str) {
+        self.frames.push(ProvenanceFrame {
+            entity: None,
+            segment: Some(format!("{}.{}", field_name, name)),
+            parent: None,
+        });
+    }
+
+    /// Exit the last entered entity or member.
+    pub fn exit(&mut self) {
+        self.frames.pop();
+    }
+
+    /// Set the entity from which the current entity or member inherits, e.g. its type.
+    pub fn set_parent(&mut self, parent: ProvenanceEntity) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.parent = Some(parent);
+        }
+    }
+
+    /// Record the current member.
+    ///
+    /// A member that is both in the parent and in the entity is overridden. However, a member
+    /// nested in an inherited or copied member shares its origin.
+    pub fn add_member(&mut self, in_parent: bool, in_entity: bool, annotations: Option<Annotations>) {
+        let Some(entity_index) = self.frames.iter().rposition(|frame| frame.entity.is_some()) else {
+            return;
+        };
+
+        let path = self.path(entity_index);
+        let entity = self.frames[entity_index].entity.clone().expect("entity");
+
+        // Nested in an inherited or copied member?
+        let enclosing = self.get(&entity).and_then(|fields| {
+            fields
+                .iter()
+                .filter(|(field_path, _)| {
+                    path.starts_with(field_path.as_str()) && path[field_path.len()..].starts_with('.')
+                })
+                .max_by_key(|(field_path, _)| field_path.len())
+        });
+
+        let origin = match enclosing.map(|(_, field)| &field.origin) {
+            Some(FieldOrigin::Inherited(entity)) => FieldOrigin::Inherited(entity.clone()),
+            Some(FieldOrigin::Copied(entity)) => FieldOrigin::Copied(entity.clone()),
+
+            _ => {
+                if in_parent {
+                    let parent = self.parent(entity_index);
+                    if in_entity { FieldOrigin::Overridden(parent) } else { FieldOrigin::Inherited(parent) }
+                } else {
+                    FieldOrigin::Declared
+                }
+            }
+        };
+
+        self.entities.entry(entity).or_default().insert(path, FieldProvenance::new(origin, annotations));
+    }
+
+    /// Record a field of the current entity or member as copied.
+    pub fn add_copied(&mut self, field_name: &str, from: Option<ProvenanceEntity>, annotations: Option<Annotations>) {
+        let Some(entity_index) = self.frames.iter().rposition(|frame| frame.entity.is_some()) else {
+            return;
+        };
+
+        let mut path = self.path(entity_index);
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(field_name);
+
+        let entity = self.frames[entity_index].entity.clone().expect("entity");
+        self.entities
+            .entry(entity)
+            .or_default()
+            .insert(path, FieldProvenance::new(FieldOrigin::Copied(from), annotations));
+    }
+
+    // Path of the current member relative to the frame
+    fn path(&self, index: usize) -> String {
+        let segments: Vec<_> = self.frames[index + 1..].iter().filter_map(|frame| frame.segment.as_deref()).collect();
+        segments.join(".")
+    }
+
+    // The nearest parent, resolved to the entity in which the member was originally written
+    fn parent(&self, entity_index: usize) -> Option<ProvenanceEntity> {
+        let parent_index =
+            (entity_index..self.frames.len()).rev().find(|index| self.frames[*index].parent.is_some())?;
+        let parent = self.frames[parent_index].parent.clone()?;
+
+        let path = self.path(parent_index);
+        let origin = self.get(&parent).and_then(|fields| fields.get(&path)).map(|field| &field.origin);
+        Some(match origin {
+            Some(FieldOrigin::Inherited(Some(entity)) | FieldOrigin::Copied(Some(entity))) => entity.clone(),
+            _ => parent,
+        })
+    }
+}
+
+//
+// ProvenanceFrame
+//
+
+#[derive(Debug)]
+struct ProvenanceFrame {
+    entity: Option<ProvenanceEntity>,
+    segment: Option<String>,
+    parent: Option<ProvenanceEntity>,
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            super::super::{entity::*, source::*},
+            *,
+        },
+        kutil::std::immutable::*,
+    };
+
+    fn entity(entity_kind_name: &'static str, name: &'static str) -> ProvenanceEntity {
+        ProvenanceEntity::new(
+            EntityKind(1),
+            ByteString::from_static(entity_kind_name),
+            SourceID::Internal(ByteString::from_static("test")),
+            name.into(),
+        )
+    }
+
+    fn add_member(provenance: &mut Provenance, field_name: &str, name: &str, in_parent: bool, in_entity: bool) {
+        provenance.enter_member(field_name, name);
+        provenance.add_member(in_parent, in_entity, None);
+        provenance.exit();
+    }
+
+    fn origin(provenance: &Provenance, entity: &ProvenanceEntity, path: &str) -> String {
+        provenance.get(entity).and_then(|fields| fields.get(path)).expect("field").origin.to_string()
+    }
+
+    #[test]
+    fn declared_overridden_inherited() {
+        let mut provenance = Provenance::default();
+        let node_type = entity("node_type", "Server");
+        let node_template = entity("node_template", "server");
+
+        provenance.enter_entity(node_template.clone());
+        provenance.set_parent(node_type);
+        add_member(&mut provenance, "properties", "name", false, true);
+        add_member(&mut provenance, "properties", "port", true, true);
+        add_member(&mut provenance, "properties", "host", true, false);
+        provenance.exit();
+
+        assert_eq!(origin(&provenance, &node_template, "properties.name"), "declared here");
+        assert_eq!(
+            origin(&provenance, &node_template, "properties.port"),
+            "overridden here (was in node_type \"Server\")"
+        );
+        assert_eq!(origin(&provenance, &node_template, "properties.host"), "inherited from node_type \"Server\"");
+    }
+
+    #[test]
+    fn inherited_from_ancestor() {
+        let mut provenance = Provenance::default();
+        let base_type = entity("node_type", "Base");
+        let node_type = entity("node_type", "Server");
+        let node_template = entity("node_template", "server");
+
+        // The type inherits the property from its parent type
+        provenance.enter_entity(node_type.clone());
+        provenance.set_parent(base_type);
+        add_member(&mut provenance, "properties", "port", true, false);
+        provenance.exit();
+
+        provenance.enter_entity(node_template.clone());
+        provenance.set_parent(node_type);
+        add_member(&mut provenance, "properties", "port", true, true);
+        add_member(&mut provenance, "properties", "host", true, false);
+        provenance.exit();
+
+        // The entity in which the property was originally written
+        assert_eq!(
+            origin(&provenance, &node_template, "properties.port"),
+            "overridden here (was in node_type \"Base\")"
+        );
+        assert_eq!(origin(&provenance, &node_template, "properties.host"), "inherited from node_type \"Server\"");
+    }
+
+    #[test]
+    fn nested_member_shares_origin() {
+        let mut provenance = Provenance::default();
+        let node_type = entity("node_type", "Server");
+        let node_template = entity("node_template", "server");
+
+        provenance.enter_entity(node_template.clone());
+        provenance.set_parent(node_type);
+        provenance.enter_member("capabilities", "host");
+        provenance.add_member(true, false, None);
+        add_member(&mut provenance, "properties", "num_cpus", true, true);
+        provenance.exit();
+        provenance.exit();
+
+        assert_eq!(
+            origin(&provenance, &node_template, "capabilities.host.properties.num_cpus"),
+            "inherited from node_type \"Server\""
+        );
+    }
+
+    #[test]
+    fn copied() {
+        let mut provenance = Provenance::default();
+        let node_type = entity("node_type", "Server");
+        let node_template = entity("node_template", "server");
+        let copied_template = entity("node_template", "server_copy");
+
+        provenance.enter_entity(copied_template.clone());
+        provenance.set_parent(node_type);
+        provenance.add_copied("properties", Some(node_template), None);
+        add_member(&mut provenance, "properties", "port", true, true);
+        provenance.exit();
+
+        assert_eq!(origin(&provenance, &copied_template, "properties"), "copied from node_template \"server\"");
+        assert_eq!(origin(&provenance, &copied_template, "properties.port"), "copied from node_template \"server\"");
+    }
+
+    #[test]
+    fn no_entity() {
+        let mut provenance = Provenance::default();
+        add_member(&mut provenance, "properties", "port", false, true);
+        assert!(provenance.entities.is_empty());
+    }
+}
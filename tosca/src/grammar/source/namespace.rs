@@ -1,5 +1,5 @@
 use super::{
-    super::{entity::*, errors::*, name::*},
+    super::{entity::*, errors::*, name::*, utils::*},
     id::*,
     source::*,
 };
@@ -66,7 +66,72 @@ impl Source {
     where
         AnnotatedT: Default,
     {
-        self.try_lookup(entity_kind, full_name)
-            .ok_or_else(|| UndeclaredError::new(entity_kind_name.to_string(), full_name.to_string()))
+        self.try_lookup(entity_kind, full_name).ok_or_else(|| {
+            UndeclaredError::new(entity_kind_name.to_string(), full_name.to_string())
+                .with_suggestions(self.suggest_names(&[entity_kind], full_name))
+        })
+    }
+
+    /// Suggest the closest names in the namespace for a [FullName] that was not found.
+    ///
+    /// Namespace-prefixed names are also compared without their prefix, so that a name that was
+    /// imported into a namespace can be found when the prefix was omitted.
+    pub fn suggest_names(&self, entity_kinds: &[EntityKind], full_name: &FullName) -> Vec<String> {
+        let name = full_name.to_string();
+        suggest_by_distance(
+            &name,
+            self.namespace.keys().filter(|key| entity_kinds.contains(&key.entity_kind)).map(|key| {
+                let candidate = key.inner.to_string();
+                let mut distance = edit_distance(&name, &candidate);
+                if key.inner.scope != full_name.scope {
+                    distance = distance.min(edit_distance(&full_name.name.0, &key.inner.name.0));
+                }
+                (distance, candidate)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, compris::annotate::*};
+
+    const TYPE: EntityKind = EntityKind(1);
+    const TEMPLATE: EntityKind = EntityKind(2);
+
+    fn source() -> Source {
+        let source_id = SourceID::Internal(ByteString::from_static("test"));
+        let mut source = Source::new(source_id.clone(), ByteString::from_static("test"));
+
+        for (entity_kind, full_name) in
+            [(TYPE, "tosca:Compute"), (TYPE, "tosca:Storage"), (TYPE, "Database"), (TEMPLATE, "server")]
+        {
+            source
+                .map_name::<WithoutAnnotations>(entity_kind, full_name.parse().expect("full name"), source_id.clone())
+                .expect("map name");
+        }
+
+        source
+    }
+
+    fn suggest(entity_kind: EntityKind, full_name: &str) -> Vec<String> {
+        source().suggest_names(&[entity_kind], &full_name.parse().expect("full name"))
+    }
+
+    #[test]
+    fn suggest_misspelled_name() {
+        assert_eq!(suggest(TYPE, "Databse"), vec!["Database"]);
+        assert_eq!(suggest(TYPE, "tosca:Comptue"), vec!["tosca:Compute"]);
+    }
+
+    #[test]
+    fn suggest_without_prefix() {
+        assert_eq!(suggest(TYPE, "Compute"), vec!["tosca:Compute"]);
+    }
+
+    #[test]
+    fn suggest_only_entity_kind() {
+        assert!(suggest(TYPE, "servr").is_empty());
+        assert_eq!(suggest(TEMPLATE, "servr"), vec!["server"]);
     }
 }
@@ -1,4 +1,7 @@
-use super::super::{catalog::*, data::*, entity::*, errors::*, name::*, source::*};
+use super::{
+    super::{catalog::*, data::*, entity::*, errors::*, name::*, source::*},
+    suggest::*,
+};
 
 use {
     compris::annotate::*,
@@ -8,6 +11,7 @@ use {
 
 /// Complete [BTreeMap].
 pub fn complete_map<EntityT, ParentEntityT, ErrorRecipientT>(
+    field_name: &str,
     map: &mut BTreeMap<ByteString, EntityT>,
     parent_map: Option<(&BTreeMap<ByteString, ParentEntityT>, &Scope)>,
     catalog: &mut Catalog,
@@ -16,7 +20,7 @@ pub fn complete_map<EntityT, ParentEntityT, ErrorRecipientT>(
 ) -> Result<(), ToscaError<WithAnnotations>>
 where
     EntityT: Subentity<ParentEntityT>,
-    ParentEntityT: ConvertIntoScope<EntityT>,
+    ParentEntityT: ConvertIntoScope<EntityT> + Subentity<ParentEntityT>,
     ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
 {
     let errors = errors.to_ref();
@@ -26,33 +30,61 @@ where
             for (name, parent_entity) in parent_map {
                 match map.get_mut(name) {
                     Some(entity) => {
-                        entity.complete(
+                        entity.lint(name, parent_entity, catalog, source_id);
+                        catalog.provenance.enter_member(field_name, name);
+                        catalog.provenance.add_member(true, true, entity.declaration_annotations().cloned());
+                        let result = entity.complete(
                             Some(name.clone()),
                             Some((parent_entity, scope)),
                             catalog,
                             source_id,
                             errors.clone(),
-                        )?;
+                        );
+                        catalog.provenance.exit();
+                        result?;
                     }
 
                     None => {
                         let mut entity = parent_entity.convert_into_scope(scope);
-                        entity.complete(
+                        catalog.provenance.enter_member(field_name, name);
+                        catalog.provenance.add_member(true, false, parent_entity.declaration_annotations().cloned());
+                        let result = entity.complete(
                             Some(name.clone()),
                             Some((parent_entity, scope)),
                             catalog,
                             source_id,
                             errors.clone(),
-                        )?;
+                        );
+                        catalog.provenance.exit();
+                        result?;
                         map.insert(name.clone(), entity);
                     }
                 }
             }
+
+            if catalog.report_undeclared
+                && let Some(declared_kind) = EntityT::declared_kind()
+            {
+                for (name, entity) in &*map {
+                    if !parent_map.contains_key(name) {
+                        let errors = &mut errors.to_error_recipient();
+                        let errors = &mut errors.with_annotations(entity.declaration_annotations());
+                        errors.give(
+                            UndeclaredError::new(declared_kind.into(), name.to_string())
+                                .with_suggestions(suggest(name, parent_map.keys())),
+                        )?;
+                    }
+                }
+            }
         }
 
         None => {
             for (name, entity) in map {
-                entity.complete(Some(name.clone()), None, catalog, source_id, errors.clone())?;
+                catalog.provenance.enter_member(field_name, name);
+                catalog.provenance.add_member(false, true, entity.declaration_annotations().cloned());
+                let result = entity.complete(Some(name.clone()), None, catalog, source_id, errors.clone());
+                catalog.provenance.exit();
+                result?;
             }
         }
     }
@@ -63,6 +95,7 @@ where
 /// Complete [TaggedValues].
 #[allow(unused_variables)]
 pub fn complete_tagged_values<EntityT, ParentEntityT, ErrorRecipientT>(
+    field_name: &str,
     tagged_values: &mut TaggedValues<ByteString, EntityT>,
     parent_tagged_values: Option<(&TaggedValues<ByteString, ParentEntityT>, &Scope)>,
     catalog: &mut Catalog,
@@ -71,7 +104,7 @@ pub fn complete_tagged_values<EntityT, ParentEntityT, ErrorRecipientT>(
 ) -> Result<(), ToscaError<WithAnnotations>>
 where
     EntityT: Subentity<ParentEntityT>,
-    ParentEntityT: ConvertIntoScope<EntityT>,
+    ParentEntityT: ConvertIntoScope<EntityT> + Subentity<ParentEntityT>,
     ErrorRecipientT: ErrorRecipient<ToscaError<WithAnnotations>>,
 {
     let errors = errors.to_ref();
@@ -83,33 +116,63 @@ where
             for (name, parent_entity) in parent_tagged_values {
                 match tagged_values.get_first_mut(name) {
                     Some(entity) => {
-                        entity.complete(
+                        entity.lint(name, parent_entity, catalog, source_id);
+                        catalog.provenance.enter_member(field_name, name);
+                        catalog.provenance.add_member(true, true, entity.declaration_annotations().cloned());
+                        let result = entity.complete(
                             Some(name.clone()),
                             Some((parent_entity, scope)),
                             catalog,
                             source_id,
                             errors.clone(),
-                        )?;
+                        );
+                        catalog.provenance.exit();
+                        result?;
                     }
 
                     None => {
                         let mut entity = parent_entity.convert_into_scope(scope);
-                        entity.complete(
+                        catalog.provenance.enter_member(field_name, name);
+                        catalog.provenance.add_member(true, false, parent_entity.declaration_annotations().cloned());
+                        let result = entity.complete(
                             Some(name.clone()),
                             Some((parent_entity, scope)),
                             catalog,
                             source_id,
                             errors.clone(),
-                        )?;
+                        );
+                        catalog.provenance.exit();
+                        result?;
                         tagged_values.add(name.clone(), entity);
                     }
                 }
             }
+
+            if catalog.report_undeclared
+                && let Some(declared_kind) = EntityT::declared_kind()
+            {
+                for (name, entity) in &*tagged_values {
+                    if !parent_tagged_values.contains_tag(name) {
+                        let errors = &mut errors.to_error_recipient();
+                        let errors = &mut errors.with_annotations(entity.declaration_annotations());
+                        errors.give(
+                            UndeclaredError::new(declared_kind.into(), name.to_string()).with_suggestions(suggest(
+                                name,
+                                parent_tagged_values.into_iter().map(|(name, _)| name),
+                            )),
+                        )?;
+                    }
+                }
+            }
         }
 
         None => {
             for (name, entity) in tagged_values {
-                entity.complete(Some(name.clone()), None, catalog, source_id, errors.clone())?;
+                catalog.provenance.enter_member(field_name, name);
+                catalog.provenance.add_member(false, true, entity.declaration_annotations().cloned());
+                let result = entity.complete(Some(name.clone()), None, catalog, source_id, errors.clone());
+                catalog.provenance.exit();
+                result?;
             }
         }
     }
@@ -157,6 +220,7 @@ macro_rules! complete_map_for_field (
         errors_with_field_annotations!(
             $errors, $self, stringify!($field),
             complete_map(
+                stringify!($field),
                 &mut $self.$field,
                 $entity.as_ref().map(|(entity, scope)| (&entity.$field, *scope)),
                 $catalog,
@@ -181,6 +245,7 @@ macro_rules! complete_tagged_values_for_field (
         errors_with_field_annotations!(
             $errors, $self, stringify!($field),
             complete_tagged_values(
+                stringify!($field),
                 &mut $self.$field,
                 $entity.as_ref().map(|(entity, scope)| (&entity.$field, *scope)),
                 $catalog,
@@ -213,7 +278,10 @@ macro_rules! get_complete_entity (
                 )?
                 .cloned()
             {
-                Some(entity) => Some((entity, &$self.$field.scope)),
+                Some(entity) => {
+                    $catalog.set_provenance_parent($kind, &$self.$field, $source_id);
+                    Some((entity, &$self.$field.scope))
+                }
                 None => None,
             }
         } else {
@@ -246,7 +314,10 @@ macro_rules! get_complete_parent (
                     )?
                     .cloned()
                 {
-                    Some(parent) => Some((parent, &full_name.scope)),
+                    Some(parent) => {
+                        $catalog.set_provenance_parent($kind, full_name, $source_id);
+                        Some((parent, &full_name.scope))
+                    }
                     None => None,
                 }
             }
@@ -258,3 +329,77 @@ macro_rules! get_complete_parent (
 
 #[allow(unused_imports)]
 pub use {complete_map_for_field, complete_tagged_values_for_field, get_complete_entity, get_complete_parent};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A property that must be declared by its parent
+    #[derive(Clone, Default)]
+    struct Property;
+
+    impl Subentity<Property> for Property {
+        fn complete(
+            &mut self,
+            _name: Option<ByteString>,
+            _parent: Option<(&Property, &Scope)>,
+            _catalog: &mut Catalog,
+            _source_id: &SourceID,
+            _errors: ToscaErrorRecipientRef,
+        ) -> Result<(), ToscaError<WithAnnotations>> {
+            Ok(())
+        }
+
+        fn declared_kind() -> Option<&'static str> {
+            Some("property")
+        }
+    }
+
+    impl ConvertIntoScope<Property> for Property {
+        fn convert_into_scope(&self, _scope: &Scope) -> Property {
+            self.clone()
+        }
+    }
+
+    // The errors of completing a misspelled property
+    fn complete(report_undeclared: bool) -> Vec<String> {
+        let mut catalog = Catalog { report_undeclared, ..Default::default() };
+        let mut errors = Errors::<ToscaError<WithAnnotations>>::default();
+
+        let mut map = BTreeMap::from([("prot".into(), Property)]);
+        let parent_map = BTreeMap::from([("port".into(), Property), ("host".into(), Property)]);
+
+        complete_map(
+            "properties",
+            &mut map,
+            Some((&parent_map, &Scope::default())),
+            &mut catalog,
+            &Default::default(),
+            &mut errors,
+        )
+        .expect("complete");
+
+        errors.errors.iter().map(|error| error.to_string()).collect()
+    }
+
+    #[test]
+    fn report_undeclared_with_suggestions() {
+        let errors = complete(true);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("property: prot, did you mean \"port\"?"), "{}", errors[0]);
+    }
+
+    #[test]
+    fn undeclared_not_reported_by_default() {
+        assert!(complete(false).is_empty());
+    }
+
+    #[test]
+    fn undeclared_error_suggestions() {
+        let error = UndeclaredError::<WithoutAnnotations>::new("property".into(), "prot".into());
+        assert_eq!(error.to_string(), "property: prot");
+
+        let error = error.with_suggestions(vec!["port".into(), "prop".into()]);
+        assert_eq!(error.to_string(), "property: prot, did you mean \"port\" or \"prop\"?");
+    }
+}
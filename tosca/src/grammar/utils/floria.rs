@@ -36,6 +36,12 @@ pub trait FloriaToscaMetadata {
 
     /// Set `tosca:directives` metadata.
     fn set_tosca_directives(&mut self, directives: &Vec<ByteString>);
+
+    /// Set `tosca:members` metadata.
+    fn set_tosca_members(&mut self, members: &Vec<Name>);
+
+    /// Set `tosca:targets` metadata.
+    fn set_tosca_targets(&mut self, targets: &Vec<Name>);
 }
 
 impl FloriaToscaMetadata for floria::Metadata {
@@ -88,6 +94,20 @@ impl FloriaToscaMetadata for floria::Metadata {
             self.into_insert("tosca:directives", directives);
         }
     }
+
+    fn set_tosca_members(&mut self, members: &Vec<Name>) {
+        if !members.is_empty() {
+            let members: Vec<Variant<_>> = members.into_iter().map(|member| member.to_string().into()).collect();
+            self.into_insert("tosca:members", members);
+        }
+    }
+
+    fn set_tosca_targets(&mut self, targets: &Vec<Name>) {
+        if !targets.is_empty() {
+            let targets: Vec<Variant<_>> = targets.into_iter().map(|target| target.to_string().into()).collect();
+            self.into_insert("tosca:targets", targets);
+        }
+    }
 }
 
 //
@@ -12,6 +12,9 @@ pub fn clone_struct_annotations(struct_annotations: &StructAnnotations, field_na
 }
 
 /// Clone if [None].
+///
+/// Evaluates to whether it was cloned. If a [Vec] is provided, the name of the field is pushed to it
+/// when cloned.
 #[macro_export]
 macro_rules! if_none_clone {
     (
@@ -19,30 +22,61 @@ macro_rules! if_none_clone {
         $self:ident,
         $entity:ident $(,)?
     ) => {{
-        if $self.$field.is_none() && !$entity.$field.is_some() {
+        if $self.$field.is_none() && $entity.$field.is_some() {
             $self.$field = $entity.$field.clone();
             if let Some(annotations) = $entity.annotations.get(stringify!($field)) {
                 $self.annotations.insert(stringify!($field).into(), annotations.clone());
             }
+            true
+        } else {
+            false
+        }
+    }};
+
+    (
+        $field:tt,
+        $self:ident,
+        $entity:ident,
+        $cloned:ident $(,)?
+    ) => {{
+        if $crate::if_none_clone!($field, $self, $entity) {
+            $cloned.push(stringify!($field));
         }
     }};
 }
 
 /// Clone if `is_empty`.
+///
+/// Evaluates to whether it was cloned. If a [Vec] is provided, the name of the field is pushed to it
+/// when cloned.
 #[macro_export]
 macro_rules! if_empty_clone {
     (
         $field:tt,
         $self:ident,
         $entity:ident $(,)?
-    ) => {
+    ) => {{
         if $self.$field.is_empty() && !$entity.$field.is_empty() {
             $self.$field = $entity.$field.clone();
             if let Some(annotations) = $entity.annotations.get(stringify!($field)) {
                 $self.annotations.insert(stringify!($field).into(), annotations.clone());
             }
+            true
+        } else {
+            false
         }
-    };
+    }};
+
+    (
+        $field:tt,
+        $self:ident,
+        $entity:ident,
+        $cloned:ident $(,)?
+    ) => {{
+        if $crate::if_empty_clone!($field, $self, $entity) {
+            $cloned.push(stringify!($field));
+        }
+    }};
 }
 
 /// Do something if [None].
@@ -65,3 +99,54 @@ macro_rules! if_none_else {
 
 #[allow(unused_imports)]
 pub use {if_empty_clone, if_none_clone, if_none_else};
+
+#[cfg(test)]
+mod tests {
+    use {super::*, kutil::std::immutable::*};
+
+    #[derive(Default)]
+    struct Entity {
+        description: Option<ByteString>,
+        count: Option<u64>,
+        annotations: StructAnnotations,
+    }
+
+    fn parent() -> Entity {
+        let mut parent = Entity { description: Some("parent".into()), count: None, ..Default::default() };
+        parent.annotations.insert("description".into(), Default::default());
+        parent
+    }
+
+    #[test]
+    fn inherit_from_parent() {
+        // Unset in the child and set in the parent: inherited
+        let parent = parent();
+        let mut child = Entity::default();
+        let mut cloned = Vec::default();
+        if_none_clone!(description, child, parent, cloned);
+        assert_eq!(child.description, Some("parent".into()));
+        assert!(child.annotations.contains_key("description"));
+        assert_eq!(cloned, ["description"]);
+    }
+
+    #[test]
+    fn keep_child_value() {
+        // Set in the child: the parent's value is not inherited
+        let parent = parent();
+        let mut child = Entity { description: Some("child".into()), ..Default::default() };
+        assert!(!if_none_clone!(description, child, parent));
+        assert_eq!(child.description, Some("child".into()));
+        assert!(!child.annotations.contains_key("description"));
+    }
+
+    #[test]
+    fn nothing_to_inherit() {
+        // Unset in both: nothing is cloned or recorded
+        let parent = parent();
+        let mut child = Entity::default();
+        let mut cloned = Vec::<&str>::default();
+        if_none_clone!(count, child, parent, cloned);
+        assert_eq!(child.count, None);
+        assert!(cloned.is_empty());
+    }
+}
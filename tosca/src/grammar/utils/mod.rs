@@ -5,7 +5,10 @@ mod errors;
 mod floria;
 mod if_none;
 mod into_scoped;
+mod suggest;
 mod validate;
 
 #[allow(unused_imports)]
-pub use {compile::*, complete::*, downcast::*, errors::*, floria::*, if_none::*, into_scoped::*, validate::*};
+pub use {
+    compile::*, complete::*, downcast::*, errors::*, floria::*, if_none::*, into_scoped::*, suggest::*, validate::*,
+};
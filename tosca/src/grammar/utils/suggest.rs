@@ -0,0 +1,115 @@
+use std::{cmp::*, collections::*};
+
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Suggest the closest candidates for a name that was not found.
+///
+/// Candidates are compared by case-insensitive edit distance. Returns at most a few suggestions,
+/// closest first.
+pub fn suggest<IterableT, CandidateT>(name: &str, candidates: IterableT) -> Vec<String>
+where
+    IterableT: IntoIterator<Item = CandidateT>,
+    CandidateT: ToString,
+{
+    suggest_by_distance(
+        name,
+        candidates.into_iter().map(|candidate| {
+            let candidate = candidate.to_string();
+            (edit_distance(name, &candidate), candidate)
+        }),
+    )
+}
+
+/// Suggest the closest candidates for a name that was not found.
+///
+/// The distances are provided by the caller, which allows for comparing a candidate in more than
+/// one way (e.g. both with and without its namespace prefix). Returns at most a few suggestions,
+/// closest first.
+pub fn suggest_by_distance<IterableT>(name: &str, candidates: IterableT) -> Vec<String>
+where
+    IterableT: IntoIterator<Item = (usize, String)>,
+{
+    let max_distance = max(name.chars().count() / 3, 1);
+
+    // Keep the shortest distance per candidate
+    let mut distances = BTreeMap::<String, usize>::default();
+    for (distance, candidate) in candidates {
+        if (distance <= max_distance) && (candidate != name) {
+            let shortest = distances.entry(candidate).or_insert(distance);
+            *shortest = min(*shortest, distance);
+        }
+    }
+
+    let mut candidates: Vec<_> = distances.into_iter().map(|(candidate, distance)| (distance, candidate)).collect();
+    candidates.sort();
+
+    candidates.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate).collect()
+}
+
+/// Case-insensitive edit distance.
+///
+/// Uses the optimal string alignment distance, in which transposing two adjacent characters
+/// counts as a single edit.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<_> = a.chars().flat_map(char::to_lowercase).collect();
+    let b: Vec<_> = b.chars().flat_map(char::to_lowercase).collect();
+
+    // Three rows of the distance matrix are enough
+    let mut before_previous = vec![0; b.len() + 1];
+    let mut previous: Vec<_> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            current[j] = min(min(previous[j] + 1, current[j - 1] + 1), previous[j - 1] + cost);
+
+            if (i > 1) && (j > 1) && (a[i - 1] == b[j - 2]) && (a[i - 2] == b[j - 1]) {
+                current[j] = min(current[j], before_previous[j - 2] + 1);
+            }
+        }
+
+        (before_previous, previous, current) = (previous, current, before_previous);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "port"), 4);
+        assert_eq!(edit_distance("Port", "port"), 0);
+        assert_eq!(edit_distance("host", "hsot"), 1);
+    }
+
+    #[test]
+    fn closest_first() {
+        assert_eq!(suggest("Compute", ["Storage", "Comptue", "compute", "Compute"]), vec!["compute", "Comptue"]);
+    }
+
+    #[test]
+    fn too_distant() {
+        assert!(suggest("port", ["host", "address"]).is_empty());
+    }
+
+    #[test]
+    fn at_most_three() {
+        assert_eq!(suggest("a", ["e", "d", "c", "b"]), vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn shortest_distance_per_candidate() {
+        assert_eq!(
+            suggest_by_distance("Compute", [(2, "tosca:Compute".into()), (0, "tosca:Compute".into())]),
+            vec!["tosca:Compute"]
+        );
+    }
+}